@@ -0,0 +1,113 @@
+/*!
+# Benchmark: Streaming Argue.
+*/
+
+use argyle::{
+	Argue,
+	Argument,
+	KeyWord,
+};
+use brunch::{
+	Bench,
+	benches,
+};
+use std::ffi::OsString;
+
+
+
+/// # Arguments Per Workload.
+const ARGS: usize = 10_000;
+
+/// # Keywords.
+const KEYWORDS: [KeyWord; 12] = [
+	KeyWord::Command("build"),
+	KeyWord::Command("check"),
+	KeyWord::Key("-h"),
+	KeyWord::Key("--help"),
+	KeyWord::Key("-q"),
+	KeyWord::Key("--quiet"),
+	KeyWord::Key("-v"),
+	KeyWord::Key("--verbose"),
+	KeyWord::KeyWithValue("-j"),
+	KeyWord::KeyWithValue("-o"),
+	KeyWord::KeyWithValue("--out"),
+	KeyWord::KeyWithOptionalValue("--color"),
+];
+
+
+
+/// # Short ASCII Arguments.
+fn short_ascii() -> Vec<OsString> {
+	(0..ARGS).map(|i| OsString::from(format!("file-{i}.txt"))).collect()
+}
+
+/// # Keyword-Heavy Arguments.
+///
+/// Mostly keys, with the odd value and unknown thrown in.
+fn keyword_heavy() -> Vec<OsString> {
+	const RAW: [&str; 10] = [
+		"-v", "--quiet", "build", "-j", "4", "--color", "--help", "-q", "--nope", "check",
+	];
+	RAW.iter().copied().cycle().take(ARGS).map(OsString::from).collect()
+}
+
+/// # Glued Values.
+///
+/// Keys with their values attached, short and long.
+fn glued_values() -> Vec<OsString> {
+	(0..ARGS).map(|i| OsString::from(match i % 4 {
+		0 => format!("-j{i}"),
+		1 => format!("-ofile-{i}.txt"),
+		2 => format!("--out=file-{i}.txt"),
+		_ => format!("--color={i}"),
+	})).collect()
+}
+
+/// # End Collection.
+///
+/// A single key, then everything after a separator.
+fn end_collection() -> Vec<OsString> {
+	let mut out = short_ascii();
+	out[0] = OsString::from("-v");
+	out[1] = OsString::from("--");
+	out
+}
+
+
+
+benches!(
+	// What Argue does: convert in place.
+	Bench::new("OsString::into_string (10K)")
+		.run_seeded_with(short_ascii, |v| {
+			v.into_iter().filter_map(|s| s.into_string().ok()).collect::<Vec<_>>()
+		}),
+
+	// The suggested "fast path": validate borrowed bytes, then copy.
+	Bench::new("OsStr::as_encoded_bytes + from_utf8 + to_owned (10K)")
+		.run_seeded_with(short_ascii, |v| {
+			v.iter()
+				.filter_map(|s| std::str::from_utf8(s.as_encoded_bytes()).ok().map(str::to_owned))
+				.collect::<Vec<_>>()
+		}),
+
+	Bench::spacer(),
+
+	Bench::new("Argue (positional only, 10K)")
+		.run_seeded_with(short_ascii, |v| Argue::from(v).count()),
+
+	Bench::new("Argue (positional only, with keywords, 10K)")
+		.run_seeded_with(short_ascii, |v| Argue::from(v).with_keywords(KEYWORDS).count()),
+
+	Bench::new("Argue (keyword heavy, 10K)")
+		.run_seeded_with(keyword_heavy, |v| Argue::from(v).with_keywords(KEYWORDS).count()),
+
+	Bench::new("Argue (glued values, 10K)")
+		.run_seeded_with(glued_values, |v| Argue::from(v).with_keywords(KEYWORDS).count()),
+
+	Bench::new("Argue (end collection, 10K)")
+		.run_seeded_with(end_collection, |v| {
+			Argue::from(v).with_keywords(KEYWORDS)
+				.map(|arg| if let Argument::End(rest) = arg { rest.len() } else { 0 })
+				.sum::<usize>()
+		}),
+);
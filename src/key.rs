@@ -0,0 +1,2448 @@
+/*!
+# Argyle: Keywords.
+*/
+
+mod classify;
+mod table;
+
+pub use classify::{
+	classify,
+	Classification,
+};
+pub use table::KeyWordTable;
+use alloc::{
+	borrow::ToOwned,
+	collections::{
+		BTreeMap,
+		BTreeSet,
+	},
+	format,
+	string::String,
+	vec::Vec,
+};
+use core::{
+	borrow::Borrow,
+	cmp::Ordering,
+	fmt,
+};
+#[cfg(feature = "std")] use std::path::Path;
+
+
+
+#[macro_export]
+/// # Keyword List.
+///
+/// This macro expands to a `&'static [KeyWord]` from a compact list of
+/// comma-separated entries:
+///
+/// | Entry | Keyword |
+/// | ----- | ------- |
+/// | `-h`, `--help` | [`KeyWord::Key`] |
+/// | `--threads = value` | [`KeyWord::KeyWithValue`] |
+/// | `--color = optional` | [`KeyWord::KeyWithOptionalValue`] |
+/// | `-O = prefix` | [`KeyWord::Prefix`] |
+/// | `make(cmd)` | [`KeyWord::Command`] |
+///
+/// Everything is validated — and checked for duplicates — at compile time,
+/// providing the same guarantees as [`KeyWordsBuilder`] without the need
+/// for a build script.
+///
+/// ## Examples
+///
+/// ```
+/// use argyle::{Argue, Argument, KeyWord};
+/// use std::ffi::OsString;
+///
+/// let keys = argyle::keywords![-h, --help, --threads = value, make(cmd)];
+/// assert_eq!(
+///     keys,
+///     [
+///         KeyWord::Key("-h"),
+///         KeyWord::Key("--help"),
+///         KeyWord::KeyWithValue("--threads"),
+///         KeyWord::Command("make"),
+///     ],
+/// );
+///
+/// let mut args = Argue::from([OsString::from("make"), OsString::from("--threads=4")])
+///     .with_keywords(keys);
+/// assert_eq!(args.next(), Some(Argument::Command("make")));
+/// assert_eq!(args.next(), Some(Argument::KeyWithValue("--threads", "4".to_owned())));
+/// ```
+///
+/// Invalid or duplicate entries fail to compile:
+///
+/// ```compile_fail
+/// let keys = argyle::keywords![-h, --help, -h];
+/// ```
+///
+/// ```compile_fail
+/// let keys = argyle::keywords![--björk];
+/// ```
+macro_rules! keywords {
+	// Keywords with values.
+	(@munch [$($done:expr,)*] [$($k:tt)+] = value $(, $($rest:tt)*)?) => (
+		$crate::keywords!(
+			@munch
+			[$($done,)* $crate::KeyWord::key_with_value_unwrap(stringify!($($k)+)),]
+			[]
+			$($($rest)*)?
+		)
+	);
+	(@munch [$($done:expr,)*] [$($k:tt)+] = optional $(, $($rest:tt)*)?) => (
+		$crate::keywords!(
+			@munch
+			[$($done,)* $crate::KeyWord::key_with_optional_value_unwrap(stringify!($($k)+)),]
+			[]
+			$($($rest)*)?
+		)
+	);
+	(@munch [$($done:expr,)*] [$($k:tt)+] = prefix $(, $($rest:tt)*)?) => (
+		$crate::keywords!(
+			@munch
+			[$($done,)* $crate::KeyWord::prefix_unwrap(stringify!($($k)+)),]
+			[]
+			$($($rest)*)?
+		)
+	);
+
+	// Commands.
+	(@munch [$($done:expr,)*] [$($k:tt)+] (cmd) $(, $($rest:tt)*)?) => (
+		$crate::keywords!(
+			@munch
+			[$($done,)* $crate::KeyWord::command_unwrap(stringify!($($k)+)),]
+			[]
+			$($($rest)*)?
+		)
+	);
+
+	// Boolean keys.
+	(@munch [$($done:expr,)*] [$($k:tt)+] $(, $($rest:tt)*)?) => (
+		$crate::keywords!(
+			@munch
+			[$($done,)* $crate::KeyWord::key_unwrap(stringify!($($k)+)),]
+			[]
+			$($($rest)*)?
+		)
+	);
+
+	// All done!
+	(@munch [$($done:expr,)*] []) => ({
+		const KEYS: &[$crate::KeyWord] = &[$($done),*];
+		const _: () = assert!($crate::__unique_keywords(KEYS), "Duplicate keyword.");
+		KEYS
+	});
+
+	// Keep reading the current keyword.
+	(@munch [$($done:expr,)*] [$($k:tt)*] $next:tt $($rest:tt)*) => (
+		$crate::keywords!(@munch [$($done,)*] [$($k)* $next] $($rest)*)
+	);
+
+	// Entry point.
+	($($t:tt)*) => ($crate::keywords!(@munch [] [] $($t)*));
+}
+
+
+
+#[derive(Debug, Clone, Copy)]
+/// # Keyword.
+///
+/// This enum is used by [`Argue::with_keywords`](crate::Argue::with_keywords)
+/// to declare the special CLI (sub)commands and/or keys used by the app.
+///
+/// Each variant has its own formatting requirements, so it is recommended you
+/// create new instances using the [`KeyWord::command`], [`KeyWord::key`], and
+/// [`KeyWord::key_with_value`] methods rather than populating variants
+/// directly.
+///
+/// For a compile-time alternative, see [`KeyWordsBuilder`].
+///
+/// Note that for the purposes of equality and ordering, the variants are
+/// irrelevant; only the words are used.
+///
+/// For example, the following are "equal" despite one requiring a value and
+/// one not.
+///
+/// ```
+/// assert_eq!(
+///     argyle::KeyWord::key("--help"),
+///     argyle::KeyWord::key_with_value("--help"),
+/// );
+/// ```
+pub enum KeyWord {
+	/// # (Sub)command.
+	Command(&'static str),
+
+	/// # Boolean key.
+	Key(&'static str),
+
+	/// # Key with Value.
+	KeyWithValue(&'static str),
+
+	/// # Key with Optional Value.
+	///
+	/// A key that _may_ have a value, but only if it is attached to the same
+	/// argument, like `--color=always`. The next argument is never consumed.
+	KeyWithOptionalValue(&'static str),
+
+	/// # Prefix.
+	///
+	/// A key whose value is glued directly onto it, compiler-style, like
+	/// `-O2` or `-Wall`. Everything after the prefix — equal signs and all —
+	/// is the value. The next argument is never consumed.
+	Prefix(&'static str),
+
+	/// # Alias.
+	///
+	/// An alternative spelling — the first value — for another keyword — the
+	/// second value — which must be registered separately.
+	///
+	/// When an alias is matched, [`Argue`](crate::Argue) reports the
+	/// canonical keyword instead, so you only need to match the one.
+	///
+	/// See [`KeyWord::pair`] for the most common use case.
+	Alias(&'static str, &'static str),
+}
+
+impl Borrow<str> for KeyWord {
+	#[inline]
+	fn borrow(&self) -> &str { self.as_str() }
+}
+
+impl Eq for KeyWord {}
+
+impl From<&Self> for KeyWord {
+	#[inline]
+	fn from(src: &Self) -> Self { *src }
+}
+
+impl Ord for KeyWord {
+	#[inline]
+	fn cmp(&self, other: &Self) -> Ordering { self.as_str().cmp(other.as_str()) }
+}
+
+impl PartialEq for KeyWord {
+	#[inline]
+	fn eq(&self, other: &Self) -> bool { self.as_str() == other.as_str() }
+}
+
+impl PartialOrd for KeyWord {
+	#[inline]
+	fn partial_cmp(&self, other: &Self) -> Option<Ordering> { Some(self.cmp(other)) }
+}
+
+impl KeyWord {
+	#[must_use]
+	/// # New (Sub)Command.
+	///
+	/// Validate and return a new (sub)command keyword, or `None` if invalid.
+	///
+	/// (Sub)commands may only contain ASCII alphanumeric characters, `-`,
+	/// and `_`, and must begin with an alphanumeric.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use argyle::KeyWord;
+	///
+	/// // Totally fine.
+	/// assert!(KeyWord::command("make").is_some());
+	///
+	/// // This, however, does not work.
+	/// assert!(KeyWord::command("--help").is_none());
+	/// ```
+	///
+	/// For a compile-time alternative, see [`KeyWordsBuilder`].
+	pub const fn command(word: &'static str) -> Option<Self> {
+		if valid_command(word.as_bytes()) { Some(Self::Command(word)) }
+		else { None }
+	}
+
+	#[must_use]
+	/// # New Boolean Key.
+	///
+	/// Validate and return a new boolean/switch keyword — a flag that stands
+	/// on its own — or `None` if invalid.
+	///
+	/// Both long and short style keys are supported:
+	/// * Short keys must be two bytes: a dash and an ASCII alphanumeric character.
+	/// * Long keys can be any length, but must start with two dashes and an ASCII alphanumeric character; all other characters must be alphanumerics, `-`, and `_`.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use argyle::KeyWord;
+	///
+	/// // Totally fine.
+	/// assert!(KeyWord::key("--help").is_some());
+	///
+	/// // These, however, do not work.
+	/// assert!(KeyWord::key("--björk").is_none());
+	/// assert!(KeyWord::key("-too_long_to_be_short").is_none());
+	/// ```
+	///
+	/// For a compile-time alternative, see [`KeyWordsBuilder`].
+	pub const fn key(keyword: &'static str) -> Option<Self> {
+		if valid_key(keyword.as_bytes()) { Some(Self::Key(keyword)) }
+		else { None }
+	}
+
+	#[must_use]
+	/// # New Option Key.
+	///
+	/// Validate and return a new option keyword — a key that expects a value —
+	/// or `None` if invalid.
+	///
+	/// Both long and short style keys are supported:
+	/// * Short keys must be two bytes: a dash and an ASCII alphanumeric character.
+	/// * Long keys can be any length, but must start with two dashes and an ASCII alphanumeric character; all other characters must be alphanumerics, `-`, and `_`.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use argyle::KeyWord;
+	///
+	/// // Totally fine.
+	/// assert!(KeyWord::key_with_value("--help").is_some());
+	///
+	/// // These, however, do not work.
+	/// assert!(KeyWord::key_with_value("--björk").is_none());
+	/// assert!(KeyWord::key_with_value("-too_long_to_be_short").is_none());
+	/// ```
+	///
+	/// For a compile-time alternative, see [`KeyWordsBuilder`].
+	pub const fn key_with_value(keyword: &'static str) -> Option<Self> {
+		if valid_key(keyword.as_bytes()) { Some(Self::KeyWithValue(keyword)) }
+		else { None }
+	}
+
+	#[must_use]
+	/// # New Optional-Value Key.
+	///
+	/// Validate and return a new keyword for a key that _may_ have a value,
+	/// or `None` if invalid.
+	///
+	/// Unlike [`KeyWord::key_with_value`], values must be attached to the key
+	/// itself, like `--color=always` or `-calways`. If the key appears on its
+	/// own, it is treated like a boolean [`KeyWord::key`]; the next argument
+	/// is never consumed.
+	///
+	/// The formatting rules are otherwise the same as for other keys.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use argyle::{Argue, Argument, KeyWord};
+	/// use std::ffi::OsString;
+	///
+	/// let mut args = Argue::from([
+	///     OsString::from("--color"),
+	///     OsString::from("--color=never"),
+	///     OsString::from("always"),
+	/// ])
+	///     .with_keywords([KeyWord::key_with_optional_value("--color").unwrap()]);
+	///
+	/// assert_eq!(args.next(), Some(Argument::Key("--color")));
+	/// assert_eq!(args.next(), Some(Argument::KeyWithValue("--color", "never".to_owned())));
+	/// assert_eq!(args.next(), Some(Argument::Other("always".to_owned())));
+	/// assert_eq!(args.next(), None);
+	/// ```
+	///
+	/// For a compile-time alternative, see [`KeyWordsBuilder`].
+	pub const fn key_with_optional_value(keyword: &'static str) -> Option<Self> {
+		if valid_key(keyword.as_bytes()) { Some(Self::KeyWithOptionalValue(keyword)) }
+		else { None }
+	}
+
+	#[must_use]
+	/// # New Prefix Key.
+	///
+	/// Validate and return a new keyword for a compiler-style key whose value
+	/// is glued directly onto it — `-O2`, `-Wall`, etc. — or `None` if
+	/// invalid.
+	///
+	/// Any argument starting with the prefix is matched, with the remainder
+	/// — verbatim — as its value; neither an equal sign nor a following
+	/// argument is needed (or used). If the prefix appears on its own, it is
+	/// treated like a boolean [`KeyWord::key`].
+	///
+	/// Ordinary keys take priority, so `--opt` and `--opt-level` can be
+	/// registered alongside a `--opt` prefix without getting swallowed.
+	///
+	/// When parsing [`Argue::from_pairs`](crate::Argue::from_pairs), the
+	/// prefix must be the pair's key, and the pair's value is used as-is,
+	/// exactly like a [`KeyWord::key_with_value`].
+	///
+	/// The formatting rules are otherwise the same as for other keys.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use argyle::{Argue, Argument, KeyWord};
+	/// use std::ffi::OsString;
+	///
+	/// let mut args = Argue::from([
+	///     OsString::from("-O2"),
+	///     OsString::from("-Wall"),
+	///     OsString::from("-Wno-unused=x"),
+	///     OsString::from("-O"),
+	///     OsString::from("3"),
+	/// ])
+	///     .with_keywords([
+	///         KeyWord::prefix("-O").unwrap(),
+	///         KeyWord::prefix("-W").unwrap(),
+	///     ]);
+	///
+	/// assert_eq!(args.next(), Some(Argument::KeyWithValue("-O", "2".to_owned())));
+	/// assert_eq!(args.next(), Some(Argument::KeyWithValue("-W", "all".to_owned())));
+	/// assert_eq!(args.next(), Some(Argument::KeyWithValue("-W", "no-unused=x".to_owned())));
+	/// assert_eq!(args.next(), Some(Argument::Key("-O")));
+	/// assert_eq!(args.next(), Some(Argument::Other("3".to_owned())));
+	/// assert_eq!(args.next(), None);
+	/// ```
+	///
+	/// For a compile-time alternative, see [`KeyWordsBuilder`].
+	pub const fn prefix(keyword: &'static str) -> Option<Self> {
+		if valid_key(keyword.as_bytes()) { Some(Self::Prefix(keyword)) }
+		else { None }
+	}
+
+	#[must_use]
+	/// # New Short/Long Key Pair.
+	///
+	/// Validate and return a matching short and long key — a [`KeyWord::Key`]
+	/// or [`KeyWord::KeyWithValue`], depending on `value` — or `None` if
+	/// invalid.
+	///
+	/// One of the keys must be short, and the other long; the order doesn't
+	/// matter. The long key is treated as canonical, while the short one is
+	/// registered as its [`KeyWord::Alias`].
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use argyle::{Argue, Argument, KeyWord};
+	/// use std::ffi::OsString;
+	///
+	/// let mut args = Argue::from([OsString::from("-o"), OsString::from("out.txt")])
+	///     .with_keywords(KeyWord::pair("-o", "--output", true).unwrap());
+	///
+	/// // The short key is reported using its long name.
+	/// assert_eq!(
+	///     args.next(),
+	///     Some(Argument::KeyWithValue("--output", "out.txt".to_owned())),
+	/// );
+	///
+	/// // Both can't be short (or long).
+	/// assert!(KeyWord::pair("-o", "-p", true).is_none());
+	/// assert!(KeyWord::pair("--output", "--out", true).is_none());
+	/// ```
+	pub const fn pair(a: &'static str, b: &'static str, value: bool) -> Option<[Self; 2]> {
+		let (short, long) =
+			if a.len() == 2 { (a, b) }
+			else { (b, a) };
+
+		if
+			short.len() == 2 && long.len() > 2 &&
+			valid_key(short.as_bytes()) && valid_key(long.as_bytes())
+		{
+			let long = if value { Self::KeyWithValue(long) } else { Self::Key(long) };
+			Some([long, Self::Alias(short, long.as_str())])
+		}
+		else { None }
+	}
+}
+
+impl KeyWord {
+	#[must_use]
+	/// # New (Sub)Command (Unwrapped).
+	///
+	/// Same as [`KeyWord::command`], but returns the keyword directly,
+	/// panicking if invalid.
+	///
+	/// This is mainly intended for `const` and `static` keyword lists, where
+	/// an invalid entry will fail to compile.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use argyle::{Argue, Argument, KeyWord};
+	/// use std::ffi::OsString;
+	///
+	/// static KEYS: [KeyWord; 3] = [
+	///     KeyWord::command_unwrap("build"),
+	///     KeyWord::key_unwrap("-v"),
+	///     KeyWord::key_with_value_unwrap("--out"),
+	/// ];
+	///
+	/// let mut args = Argue::from([OsString::from("build")])
+	///     .with_keywords(&KEYS);
+	/// assert_eq!(args.next(), Some(Argument::Command("build")));
+	/// ```
+	///
+	/// ## Panics
+	///
+	/// This will panic if the command is invalid.
+	pub const fn command_unwrap(word: &'static str) -> Self {
+		match Self::command(word) {
+			Some(k) => k,
+			None => panic!("Invalid command."),
+		}
+	}
+
+	#[must_use]
+	/// # New Boolean Key (Unwrapped).
+	///
+	/// Same as [`KeyWord::key`], but returns the keyword directly, panicking
+	/// if invalid. See [`KeyWord::command_unwrap`] for an example.
+	///
+	/// ## Panics
+	///
+	/// This will panic if the key is invalid.
+	pub const fn key_unwrap(keyword: &'static str) -> Self {
+		match Self::key(keyword) {
+			Some(k) => k,
+			None => panic!("Invalid key."),
+		}
+	}
+
+	#[must_use]
+	/// # New Option Key (Unwrapped).
+	///
+	/// Same as [`KeyWord::key_with_value`], but returns the keyword directly,
+	/// panicking if invalid. See [`KeyWord::command_unwrap`] for an example.
+	///
+	/// ## Panics
+	///
+	/// This will panic if the key is invalid.
+	pub const fn key_with_value_unwrap(keyword: &'static str) -> Self {
+		match Self::key_with_value(keyword) {
+			Some(k) => k,
+			None => panic!("Invalid key."),
+		}
+	}
+
+	#[must_use]
+	/// # New Optional-Value Key (Unwrapped).
+	///
+	/// Same as [`KeyWord::key_with_optional_value`], but returns the keyword
+	/// directly, panicking if invalid. See [`KeyWord::command_unwrap`] for an
+	/// example.
+	///
+	/// ## Panics
+	///
+	/// This will panic if the key is invalid.
+	pub const fn key_with_optional_value_unwrap(keyword: &'static str) -> Self {
+		match Self::key_with_optional_value(keyword) {
+			Some(k) => k,
+			None => panic!("Invalid key."),
+		}
+	}
+
+	#[must_use]
+	/// # New Prefix Key (Unwrapped).
+	///
+	/// Same as [`KeyWord::prefix`], but returns the keyword directly,
+	/// panicking if invalid. See [`KeyWord::command_unwrap`] for an example.
+	///
+	/// ## Panics
+	///
+	/// This will panic if the key is invalid.
+	pub const fn prefix_unwrap(keyword: &'static str) -> Self {
+		match Self::prefix(keyword) {
+			Some(k) => k,
+			None => panic!("Invalid key."),
+		}
+	}
+}
+
+impl KeyWord {
+	#[must_use]
+	/// # As String Slice.
+	///
+	/// Return the keyword's inner value. (For aliases, this is the alias
+	/// itself rather than its canonical target.)
+	pub const fn as_str(&self) -> &'static str {
+		match self {
+			Self::Command(s) | Self::Key(s) | Self::KeyWithValue(s) |
+			Self::KeyWithOptionalValue(s) | Self::Prefix(s) | Self::Alias(s, _) => s,
+		}
+	}
+
+	#[must_use]
+	/// # To Spec String.
+	///
+	/// Serialize the keyword as a short, stable string — `cmd:build`,
+	/// `key:--help`, `kv:--out`, `opt:--color`, `prefix:-O`, or
+	/// `alias:-h=--help` — suitable for handing off to another process.
+	///
+	/// Use [`KeyWord::from_spec_string`] to reverse the process.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use argyle::KeyWord;
+	///
+	/// assert_eq!(KeyWord::Command("build").to_spec_string(), "cmd:build");
+	/// assert_eq!(KeyWord::KeyWithValue("--out").to_spec_string(), "kv:--out");
+	/// assert_eq!(KeyWord::Alias("-h", "--help").to_spec_string(), "alias:-h=--help");
+	/// ```
+	pub fn to_spec_string(&self) -> String {
+		match self {
+			Self::Command(s) => format!("cmd:{s}"),
+			Self::Key(s) => format!("key:{s}"),
+			Self::KeyWithValue(s) => format!("kv:{s}"),
+			Self::KeyWithOptionalValue(s) => format!("opt:{s}"),
+			Self::Prefix(s) => format!("prefix:{s}"),
+			Self::Alias(a, t) => format!("alias:{a}={t}"),
+		}
+	}
+
+	#[must_use]
+	/// # From Spec String.
+	///
+	/// Parse and validate a keyword serialized by [`KeyWord::to_spec_string`],
+	/// returning `None` if the format or the word is invalid.
+	///
+	/// This allows a parent process to transmit its keyword registry to a
+	/// helper — a completion daemon, GUI wrapper, etc. — which can then
+	/// reconstruct an equivalent parser without recompiling.
+	///
+	/// Keywords borrow their strings for the `'static` lifetime, so specs
+	/// received at runtime will need to be leaked (or otherwise stored for
+	/// the duration), e.g. with `String::leak`.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use argyle::KeyWord;
+	///
+	/// // From the parent…
+	/// let keys = [
+	///     KeyWord::Command("build"),
+	///     KeyWord::Key("--help"),
+	///     KeyWord::Alias("-h", "--help"),
+	/// ];
+	/// let specs: String = keys.iter()
+	///     .map(|k| k.to_spec_string() + "\n")
+	///     .collect();
+	///
+	/// // …to the helper.
+	/// let specs: &'static str = specs.leak();
+	/// let parsed: Option<Vec<KeyWord>> = specs.lines()
+	///     .map(KeyWord::from_spec_string)
+	///     .collect();
+	/// assert_eq!(parsed.as_deref(), Some(&keys[..]));
+	///
+	/// // Nonsense is rejected.
+	/// assert!(KeyWord::from_spec_string("cmd:--help").is_none());
+	/// assert!(KeyWord::from_spec_string("flag:--help").is_none());
+	/// ```
+	pub fn from_spec_string(spec: &'static str) -> Option<Self> {
+		let (kind, word) = spec.split_once(':')?;
+		match kind {
+			"cmd" => Self::command(word),
+			"key" => Self::key(word),
+			"kv" => Self::key_with_value(word),
+			"opt" => Self::key_with_optional_value(word),
+			"prefix" => Self::prefix(word),
+			"alias" => {
+				let (a, t) = word.split_once('=')?;
+				let valid = |w: &str| valid_key(w.as_bytes()) || valid_command(w.as_bytes());
+				if valid(a) && valid(t) { Some(Self::Alias(a, t)) }
+				else { None }
+			},
+			_ => None,
+		}
+	}
+}
+
+
+
+#[derive(Debug, Default, Clone)]
+/// # Compile-Time [`KeyWord`]s Codegen.
+///
+/// This struct can be used by build scripts to generate a [`KeyWord`] array
+/// suitable for use with [`Argue::with_keywords`](crate::Argue::with_keywords).
+///
+/// It provides the same semantic safety guarantees as [`KeyWord::key`]
+/// and family, but at compile-time, eliminating the (mild) runtime overhead.
+///
+/// The builder also frees you from [`KeyWord`]'s usual `&'static` lifetime
+/// constraints, allowing for more programmatic population.
+///
+/// The `push_*` and `set_*` methods panic on invalid input, which is
+/// usually what you want in a build script. For builders populated from
+/// user data at runtime, each has a `try_*` counterpart that returns the
+/// problem as an error instead.
+///
+/// ## Examples
+///
+/// ```
+/// use argyle::KeyWordsBuilder;
+///
+/// // Start by adding your keywords.
+/// let mut words = KeyWordsBuilder::default();
+/// for i in 0..10_u8 {
+///     words.push_key(format!("-{i}")); // Automation for the win!
+/// }
+/// ```
+///
+/// You can grab a copy of the code by leveraging `Display::to_string`, but in
+/// most cases you'll probably just want to use [`KeyWordsBuilder::save`] to write
+/// it straight to a file:
+///
+/// ```ignore
+/// let out_dir: &Path = std::env::var("OUT_DIR").unwrap().as_ref();
+/// words.save(out_dir.join("keyz.rs"));
+/// ```
+///
+/// Having done that, just `include!` it where needed:
+///
+/// ```ignore
+/// let ags = argyle::args()
+///     .with_keywords(include!(concat!(env!("OUT_DIR"), "/keyz.rs")));
+/// ```
+pub struct KeyWordsBuilder(BTreeMap<String, KeyWordMeta>);
+
+impl fmt::Display for KeyWordsBuilder {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.write_str("[")?;
+
+		let mut iter = self.0.iter();
+		if let Some((k, v)) = iter.next() {
+			// Write the first value.
+			v.kind.write_code(f, k)?;
+
+			// Write the rest with leading comma/space separators.
+			for (k, v) in iter {
+				f.write_str(", ")?;
+				v.kind.write_code(f, k)?;
+			}
+		}
+
+		f.write_str("]")
+	}
+}
+
+impl KeyWordsBuilder {
+	#[inline]
+	#[must_use]
+	/// # Is Empty?
+	///
+	/// Returns `true` if there are no keywords.
+	pub fn is_empty(&self) -> bool { self.0.is_empty() }
+
+	#[inline]
+	#[must_use]
+	/// # Length.
+	///
+	/// Returns the number of keywords currently in the set.
+	pub fn len(&self) -> usize { self.0.len() }
+}
+
+impl KeyWordsBuilder {
+	/// # Add Keyword.
+	///
+	/// Add a keyword, ensuring the string portion is unique.
+	///
+	/// ## Errors
+	///
+	/// This will return an error if the string part is not unique.
+	fn try_push(&mut self, k: &str, kind: KeyKind) -> Result<(), String> {
+		if self.0.contains_key(k) { return Err(format!("Duplicate key: {k}")); }
+		self.0.insert(k.to_owned(), KeyWordMeta { kind, help: None, hint: CompletionHint::None });
+		Ok(())
+	}
+
+	/// # Add a Command.
+	///
+	/// Use this to add a [`KeyWord::Command`] to the list.
+	///
+	/// ## Panics
+	///
+	/// This will panic if the command is invalid or repeated;
+	pub fn push_command<S: AsRef<str>>(&mut self, key: S) {
+		if let Err(e) = self.try_push_command(key) { panic!("{e}"); }
+	}
+
+	/// # Add Commands.
+	///
+	/// Use this to add one or more [`KeyWord::Command`] to the list.
+	///
+	/// ## Panics
+	///
+	/// This will panic if any commands are invalid or repeated;
+	pub fn push_commands<I: IntoIterator<Item=S>, S: AsRef<str>>(&mut self, keys: I) {
+		for k in keys { self.push_command(k); }
+	}
+
+	/// # Add a Boolean Key.
+	///
+	/// Use this to add a [`KeyWord::Key`] to the list.
+	///
+	/// ## Panics
+	///
+	/// This will panic if the key is invalid or repeated.
+	pub fn push_key<S: AsRef<str>>(&mut self, key: S) {
+		if let Err(e) = self.try_push_key(key) { panic!("{e}"); }
+	}
+
+	/// # Add Boolean Keys.
+	///
+	/// Use this to add one or more [`KeyWord::Key`] to the list.
+	///
+	/// ## Panics
+	///
+	/// This will panic if any keys are invalid or repeated.
+	pub fn push_keys<I: IntoIterator<Item=S>, S: AsRef<str>>(&mut self, keys: I) {
+		for k in keys { self.push_key(k); }
+	}
+
+	/// # Add a Key that Expects a Value.
+	///
+	/// Use this to add a [`KeyWord::KeyWithValue`] to the list.
+	///
+	/// ## Panics
+	///
+	/// This will panic if the key is invalid or repeated.
+	pub fn push_key_with_value<S: AsRef<str>>(&mut self, key: S) {
+		if let Err(e) = self.try_push_key_with_value(key) { panic!("{e}"); }
+	}
+
+	/// # Add Keys that Expect Values.
+	///
+	/// Use this to add one or more [`KeyWord::KeyWithValue`] to the list.
+	///
+	/// ## Panics
+	///
+	/// This will panic if any keys are invalid or repeated.
+	pub fn push_keys_with_values<I: IntoIterator<Item=S>, S: AsRef<str>>(&mut self, keys: I) {
+		for k in keys { self.push_key_with_value(k); }
+	}
+
+	/// # Add a Key that Accepts an Optional Value.
+	///
+	/// Use this to add a [`KeyWord::KeyWithOptionalValue`] to the list.
+	///
+	/// ## Panics
+	///
+	/// This will panic if the key is invalid or repeated.
+	pub fn push_key_with_optional_value<S: AsRef<str>>(&mut self, key: S) {
+		if let Err(e) = self.try_push_key_with_optional_value(key) { panic!("{e}"); }
+	}
+
+	/// # Add Keys that Accept Optional Values.
+	///
+	/// Use this to add one or more [`KeyWord::KeyWithOptionalValue`] to the
+	/// list.
+	///
+	/// ## Panics
+	///
+	/// This will panic if any keys are invalid or repeated.
+	pub fn push_keys_with_optional_values<I: IntoIterator<Item=S>, S: AsRef<str>>(&mut self, keys: I) {
+		for k in keys { self.push_key_with_optional_value(k); }
+	}
+
+	/// # Add a Prefix Key.
+	///
+	/// Use this to add a [`KeyWord::Prefix`] to the list.
+	///
+	/// ## Panics
+	///
+	/// This will panic if the key is invalid or repeated.
+	pub fn push_prefix<S: AsRef<str>>(&mut self, key: S) {
+		if let Err(e) = self.try_push_prefix(key) { panic!("{e}"); }
+	}
+
+	/// # Add Prefix Keys.
+	///
+	/// Use this to add one or more [`KeyWord::Prefix`] to the list.
+	///
+	/// ## Panics
+	///
+	/// This will panic if any keys are invalid or repeated.
+	pub fn push_prefixes<I: IntoIterator<Item=S>, S: AsRef<str>>(&mut self, keys: I) {
+		for k in keys { self.push_prefix(k); }
+	}
+
+	/// # Add an Alias.
+	///
+	/// Use this to add a [`KeyWord::Alias`] to the list, pointing `key` to
+	/// the (previously-added) `target`.
+	///
+	/// ## Panics
+	///
+	/// This will panic if the key is invalid or repeated, or the target does
+	/// not exist or is itself an alias.
+	pub fn push_alias<S: AsRef<str>, T: AsRef<str>>(&mut self, key: S, target: T) {
+		if let Err(e) = self.try_push_alias(key, target) { panic!("{e}"); }
+	}
+
+	/// # Add Short/Long Key Pairs.
+	///
+	/// Add one or more `(short, long, value, help)` entries in one go. This
+	/// is the builder equivalent of [`KeyWord::pair`]: the long key is added
+	/// as a [`KeyWord::Key`] or [`KeyWord::KeyWithValue`], depending on
+	/// `value`, with the `help` text attached, and the short key is added as
+	/// its [`KeyWord::Alias`].
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use argyle::KeyWordsBuilder;
+	///
+	/// let mut words = KeyWordsBuilder::default();
+	/// words.push_pairs([
+	///     ("-o", "--output", true, "Write results to FILE."),
+	///     ("-v", "--verbose", false, "Print more stuff."),
+	/// ]);
+	///
+	/// assert_eq!(words.len(), 4);
+	/// assert_eq!(
+	///     words.to_string(),
+	///     "[argyle::KeyWord::KeyWithValue(\"--output\"), \
+	///     argyle::KeyWord::Key(\"--verbose\"), \
+	///     argyle::KeyWord::Alias(\"-o\", \"--output\"), \
+	///     argyle::KeyWord::Alias(\"-v\", \"--verbose\")]",
+	/// );
+	/// ```
+	///
+	/// ## Panics
+	///
+	/// This will panic if either key is invalid or repeated, or if there
+	/// isn't exactly one short and one long key.
+	pub fn push_pairs<I, S1, S2, H>(&mut self, pairs: I)
+	where
+		I: IntoIterator<Item=(S1, S2, bool, H)>,
+		S1: AsRef<str>,
+		S2: AsRef<str>,
+		H: AsRef<str>,
+	{
+		for (a, b, value, help) in pairs {
+			let a: &str = a.as_ref().trim();
+			let b: &str = b.as_ref().trim();
+			let (short, long) =
+				if a.len() == 2 { (a, b) }
+				else { (b, a) };
+			assert!(short.len() == 2 && long.len() > 2, "Invalid pair: {a}/{b}");
+
+			if value { self.push_key_with_value(long); }
+			else { self.push_key(long); }
+			self.set_help(long, help);
+			self.push_alias(short, long);
+		}
+	}
+
+	/// # Set Help Text.
+	///
+	/// Attach a short description to a previously-added keyword. This is
+	/// used by [`KeyWordsBuilder::to_markdown`].
+	///
+	/// ## Panics
+	///
+	/// This will panic if the keyword has not been added.
+	pub fn set_help<S: AsRef<str>, H: AsRef<str>>(&mut self, key: S, help: H) {
+		if let Err(e) = self.try_set_help(key, help) { panic!("{e}"); }
+	}
+
+	/// # Set Completion Hint.
+	///
+	/// Describe the kind of value a [`KeyWord::KeyWithValue`] or
+	/// [`KeyWord::KeyWithOptionalValue`] expects, for the benefit of shell
+	/// completion generators and documentation.
+	///
+	/// ## Panics
+	///
+	/// This will panic if the keyword has not been added, or does not take
+	/// a value.
+	pub fn set_hint<S: AsRef<str>>(&mut self, key: S, hint: CompletionHint) {
+		if let Err(e) = self.try_set_hint(key, hint) { panic!("{e}"); }
+	}
+}
+
+impl KeyWordsBuilder {
+	/// # Try to Add a Command.
+	///
+	/// Same as [`KeyWordsBuilder::push_command`], but returns an error
+	/// instead of panicking.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use argyle::KeyWordsBuilder;
+	///
+	/// let mut words = KeyWordsBuilder::default();
+	/// assert!(words.try_push_command("build").is_ok());
+	/// assert_eq!(
+	///     words.try_push_command("build"),
+	///     Err("Duplicate key: build".to_owned()),
+	/// );
+	/// assert_eq!(
+	///     words.try_push_command("--build"),
+	///     Err("Invalid command: --build".to_owned()),
+	/// );
+	/// assert_eq!(words.len(), 1);
+	/// ```
+	///
+	/// ## Errors
+	///
+	/// This will return an error if the command is invalid or repeated.
+	pub fn try_push_command<S: AsRef<str>>(&mut self, key: S) -> Result<(), String> {
+		let k: &str = key.as_ref().trim();
+		if valid_command(k.as_bytes()) { self.try_push(k, KeyKind::Command) }
+		else { Err(format!("Invalid command: {k}")) }
+	}
+
+	/// # Try to Add a Boolean Key.
+	///
+	/// Same as [`KeyWordsBuilder::push_key`], but returns an error instead
+	/// of panicking.
+	///
+	/// ## Errors
+	///
+	/// This will return an error if the key is invalid or repeated.
+	pub fn try_push_key<S: AsRef<str>>(&mut self, key: S) -> Result<(), String> {
+		self.try_push_valid_key(key.as_ref(), KeyKind::Key)
+	}
+
+	/// # Try to Add a Key that Expects a Value.
+	///
+	/// Same as [`KeyWordsBuilder::push_key_with_value`], but returns an
+	/// error instead of panicking.
+	///
+	/// ## Errors
+	///
+	/// This will return an error if the key is invalid or repeated.
+	pub fn try_push_key_with_value<S: AsRef<str>>(&mut self, key: S) -> Result<(), String> {
+		self.try_push_valid_key(key.as_ref(), KeyKind::KeyWithValue)
+	}
+
+	/// # Try to Add a Key that Accepts an Optional Value.
+	///
+	/// Same as [`KeyWordsBuilder::push_key_with_optional_value`], but returns
+	/// an error instead of panicking.
+	///
+	/// ## Errors
+	///
+	/// This will return an error if the key is invalid or repeated.
+	pub fn try_push_key_with_optional_value<S: AsRef<str>>(&mut self, key: S)
+	-> Result<(), String> {
+		self.try_push_valid_key(key.as_ref(), KeyKind::KeyWithOptionalValue)
+	}
+
+	/// # Try to Add a Prefix Key.
+	///
+	/// Same as [`KeyWordsBuilder::push_prefix`], but returns an error
+	/// instead of panicking.
+	///
+	/// ## Errors
+	///
+	/// This will return an error if the key is invalid or repeated.
+	pub fn try_push_prefix<S: AsRef<str>>(&mut self, key: S) -> Result<(), String> {
+		self.try_push_valid_key(key.as_ref(), KeyKind::Prefix)
+	}
+
+	/// # Try to Add an Alias.
+	///
+	/// Same as [`KeyWordsBuilder::push_alias`], but returns an error instead
+	/// of panicking.
+	///
+	/// ## Errors
+	///
+	/// This will return an error if the key is invalid or repeated, or the
+	/// target does not exist or is itself an alias.
+	pub fn try_push_alias<S: AsRef<str>, T: AsRef<str>>(&mut self, key: S, target: T)
+	-> Result<(), String> {
+		let k: &str = key.as_ref().trim();
+		let t: &str = target.as_ref().trim();
+		if ! valid_key(k.as_bytes()) { return Err(format!("Invalid key: {k}")); }
+		match self.0.get(t).map(|meta| &meta.kind) {
+			Some(KeyKind::Alias(_)) => Err(format!("Alias points to another alias: {t}")),
+			Some(_) => self.try_push(k, KeyKind::Alias(t.to_owned())),
+			None => Err(format!("Unknown key: {t}")),
+		}
+	}
+
+	/// # Try to Set Help Text.
+	///
+	/// Same as [`KeyWordsBuilder::set_help`], but returns an error instead
+	/// of panicking.
+	///
+	/// ## Errors
+	///
+	/// This will return an error if the keyword has not been added.
+	pub fn try_set_help<S: AsRef<str>, H: AsRef<str>>(&mut self, key: S, help: H)
+	-> Result<(), String> {
+		let k: &str = key.as_ref().trim();
+		let Some(meta) = self.0.get_mut(k) else { return Err(format!("Unknown key: {k}")); };
+		meta.help.replace(help.as_ref().trim().to_owned());
+		Ok(())
+	}
+
+	/// # Try to Set Completion Hint.
+	///
+	/// Same as [`KeyWordsBuilder::set_hint`], but returns an error instead
+	/// of panicking.
+	///
+	/// ## Errors
+	///
+	/// This will return an error if the keyword has not been added, or does
+	/// not take a value.
+	pub fn try_set_hint<S: AsRef<str>>(&mut self, key: S, hint: CompletionHint)
+	-> Result<(), String> {
+		let k: &str = key.as_ref().trim();
+		let Some(meta) = self.0.get_mut(k) else { return Err(format!("Unknown key: {k}")); };
+		if ! matches!(meta.kind, KeyKind::KeyWithValue | KeyKind::KeyWithOptionalValue) {
+			return Err(format!("Key does not take a value: {k}"));
+		}
+		meta.hint = hint;
+		Ok(())
+	}
+
+	/// # Try to Add a (Valid) Key.
+	///
+	/// Validate and add a non-command, non-alias keyword.
+	///
+	/// ## Errors
+	///
+	/// This will return an error if the key is invalid or repeated.
+	fn try_push_valid_key(&mut self, key: &str, kind: KeyKind) -> Result<(), String> {
+		let k: &str = key.trim();
+		if valid_key(k.as_bytes()) { self.try_push(k, kind) }
+		else { Err(format!("Invalid key: {k}")) }
+	}
+}
+
+impl KeyWordsBuilder {
+	#[must_use]
+	/// # Completion Hint.
+	///
+	/// Return the [`CompletionHint`] for a given keyword, if it exists.
+	///
+	/// Keys that do not take values are always [`CompletionHint::None`].
+	pub fn hint(&self, key: &str) -> Option<&CompletionHint> {
+		self.0.get(key.trim()).map(|meta| &meta.hint)
+	}
+}
+
+impl KeyWordsBuilder {
+	#[must_use]
+	/// # Markdown Table.
+	///
+	/// Generate a Markdown table listing each keyword, its kind, and any
+	/// help text added via [`KeyWordsBuilder::set_help`], suitable for
+	/// embedding in a README or other documentation.
+	///
+	/// Value placeholders are included for keys with a [`CompletionHint`].
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use argyle::{CompletionHint, KeyWordsBuilder};
+	///
+	/// let mut words = KeyWordsBuilder::default();
+	/// words.push_key("--help");
+	/// words.push_key_with_value("--output");
+	/// words.set_help("--output", "Write results to this file.");
+	/// words.set_hint("--output", CompletionHint::File);
+	///
+	/// assert_eq!(
+	///     words.to_markdown(),
+	///     "| Keyword | Kind | Description |\n\
+	///      | ------- | ---- | ----------- |\n\
+	///      | `--help` | Key |  |\n\
+	///      | `--output <FILE>` | KeyWithValue | Write results to this file. |\n",
+	/// );
+	/// ```
+	pub fn to_markdown(&self) -> String {
+		use fmt::Write;
+
+		let mut out = String::from("| Keyword | Kind | Description |\n| ------- | ---- | ----------- |\n");
+		for (k, v) in &self.0 {
+			let help = match (&v.help, &v.kind) {
+				(Some(help), _) => help.replace('|', "\\|").replace(['\r', '\n'], " "),
+				(None, KeyKind::Alias(t)) => format!("Alias for `{t}`."),
+				(None, _) => String::new(),
+			};
+			let _res = match &v.hint {
+				CompletionHint::None => writeln!(&mut out, "| `{k}` | {} | {help} |", v.kind.as_str()),
+				hint => writeln!(&mut out, "| `{k} {hint}` | {} | {help} |", v.kind.as_str()),
+			};
+		}
+		out
+	}
+
+	#[must_use]
+	/// # Diff.
+	///
+	/// Compare this (older) keyword set against a `newer` one, reporting any
+	/// keys that were added, removed, or changed.
+	///
+	/// A key is considered changed if its kind differs — e.g. a
+	/// [`KeyWord::Key`] that became a [`KeyWord::KeyWithValue`] — or, for
+	/// aliases, if its target differs. Help text and completion hints are
+	/// cosmetic, and ignored.
+	///
+	/// This is mainly intended for CI checks guarding against a release
+	/// accidentally dropping or repurposing a flag.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use argyle::KeyWordsBuilder;
+	///
+	/// let mut old = KeyWordsBuilder::default();
+	/// old.push_keys(["-q", "-v"]);
+	/// old.push_alias("--quiet", "-q");
+	///
+	/// let mut new = KeyWordsBuilder::default();
+	/// new.push_key("-q");
+	/// new.push_key_with_value("-v");
+	/// new.push_key_with_value("-o");
+	/// new.push_alias("--quiet", "-q");
+	///
+	/// let diff = old.diff(&new);
+	/// assert_eq!(diff.added, ["-o"]);
+	/// assert!(diff.removed.is_empty());
+	/// assert_eq!(diff.changed, ["-v"]);
+	/// assert!(diff.is_breaking());
+	/// ```
+	pub fn diff(&self, newer: &Self) -> KeyWordsDiff {
+		let mut out = KeyWordsDiff::default();
+		for (k, v) in &self.0 {
+			match newer.0.get(k) {
+				Some(v2) if v.kind == v2.kind => {},
+				Some(_) => { out.changed.push(k.clone()); },
+				None => { out.removed.push(k.clone()); },
+			}
+		}
+		out.added.extend(newer.0.keys().filter(|k| ! self.0.contains_key(*k)).cloned());
+		out
+	}
+
+	#[must_use]
+	/// # Commands Enum.
+	///
+	/// Generate the code for a fieldless enum named `name` with a variant
+	/// for each [`KeyWord::Command`] in the set — `build-all` becomes
+	/// `BuildAll`, etc. — so apps can match on something more robust than
+	/// strings.
+	///
+	/// The enum includes an `ALL` constant listing every variant, `as_str`
+	/// for going one way, and `from_str` for going the other — e.g. from the
+	/// value of an [`Argument::Command`](crate::Argument::Command).
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use argyle::KeyWordsBuilder;
+	///
+	/// let mut words = KeyWordsBuilder::default();
+	/// words.push_commands(["build", "check"]);
+	/// words.push_key("-v");
+	///
+	/// let code = words.commands_enum("Command");
+	/// assert!(code.contains("pub enum Command {"));
+	/// assert!(code.contains("\tBuild,\n"));
+	/// assert!(code.contains("\"check\" => Some(Self::Check),"));
+	/// ```
+	///
+	/// ## Panics
+	///
+	/// This will panic if `name` is not a valid type name, or if two commands
+	/// would produce the same variant name.
+	pub fn commands_enum(&self, name: &str) -> String {
+		use fmt::Write;
+
+		assert!(is_ident(name), "Invalid enum name: {name}");
+
+		// Map the commands to variants.
+		let mut variants = BTreeMap::<String, &str>::new();
+		for (k, v) in &self.0 {
+			if matches!(v.kind, KeyKind::Command) {
+				let variant = variant_name(k);
+				assert!(! variants.contains_key(&variant), "Duplicate variant: {variant}");
+				variants.insert(variant, k);
+			}
+		}
+
+		let mut out = format!(
+			"#[derive(Debug, Clone, Copy, Eq, Hash, Ord, PartialEq, PartialOrd)]\n\
+			/// # Commands.\n\
+			pub enum {name} {{\n",
+		);
+		for v in variants.keys() { let _res = writeln!(&mut out, "\t{v},"); }
+		let _res = write!(
+			&mut out,
+			"}}\n\n\
+			impl {name} {{\n\
+			\t/// # All Commands.\n\
+			\tpub const ALL: [Self; {}] = [",
+			variants.len(),
+		);
+		for (i, v) in variants.keys().enumerate() {
+			if i != 0 { out.push_str(", "); }
+			let _res = write!(&mut out, "Self::{v}");
+		}
+		out.push_str(
+			"];\n\n\
+			\t#[must_use]\n\
+			\t/// # As String Slice.\n\
+			\tpub const fn as_str(self) -> &'static str {\n\
+			\t\tmatch self {\n",
+		);
+		for (v, k) in &variants { let _res = writeln!(&mut out, "\t\t\tSelf::{v} => {k:?},"); }
+		out.push_str(
+			"\t\t}\n\
+			\t}\n\n\
+			\t#[must_use]\n\
+			\t#[expect(clippy::should_implement_trait, reason = \"Lookups return an Option, not a Result.\")]\n\
+			\t/// # From String Slice.\n\
+			\tpub fn from_str(src: &str) -> Option<Self> {\n\
+			\t\tmatch src {\n",
+		);
+		for (v, k) in &variants { let _res = writeln!(&mut out, "\t\t\t{k:?} => Some(Self::{v}),"); }
+		out.push_str("\t\t\t_ => None,\n\t\t}\n\t}\n}\n");
+		out
+	}
+
+	#[must_use]
+	/// # Constant Array Codegen.
+	///
+	/// Same as the `Display` output, but wrapped in a `pub(crate) const`
+	/// declaration with an explicit type and length, so it can be used
+	/// directly as an item — e.g. shared between modules, or referenced from
+	/// a `static` — rather than only as an expression.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use argyle::KeyWordsBuilder;
+	///
+	/// let mut words = KeyWordsBuilder::default();
+	/// words.push_keys(["-h", "--help"]);
+	///
+	/// assert_eq!(
+	///     words.const_array("KEYWORDS"),
+	///     "/// # Keywords.
+	/// pub(crate) const KEYWORDS: [argyle::KeyWord; 2] = [argyle::KeyWord::Key(\"--help\"), argyle::KeyWord::Key(\"-h\")];
+	/// ",
+	/// );
+	/// ```
+	///
+	/// ## Panics
+	///
+	/// This will panic if `name` is not a valid identifier.
+	pub fn const_array(&self, name: &str) -> String {
+		assert!(is_ident(name), "Invalid constant name: {name}");
+		format!(
+			"/// # Keywords.\npub(crate) const {name}: [argyle::KeyWord; {}] = {self};\n",
+			self.len(),
+		)
+	}
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl KeyWordsBuilder {
+	/// # Save it to a File!
+	///
+	/// Generate and save the [`KeyWord`] array code to the specified file.
+	///
+	/// Note that many environments prohibit writes to arbitrary locations; for
+	/// best results, your path should be somewhere under `OUT_DIR`.
+	///
+	/// ## Examples
+	///
+	/// ```ignore
+	/// let out_dir: &Path = std::env::var("OUT_DIR").unwrap().as_ref();
+	/// words.save(out_dir.join("keyz.rs"));
+	/// ```
+	///
+	/// ## Panics
+	///
+	/// This method will panic if the write fails for any reason.
+	pub fn save<P: AsRef<Path>>(&self, file: P) {
+		save_code(file.as_ref(), &self.to_string());
+	}
+
+	/// # Save Commands Enum to a File!
+	///
+	/// Generate and save the [`KeyWordsBuilder::commands_enum`] code to the
+	/// specified file. As with [`KeyWordsBuilder::save`], `include!` it
+	/// where needed.
+	///
+	/// ## Examples
+	///
+	/// ```ignore
+	/// let out_dir: &Path = std::env::var("OUT_DIR").unwrap().as_ref();
+	/// words.save_commands_enum("Command", out_dir.join("commands.rs"));
+	/// ```
+	///
+	/// ## Panics
+	///
+	/// This method will panic if the name is invalid, variants collide, or
+	/// the write fails for any reason.
+	pub fn save_commands_enum<P: AsRef<Path>>(&self, name: &str, file: P) {
+		save_code(file.as_ref(), &self.commands_enum(name));
+	}
+
+	/// # Save Constant Array to a File!
+	///
+	/// Generate and save the [`KeyWordsBuilder::const_array`] code to the
+	/// specified file. Unlike [`KeyWordsBuilder::save`], the result is an
+	/// item rather than an expression, so should be `include!`d at the
+	/// module level.
+	///
+	/// ## Examples
+	///
+	/// ```ignore
+	/// // build.rs
+	/// let out_dir: &Path = std::env::var("OUT_DIR").unwrap().as_ref();
+	/// words.save_const(out_dir.join("keyz-const.rs"), "KEYWORDS");
+	///
+	/// // main.rs
+	/// include!(concat!(env!("OUT_DIR"), "/keyz-const.rs"));
+	///
+	/// let args = argyle::args().with_keywords(KEYWORDS);
+	/// ```
+	///
+	/// ## Panics
+	///
+	/// This method will panic if the name is invalid or the write fails for
+	/// any reason.
+	pub fn save_const<P: AsRef<Path>>(&self, file: P, name: &str) {
+		save_code(file.as_ref(), &self.const_array(name));
+	}
+
+	/// # Save Keyword Table to a File!
+	///
+	/// Generate and save the [`KeyWordsBuilder::phf_table`] code to the
+	/// specified file, for assignment to a `static` [`KeyWordTable`].
+	///
+	/// ## Examples
+	///
+	/// ```ignore
+	/// let out_dir: &Path = std::env::var("OUT_DIR").unwrap().as_ref();
+	/// words.save_phf(out_dir.join("keyz-table.rs"));
+	/// ```
+	///
+	/// ## Panics
+	///
+	/// This method will panic if the write fails for any reason.
+	pub fn save_phf<P: AsRef<Path>>(&self, file: P) {
+		save_code(file.as_ref(), &self.phf_table());
+	}
+}
+
+#[cfg(feature = "std")]
+/// # Save Code.
+///
+/// Write `code` to `file`.
+///
+/// ## Panics
+///
+/// This will panic if the write fails for any reason.
+fn save_code(file: &Path, code: &str) {
+	use std::io::Write;
+
+	assert!(
+		std::fs::File::create(file).and_then(|mut out|
+			out.write_all(code.as_bytes()).and_then(|()| out.flush())
+		).is_ok(),
+		"Unable to write to {}.",
+		file.display(),
+	);
+}
+
+
+
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+/// # Completion Hint.
+///
+/// This describes the kind of value expected by a [`KeyWord::KeyWithValue`],
+/// for the benefit of shell completion generators and documentation. See
+/// [`KeyWordsBuilder::set_hint`].
+///
+/// When displayed, hints are formatted as value placeholders, like `<FILE>`
+/// or `{json,yaml}`.
+pub enum CompletionHint {
+	#[default]
+	/// # No Hint.
+	None,
+
+	/// # File Path.
+	File,
+
+	/// # Directory Path.
+	Dir,
+
+	/// # Host Name.
+	Host,
+
+	/// # One of a Fixed Set of Choices.
+	Choice(Vec<String>),
+}
+
+impl fmt::Display for CompletionHint {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Self::None => f.write_str("<VALUE>"),
+			Self::File => f.write_str("<FILE>"),
+			Self::Dir => f.write_str("<DIR>"),
+			Self::Host => f.write_str("<HOST>"),
+			Self::Choice(all) => {
+				f.write_str("{")?;
+				for (k, v) in all.iter().enumerate() {
+					if k != 0 { f.write_str(",")?; }
+					f.write_str(v)?;
+				}
+				f.write_str("}")
+			},
+		}
+	}
+}
+
+
+
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+/// # Keyword Set Differences.
+///
+/// This is returned by [`KeyWordsBuilder::diff`]. Each list is sorted.
+pub struct KeyWordsDiff {
+	/// # Added Keys.
+	pub added: Vec<String>,
+
+	/// # Removed Keys.
+	pub removed: Vec<String>,
+
+	/// # Changed Keys.
+	///
+	/// Keys whose kind (or alias target) changed.
+	pub changed: Vec<String>,
+}
+
+impl KeyWordsDiff {
+	#[must_use]
+	/// # Is Empty?
+	///
+	/// Returns `true` if the two sets were functionally identical.
+	pub fn is_empty(&self) -> bool {
+		self.added.is_empty() && ! self.is_breaking()
+	}
+
+	#[must_use]
+	/// # Is Breaking?
+	///
+	/// Returns `true` if any keys were removed or changed. (Additions alone
+	/// are backward-compatible.)
+	pub fn is_breaking(&self) -> bool {
+		! self.removed.is_empty() || ! self.changed.is_empty()
+	}
+}
+
+
+
+#[derive(Debug, Clone)]
+/// # Keyword Metadata.
+///
+/// This holds the details for each [`KeyWordsBuilder`] entry.
+struct KeyWordMeta {
+	/// # Kind.
+	kind: KeyKind,
+
+	/// # Help Text.
+	help: Option<String>,
+
+	/// # Completion Hint.
+	hint: CompletionHint,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+/// # Keyword Kind.
+///
+/// This mirrors the [`KeyWord`] variants for the benefit of
+/// [`KeyWordsBuilder`].
+enum KeyKind {
+	/// # (Sub)command.
+	Command,
+
+	/// # Boolean key.
+	Key,
+
+	/// # Key with Value.
+	KeyWithValue,
+
+	/// # Key with Optional Value.
+	KeyWithOptionalValue,
+
+	/// # Prefix.
+	Prefix,
+
+	/// # Alias (and Target).
+	Alias(String),
+}
+
+impl KeyKind {
+	/// # As String Slice.
+	///
+	/// Return the corresponding [`KeyWord`] variant name.
+	const fn as_str(&self) -> &'static str {
+		match self {
+			Self::Command => "Command",
+			Self::Key => "Key",
+			Self::KeyWithValue => "KeyWithValue",
+			Self::KeyWithOptionalValue => "KeyWithOptionalValue",
+			Self::Prefix => "Prefix",
+			Self::Alias(_) => "Alias",
+		}
+	}
+
+	/// # Write Code.
+	///
+	/// Write the [`KeyWord`] code for key `k`.
+	fn write_code<W: fmt::Write>(&self, f: &mut W, k: &str) -> fmt::Result {
+		if let Self::Alias(t) = self { write!(f, "argyle::KeyWord::Alias({k:?}, {t:?})") }
+		else { write!(f, "argyle::KeyWord::{}({k:?})", self.as_str()) }
+	}
+}
+
+
+
+#[must_use]
+/// # Match Keyword.
+///
+/// Find the keyword matching `raw` — a single CLI argument — returning it
+/// along with any value glued onto the end, like the `val` in `-kval`,
+/// `-k=val`, or `--key=val`. (The `=` separator, if any, is not included.)
+///
+/// Aliases are resolved to their canonical keywords.
+///
+/// This is the same matching logic used by [`Argue`](crate::Argue), exposed
+/// for environments without `std` (and anybody else who might need it).
+///
+/// ## Guarantees
+///
+/// * Short keys are split after their second byte, dropping a single `=` if it comes next, so `-k==v` has the value `=v`.
+/// * Long keys are only split at the _first_ `=`, so `--key=a=b` has the value `a=b`.
+/// * Prefix keys are split right after the prefix, keeping everything else verbatim, so `-W=all` has the value `=all`; ordinary keys take priority, and the longest prefix wins.
+/// * Splits only ever happen at ASCII boundaries, so the value of a valid UTF-8 argument is always valid UTF-8 too.
+/// * Commands never have values; `make=1` — or `-m1` where `-m` aliases `make` — is not a match.
+/// * Near-keys like `-=x`, `--=x`, and `---key=x` never match anything.
+///
+/// ## Examples
+///
+/// ```
+/// use argyle::KeyWord;
+/// use std::collections::BTreeSet;
+///
+/// let keys = BTreeSet::from([
+///     KeyWord::key("-v").unwrap(),
+///     KeyWord::key_with_value("--out").unwrap(),
+/// ]);
+///
+/// assert_eq!(
+///     argyle::match_keyword(&keys, b"-v"),
+///     Some((KeyWord::Key("-v"), None)),
+/// );
+/// assert_eq!(
+///     argyle::match_keyword(&keys, b"--out=file.txt"),
+///     Some((KeyWord::KeyWithValue("--out"), Some(&b"file.txt"[..]))),
+/// );
+/// assert_eq!(argyle::match_keyword(&keys, b"--in=file.txt"), None);
+/// ```
+pub fn match_keyword<'a>(keys: &BTreeSet<KeyWord>, raw: &'a [u8])
+-> Option<(KeyWord, Option<&'a [u8]>)> {
+	match_with(raw, |k| keys.get(k).copied())
+}
+
+/// # Match Keyword (Generic).
+///
+/// This is the guts of [`match_keyword`], abstracted over the lookup so it
+/// can be shared with [`KeyWordTable`].
+fn match_with<F>(raw: &[u8], get: F) -> Option<(KeyWord, Option<&[u8]>)>
+where F: Fn(&str) -> Option<KeyWord> {
+	// Short circuit; keywords must start with a dash or alphanumeric.
+	let [first, ..] = raw else { return None; };
+	if ! (*first == b'-' || first.is_ascii_alphanumeric()) { return None; }
+
+	// Direct hit!
+	let key =
+		if let Some(key) = core::str::from_utf8(raw).ok().and_then(&get) { key }
+		// Keylike strings could have a value gumming up the works; separate
+		// and try again if that is the case.
+		else if let Some((needle, value)) = split_keylike(raw) {
+			value.and_then(|_| core::str::from_utf8(needle).ok())
+				.and_then(&get)
+				// Long prefixes can end anywhere.
+				.or_else(|| long_prefix(raw, &get))?
+		}
+		else { return None; };
+
+	// Sort out the value, if any. (Prefixes keep everything verbatim.)
+	let rest = &raw[key.as_str().len()..];
+	let key =
+		if let KeyWord::Alias(_, canonical) = key {
+			match get(canonical) {
+				Some(KeyWord::Alias(_, _)) | None => return None,
+				Some(key) => key,
+			}
+		}
+		else { key };
+	let value = match (rest, key) {
+		([], _) => None,
+		(rest, KeyWord::Prefix(_)) | ([b'=', rest @ ..] | rest, _) => Some(rest),
+	};
+
+	// Commands can't have values, even by way of an alias.
+	if value.is_some() && matches!(key, KeyWord::Command(_)) { None }
+	else { Some((key, value)) }
+}
+
+#[expect(clippy::redundant_pub_crate, reason = "A bare pub would trip unreachable_pub.")]
+/// # Split Keylike.
+///
+/// Split a key-like argument into its key and value parts based purely on
+/// its shape: long keys end at the first `=`, short keys after the second
+/// byte (with or without an `=` in between). Returns `None` if `raw` isn't
+/// key-like.
+///
+/// This is the lexical ground truth shared by [`match_keyword`] and
+/// [`Tokens`](crate::Tokens).
+pub(crate) fn split_keylike(raw: &[u8]) -> Option<(&[u8], Option<&[u8]>)> {
+	if ! keylike(raw) { return None; }
+	let (key, rest) =
+		// Long keys can only have values if there's an = sign in there
+		// somewhere.
+		if raw[1] == b'-' {
+			raw.iter().position(|&b| b == b'=').map_or((raw, &[][..]), |pos| raw.split_at(pos))
+		}
+		// Short keys can only be two bytes.
+		else { raw.split_at(2) };
+
+	let value = match rest {
+		[] => None,
+		[b'=', rest @ ..] | rest => Some(rest),
+	};
+	Some((key, value))
+}
+
+/// # Long Prefix.
+///
+/// Return the longest [`KeyWord::Prefix`] (long-style) that `raw` starts
+/// with, if any.
+fn long_prefix<F>(raw: &[u8], get: F) -> Option<KeyWord>
+where F: Fn(&str) -> Option<KeyWord> {
+	if ! raw.starts_with(b"--") { return None; }
+	(3..raw.len()).rev().find_map(|len|
+		core::str::from_utf8(&raw[..len]).ok()
+			.and_then(&get)
+			.filter(|k| matches!(k, KeyWord::Prefix(_)))
+	)
+}
+
+#[must_use]
+/// # Starts Like a Key?
+///
+/// Returns `true` if `raw` begins like a short or long key — a dash and an
+/// ASCII alphanumeric, or two dashes and an ASCII alphanumeric — regardless
+/// of what follows.
+///
+/// This is the same check [`Argue`](crate::Argue) uses to decide whether or
+/// not an argument might be a key with a value attached, so can be useful
+/// for keeping custom pre-filters and fallbacks in agreement.
+///
+/// ## Examples
+///
+/// ```
+/// assert!(argyle::starts_like_key("-v"));
+/// assert!(argyle::starts_like_key("-j4"));
+/// assert!(argyle::starts_like_key("--threads=4"));
+/// assert!(argyle::starts_like_key("-1")); // Negative numbers look like keys!
+///
+/// assert!(! argyle::starts_like_key("-"));
+/// assert!(! argyle::starts_like_key("--"));
+/// assert!(! argyle::starts_like_key("---threads"));
+/// assert!(! argyle::starts_like_key("-=4"));
+/// assert!(! argyle::starts_like_key("threads"));
+/// ```
+pub const fn starts_like_key(raw: &str) -> bool { keylike(raw.as_bytes()) }
+
+/// # Keylike?
+///
+/// This is the byte-based counterpart to [`starts_like_key`].
+const fn keylike(bytes: &[u8]) -> bool {
+	matches!(
+		bytes,
+		[b'-', b'a'..=b'z' | b'A'..=b'Z' | b'0'..=b'9', ..] |
+		[b'-', b'-', b'a'..=b'z' | b'A'..=b'Z' | b'0'..=b'9', ..]
+	)
+}
+
+/// # Valid Identifier?
+///
+/// Returns `true` if `src` can be used as a type or constant name in the
+/// generated code.
+fn is_ident(src: &str) -> bool {
+	src.starts_with(|c: char| c.is_ascii_alphabetic()) &&
+	src.bytes().all(|b| b.is_ascii_alphanumeric() || b == b'_')
+}
+
+/// # Variant Name.
+///
+/// Convert a command like `build-all` into a `PascalCase` enum variant like
+/// `BuildAll`. Commands starting with a digit get a `Cmd` prefix.
+fn variant_name(src: &str) -> String {
+	let mut out = String::with_capacity(src.len() + 3);
+	if src.starts_with(|c: char| c.is_ascii_digit()) { out.push_str("Cmd"); }
+	for part in src.split(['-', '_']).filter(|p| ! p.is_empty()) {
+		let mut chars = part.chars();
+		if let Some(c) = chars.next() {
+			out.push(c.to_ascii_uppercase());
+			out.extend(chars);
+		}
+	}
+	out
+}
+
+#[doc(hidden)]
+#[must_use]
+/// # Unique Keywords?
+///
+/// Returns `true` if no two keywords share the same string. This is used by
+/// the [`keywords!`] macro.
+pub const fn __unique_keywords(keys: &[KeyWord]) -> bool {
+	let mut i = 0;
+	while i < keys.len() {
+		let a = keys[i].as_str().as_bytes();
+		let mut j = i + 1;
+		while j < keys.len() {
+			if eq_bytes(a, keys[j].as_str().as_bytes()) { return false; }
+			j += 1;
+		}
+		i += 1;
+	}
+	true
+}
+
+/// # Equal Bytes?
+///
+/// A `const` byte slice comparison.
+const fn eq_bytes(mut a: &[u8], mut b: &[u8]) -> bool {
+	if a.len() != b.len() { return false; }
+	while let ([x, rest_a @ ..], [y, rest_b @ ..]) = (a, b) {
+		if *x != *y { return false; }
+		a = rest_a;
+		b = rest_b;
+	}
+	true
+}
+
+/// # Valid Command?
+const fn valid_command(bytes: &[u8]) -> bool {
+	if let [b'a'..=b'z' | b'A'..=b'Z' | b'0'..=b'9', rest @ ..] = bytes {
+		valid_suffix(rest)
+	}
+	else { false }
+}
+
+/// # Valid Key?
+const fn valid_key(bytes: &[u8]) -> bool {
+	match bytes {
+		// Short keys are easy.
+		[b'-', b'a'..=b'z' | b'A'..=b'Z' | b'0'..=b'9'] => true,
+		// Long keys have a variable suffix.
+		[b'-', b'-', b'a'..=b'z' | b'A'..=b'Z' | b'0'..=b'9', rest @ ..] => valid_suffix(rest),
+		// There is no such thing as a medium key.
+		_ => false,
+	}
+}
+
+/// # Valid Keyword Suffix?
+///
+/// Check that all bytes are ASCII alphanumeric, `-`, or `_`. This is required
+/// for both long keys and commands.
+const fn valid_suffix(mut bytes: &[u8]) -> bool {
+	while let [b'-' | b'_' | b'a'..=b'z' | b'A'..=b'Z' | b'0'..=b'9', rest @ ..] = bytes {
+		bytes = rest;
+	}
+
+	// By process of elimination, everything validated!
+	bytes.is_empty()
+}
+
+
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use alloc::{
+		string::ToString,
+		vec,
+	};
+
+	#[test]
+	fn t_commands_enum() {
+		let mut words = KeyWordsBuilder::default();
+		words.push_commands(["build", "check-all", "2fa"]);
+		words.push_keys(["-h", "--help"]);
+
+		assert_eq!(
+			words.commands_enum("Cmd"),
+			"#[derive(Debug, Clone, Copy, Eq, Hash, Ord, PartialEq, PartialOrd)]
+/// # Commands.
+pub enum Cmd {
+	Build,
+	CheckAll,
+	Cmd2fa,
+}
+
+impl Cmd {
+	/// # All Commands.
+	pub const ALL: [Self; 3] = [Self::Build, Self::CheckAll, Self::Cmd2fa];
+
+	#[must_use]
+	/// # As String Slice.
+	pub const fn as_str(self) -> &'static str {
+		match self {
+			Self::Build => \"build\",
+			Self::CheckAll => \"check-all\",
+			Self::Cmd2fa => \"2fa\",
+		}
+	}
+
+	#[must_use]
+	#[expect(clippy::should_implement_trait, reason = \"Lookups return an Option, not a Result.\")]
+	/// # From String Slice.
+	pub fn from_str(src: &str) -> Option<Self> {
+		match src {
+			\"build\" => Some(Self::Build),
+			\"check-all\" => Some(Self::CheckAll),
+			\"2fa\" => Some(Self::Cmd2fa),
+			_ => None,
+		}
+	}
+}
+",
+		);
+
+		assert_eq!(variant_name("a--b_c"), "ABC");
+	}
+
+	#[test]
+	fn t_const_array() {
+		let mut words = KeyWordsBuilder::default();
+		assert_eq!(
+			words.const_array("EMPTY"),
+			"/// # Keywords.\npub(crate) const EMPTY: [argyle::KeyWord; 0] = [];\n",
+		);
+
+		words.push_command("build");
+		words.push_key_with_value("-o");
+		words.push_alias("-O", "-o");
+		assert_eq!(
+			words.const_array("KEYS_2"),
+			"/// # Keywords.
+pub(crate) const KEYS_2: [argyle::KeyWord; 3] = [argyle::KeyWord::Alias(\"-O\", \"-o\"), argyle::KeyWord::KeyWithValue(\"-o\"), argyle::KeyWord::Command(\"build\")];
+",
+		);
+	}
+
+	#[test]
+	#[should_panic(expected = "Invalid constant name: 2KEYS")]
+	fn t_const_array_invalid() {
+		let _res = KeyWordsBuilder::default().const_array("2KEYS");
+	}
+
+	#[test]
+	#[should_panic(expected = "Duplicate variant: CheckAll")]
+	fn t_commands_enum_dupe() {
+		let mut words = KeyWordsBuilder::default();
+		words.push_commands(["check-all", "check_all"]);
+		let _res = words.commands_enum("Command");
+	}
+
+	#[test]
+	fn t_keywords_macro() {
+		const KEYS: &[KeyWord] = crate::keywords![
+			-v,
+			--dry-run,
+			--out_dir = value,
+			-c = optional,
+			-W = prefix,
+			build(cmd),
+			x2(cmd),
+		];
+		assert_eq!(
+			KEYS,
+			[
+				KeyWord::Key("-v"),
+				KeyWord::Key("--dry-run"),
+				KeyWord::KeyWithValue("--out_dir"),
+				KeyWord::KeyWithOptionalValue("-c"),
+				KeyWord::Prefix("-W"),
+				KeyWord::Command("build"),
+				KeyWord::Command("x2"),
+			],
+		);
+
+		// Trailing commas and emptiness are fine.
+		assert_eq!(crate::keywords![-v,], [KeyWord::Key("-v")]);
+		assert!(crate::keywords![].is_empty());
+
+		assert!(__unique_keywords(KEYS));
+		assert!(! __unique_keywords(&[KeyWord::Key("-v"), KeyWord::KeyWithValue("-v")]));
+	}
+
+	#[test]
+	fn t_valid_key() {
+		let first: BTreeSet<char> = ('0'..='9')
+			.chain('a'..='z')
+			.chain('A'..='Z')
+			.collect();
+
+		let suffix: BTreeSet<char> = first.iter()
+			.copied()
+			.chain(['-', '_'])
+			.collect();
+
+		let bad: BTreeSet<char> = ['!', '?', '.', 'ö', '\n'].into_iter().collect();
+
+		// The suffix allows two things the initial character doesn't.
+		assert_eq!(first.len(), 26 * 2 + 10);
+		assert_eq!(first.len() + 2, suffix.len());
+
+		// None of the bad characters should be present in either.
+		assert!(bad.iter().all(|c| ! first.contains(c) && ! suffix.contains(c)));
+
+		// Let's build up some keys to make sure we aren't missing anything
+		// in the match-based validation.
+		for &a in &first {
+			// This should work for both long and short.
+			assert!(valid_key(format!("-{a}").as_bytes()));
+			assert!(valid_key(format!("--{a}").as_bytes()));
+
+			// But not with the wrong number of dashes.
+			assert!(! valid_key(format!("{a}").as_bytes()));
+			assert!(! valid_key(format!("---{a}").as_bytes()));
+
+			// Longer variations.
+			for &b in &suffix {
+				// This should work for long keys.
+				assert!(valid_key(format!("--{a}{b}").as_bytes()));
+
+				// But not any other dash count.
+				assert!(! valid_key(format!("{a}{b}").as_bytes()));
+				assert!(! valid_key(format!("-{a}{b}").as_bytes()));
+				assert!(! valid_key(format!("---{a}{b}").as_bytes()));
+
+				// Not with bad stuff though.
+				for &c in &bad {
+					assert!(! valid_key(format!("--{a}{c}{b}").as_bytes()));
+					assert!(! valid_key(format!("--{a}{b}{c}").as_bytes()));
+				}
+			}
+		}
+
+		// Bad letters on their own should never work.
+		for c in bad {
+			assert!(! valid_key(format!("{c}").as_bytes()));
+			assert!(! valid_key(format!("-{c}").as_bytes()));
+			assert!(! valid_key(format!("--{c}").as_bytes()));
+			assert!(! valid_key(format!("---{c}").as_bytes()));
+		}
+
+		// Bad starts should never work either.
+		assert!(! valid_key(b""));
+		assert!(! valid_key(b"-"));
+		assert!(! valid_key(b"--"));
+		assert!(! valid_key(b"---"));
+	}
+
+	#[test]
+	fn t_match_keyword() {
+		let keys = BTreeSet::from([
+			KeyWord::Command("make"),
+			KeyWord::Key("-v"),
+			KeyWord::KeyWithValue("-o"),
+			KeyWord::KeyWithValue("--out"),
+			KeyWord::Alias("-O", "--out"),
+			KeyWord::Alias("-m", "make"),
+			KeyWord::Alias("--make", "make"),
+		]);
+
+		for (raw, expected) in [
+			(&b"make"[..], Some((KeyWord::Command("make"), None))),
+			(b"make=1", None),
+			(b"-v", Some((KeyWord::Key("-v"), None))),
+			(b"-v2", Some((KeyWord::Key("-v"), Some(&b"2"[..])))),
+			(b"-v=2", Some((KeyWord::Key("-v"), Some(&b"2"[..])))),
+			(b"-o", Some((KeyWord::KeyWithValue("-o"), None))),
+			(b"-o=", Some((KeyWord::KeyWithValue("-o"), Some(&b""[..])))),
+			(b"-o==", Some((KeyWord::KeyWithValue("-o"), Some(&b"="[..])))),
+			(b"-o\xff", Some((KeyWord::KeyWithValue("-o"), Some(&b"\xff"[..])))),
+			(b"--out", Some((KeyWord::KeyWithValue("--out"), None))),
+			(b"--out=a=b", Some((KeyWord::KeyWithValue("--out"), Some(&b"a=b"[..])))),
+			(b"--outer", None),
+			(b"-O", Some((KeyWord::KeyWithValue("--out"), None))),
+			(b"-Ofile", Some((KeyWord::KeyWithValue("--out"), Some(&b"file"[..])))),
+			(b"", None),
+			(b"-", None),
+			(b"--", None),
+			(b"---out=1", None),
+			(b"-=", None),
+			(b"-=x", None),
+			(b"--=", None),
+			(b"--=x", None),
+			(b"---", None),
+			(b"---=x", None),
+			(b"--out-=1", None),
+			(b"--ou=t", None),
+			(b"=", None),
+			(b"=-o", None),
+			(b"-o\xef\xbc\x9d1", Some((KeyWord::KeyWithValue("-o"), Some(&b"\xef\xbc\x9d1"[..])))),
+			("--out\u{ff1d}1".as_bytes(), None),
+			(b"--out=\xff", Some((KeyWord::KeyWithValue("--out"), Some(&b"\xff"[..])))),
+			(b"-m", Some((KeyWord::Command("make"), None))),
+			(b"-m1", None),
+			(b"-m=1", None),
+			(b"--make=1", None),
+		] {
+			assert_eq!(
+				match_keyword(&keys, raw),
+				expected,
+				"Mismatch for {:?}.",
+				String::from_utf8_lossy(raw),
+			);
+		}
+	}
+
+	#[test]
+	fn t_match_keyword_prefix() {
+		let keys = BTreeSet::from([
+			KeyWord::Prefix("-W"),
+			KeyWord::Alias("-X", "-W"),
+			KeyWord::Prefix("--opt"),
+			KeyWord::Prefix("--opt-l"),
+			KeyWord::Key("--opt-level"),
+			KeyWord::KeyWithValue("--optimize"),
+		]);
+
+		for (raw, expected) in [
+			(&b"-W"[..], Some((KeyWord::Prefix("-W"), None))),
+			(b"-Wall", Some((KeyWord::Prefix("-W"), Some(&b"all"[..])))),
+			(b"-W=all", Some((KeyWord::Prefix("-W"), Some(&b"=all"[..])))),
+			(b"-Xall", Some((KeyWord::Prefix("-W"), Some(&b"all"[..])))),
+			(b"-X=", Some((KeyWord::Prefix("-W"), Some(&b"="[..])))),
+			(b"--opt", Some((KeyWord::Prefix("--opt"), None))),
+			(b"--opt3", Some((KeyWord::Prefix("--opt"), Some(&b"3"[..])))),
+			(b"--opt=3", Some((KeyWord::Prefix("--opt"), Some(&b"=3"[..])))),
+			(b"--opt-x=3", Some((KeyWord::Prefix("--opt"), Some(&b"-x=3"[..])))),
+			(b"--opt-lx", Some((KeyWord::Prefix("--opt-l"), Some(&b"x"[..])))),
+			(b"--opt-level", Some((KeyWord::Key("--opt-level"), None))),
+			(b"--opt-level=1", Some((KeyWord::Key("--opt-level"), Some(&b"1"[..])))),
+			(b"--opt-levels", Some((KeyWord::Prefix("--opt-l"), Some(&b"evels"[..])))),
+			(b"--optimize=2", Some((KeyWord::KeyWithValue("--optimize"), Some(&b"2"[..])))),
+			(b"--optimizer", Some((KeyWord::Prefix("--opt"), Some(&b"imizer"[..])))),
+			(b"--op", None),
+			(b"-opt", None),
+			(b"---opt", None),
+		] {
+			assert_eq!(
+				match_keyword(&keys, raw),
+				expected,
+				"Mismatch for {:?}.",
+				String::from_utf8_lossy(raw),
+			);
+		}
+	}
+
+	#[test]
+	fn t_unwrap() {
+		const KEYS: [KeyWord; 4] = [
+			KeyWord::command_unwrap("build"),
+			KeyWord::key_unwrap("-h"),
+			KeyWord::key_with_value_unwrap("--out"),
+			KeyWord::key_with_optional_value_unwrap("--color"),
+		];
+		assert!(matches!(KEYS[0], KeyWord::Command("build")));
+		assert!(matches!(KEYS[1], KeyWord::Key("-h")));
+		assert!(matches!(KEYS[2], KeyWord::KeyWithValue("--out")));
+		assert!(matches!(KEYS[3], KeyWord::KeyWithOptionalValue("--color")));
+	}
+
+	#[test]
+	#[should_panic(expected = "Invalid key.")]
+	fn t_unwrap_invalid() { let _res = KeyWord::key_unwrap("--björk"); }
+
+	#[test]
+	fn t_spec_string() {
+		for (kw, spec) in [
+			(KeyWord::Command("build"), "cmd:build"),
+			(KeyWord::Key("-v"), "key:-v"),
+			(KeyWord::KeyWithValue("--out"), "kv:--out"),
+			(KeyWord::KeyWithOptionalValue("--color"), "opt:--color"),
+			(KeyWord::Prefix("-O"), "prefix:-O"),
+			(KeyWord::Alias("-o", "--out"), "alias:-o=--out"),
+			(KeyWord::Alias("b", "build"), "alias:b=build"),
+		] {
+			assert_eq!(kw.to_spec_string(), spec);
+
+			// Equality ignores the variant, so check that too.
+			let parsed = KeyWord::from_spec_string(spec).expect(spec);
+			assert_eq!(parsed.to_spec_string(), spec);
+		}
+
+		for spec in [
+			"", ":", "key", "key:", "key:-", "key:--björk", "cmd:-v", "kv:build",
+			"Key:-v", " key:-v", "alias:-o", "alias:-o=", "alias:=--out",
+			"alias:-o=--out=x", "prefix:-O2",
+		] {
+			assert!(KeyWord::from_spec_string(spec).is_none(), "{spec}");
+		}
+	}
+
+	#[test]
+	fn t_builder() {
+		let mut builder = KeyWordsBuilder::default();
+		assert_eq!(builder.to_string(), "[]"); // Empty.
+
+		builder.push_key("-h");
+		assert_eq!(builder.to_string(), "[argyle::KeyWord::Key(\"-h\")]");
+
+		builder.push_key("--help");
+		assert_eq!(
+			builder.to_string(),
+			"[argyle::KeyWord::Key(\"--help\"), argyle::KeyWord::Key(\"-h\")]"
+		);
+
+		builder.push_key_with_value("--output");
+		assert_eq!(
+			builder.to_string(),
+			"[argyle::KeyWord::Key(\"--help\"), argyle::KeyWord::KeyWithValue(\"--output\"), argyle::KeyWord::Key(\"-h\")]"
+		);
+
+		builder.push_command("make");
+		assert_eq!(
+			builder.to_string(),
+			"[argyle::KeyWord::Key(\"--help\"), argyle::KeyWord::KeyWithValue(\"--output\"), argyle::KeyWord::Key(\"-h\"), argyle::KeyWord::Command(\"make\")]"
+		);
+
+		builder.push_key_with_optional_value("--color");
+		assert_eq!(
+			builder.to_string(),
+			"[argyle::KeyWord::KeyWithOptionalValue(\"--color\"), argyle::KeyWord::Key(\"--help\"), argyle::KeyWord::KeyWithValue(\"--output\"), argyle::KeyWord::Key(\"-h\"), argyle::KeyWord::Command(\"make\")]"
+		);
+
+		builder.push_prefixes(["-O"]);
+		assert_eq!(
+			builder.to_string(),
+			"[argyle::KeyWord::KeyWithOptionalValue(\"--color\"), argyle::KeyWord::Key(\"--help\"), argyle::KeyWord::KeyWithValue(\"--output\"), argyle::KeyWord::Prefix(\"-O\"), argyle::KeyWord::Key(\"-h\"), argyle::KeyWord::Command(\"make\")]"
+		);
+	}
+
+	#[test]
+	#[should_panic(expected = "Invalid key")]
+	fn t_builder_invalid() {
+		let mut builder = KeyWordsBuilder::default();
+		builder.push_key("--Björk"); // Invalid characters.
+	}
+
+	#[test]
+	#[should_panic(expected = "Duplicate key")]
+	fn t_builder_duplicate() {
+		let mut builder = KeyWordsBuilder::default();
+		builder.push_key("--help");
+		builder.push_key_with_value("--help"); // Repeated string.
+	}
+
+	#[test]
+	fn t_builder_markdown() {
+		let mut builder = KeyWordsBuilder::default();
+		builder.push_command("make");
+		builder.push_key("-h");
+		builder.set_help("make", "Make | build.");
+		builder.set_help("-h", "Print help\ninformation.");
+		assert_eq!(
+			builder.to_markdown(),
+			"| Keyword | Kind | Description |\n| ------- | ---- | ----------- |\n| `-h` | Key | Print help information. |\n| `make` | Command | Make \\| build. |\n",
+		);
+	}
+
+	#[test]
+	fn t_builder_hint() {
+		let mut builder = KeyWordsBuilder::default();
+		builder.push_key("-h");
+		builder.push_key_with_value("--format");
+		assert_eq!(builder.hint("-h"), Some(&CompletionHint::None));
+		assert_eq!(builder.hint("--format"), Some(&CompletionHint::None));
+		assert_eq!(builder.hint("--nope"), None);
+
+		let hint = CompletionHint::Choice(vec!["json".to_owned(), "yaml".to_owned()]);
+		builder.set_hint("--format", hint.clone());
+		assert_eq!(builder.hint("--format"), Some(&hint));
+		assert_eq!(
+			builder.to_markdown(),
+			"| Keyword | Kind | Description |\n| ------- | ---- | ----------- |\n| `--format {json,yaml}` | KeyWithValue |  |\n| `-h` | Key |  |\n",
+		);
+	}
+
+	#[test]
+	#[should_panic(expected = "Key does not take a value")]
+	fn t_builder_hint_invalid() {
+		let mut builder = KeyWordsBuilder::default();
+		builder.push_key("-h");
+		builder.set_hint("-h", CompletionHint::File);
+	}
+
+	#[test]
+	#[should_panic(expected = "Unknown key")]
+	fn t_builder_help_unknown() {
+		let mut builder = KeyWordsBuilder::default();
+		builder.set_help("--help", "Print help information.");
+	}
+
+	#[test]
+	fn t_builder_pairs() {
+		let mut builder1 = KeyWordsBuilder::default();
+		builder1.push_key_with_value("--output");
+		builder1.set_help("--output", "Write results to FILE.");
+		builder1.push_alias("-o", "--output");
+		builder1.push_key("--verbose");
+		builder1.set_help("--verbose", "Print more.");
+		builder1.push_alias("-v", "--verbose");
+
+		// The order within each pair shouldn't matter.
+		let mut builder2 = KeyWordsBuilder::default();
+		builder2.push_pairs([
+			("-o", "--output", true, "Write results to FILE."),
+			("--verbose", "-v", false, "Print more."),
+		]);
+
+		assert_eq!(builder1.to_string(), builder2.to_string());
+		assert_eq!(builder1.to_markdown(), builder2.to_markdown());
+		assert_eq!(
+			builder2.to_markdown(),
+			"| Keyword | Kind | Description |\n| ------- | ---- | ----------- |\n\
+			| `--output` | KeyWithValue | Write results to FILE. |\n\
+			| `--verbose` | Key | Print more. |\n\
+			| `-o` | Alias | Alias for `--output`. |\n\
+			| `-v` | Alias | Alias for `--verbose`. |\n",
+		);
+	}
+
+	#[test]
+	#[should_panic(expected = "Invalid pair")]
+	fn t_builder_pairs_invalid() {
+		let mut builder = KeyWordsBuilder::default();
+		builder.push_pairs([("-o", "-p", true, "")]);
+	}
+
+	#[test]
+	#[should_panic(expected = "Unknown key")]
+	fn t_builder_alias_unknown() {
+		let mut builder = KeyWordsBuilder::default();
+		builder.push_alias("-o", "--output");
+	}
+
+	#[test]
+	fn t_builder_try() {
+		let mut builder = KeyWordsBuilder::default();
+		assert_eq!(builder.try_push_command("build"), Ok(()));
+		assert_eq!(builder.try_push_key("-v"), Ok(()));
+		assert_eq!(builder.try_push_key_with_value("--output"), Ok(()));
+		assert_eq!(builder.try_push_key_with_optional_value("--color"), Ok(()));
+		assert_eq!(builder.try_push_prefix("-O"), Ok(()));
+		assert_eq!(builder.try_push_alias("-o", "--output"), Ok(()));
+		assert_eq!(builder.try_set_help("-v", "Verbose."), Ok(()));
+		assert_eq!(builder.try_set_hint("--output", CompletionHint::File), Ok(()));
+
+		let before = builder.to_string();
+		for (res, err) in [
+			(builder.try_push_command("-b"), "Invalid command: -b"),
+			(builder.try_push_key("--björk"), "Invalid key: --björk"),
+			(builder.try_push_key_with_value(" -v "), "Duplicate key: -v"),
+			(builder.try_push_key_with_optional_value("-"), "Invalid key: -"),
+			(builder.try_push_prefix("-O2"), "Invalid key: -O2"),
+			(builder.try_push_alias("-x", "-o"), "Alias points to another alias: -o"),
+			(builder.try_push_alias("-x", "--nope"), "Unknown key: --nope"),
+			(builder.try_push_alias("x", "-v"), "Invalid key: x"),
+			(builder.try_set_help("--nope", "Nope."), "Unknown key: --nope"),
+			(builder.try_set_hint("--nope", CompletionHint::File), "Unknown key: --nope"),
+			(builder.try_set_hint("-v", CompletionHint::File), "Key does not take a value: -v"),
+		] {
+			assert_eq!(res, Err(err.to_owned()));
+		}
+
+		// Failures leave the builder as it was.
+		assert_eq!(builder.to_string(), before);
+		assert_eq!(builder.len(), 6);
+	}
+
+	#[test]
+	fn t_builder_diff() {
+		let mut old = KeyWordsBuilder::default();
+		old.push_command("build");
+		old.push_keys(["-h", "--help", "-V"]);
+		old.push_key_with_value("-o");
+		old.push_alias("--out", "-o");
+		old.set_help("-h", "Print help.");
+
+		// Nothing changed; cosmetics don't count.
+		let mut new = old.clone();
+		new.set_help("-h", "Print help information.");
+		new.set_hint("-o", CompletionHint::File);
+		let diff = old.diff(&new);
+		assert!(diff.is_empty());
+		assert!(! diff.is_breaking());
+
+		// Additions only.
+		new.push_key("--version");
+		let diff = old.diff(&new);
+		assert_eq!(diff.added, ["--version"]);
+		assert!(! diff.is_empty());
+		assert!(! diff.is_breaking());
+
+		// Now the bad stuff.
+		let mut new = KeyWordsBuilder::default();
+		new.push_keys(["-h", "--help"]);
+		new.push_keys_with_values(["-o", "--output", "-V"]);
+		new.push_alias("--out", "--output");
+		let diff = old.diff(&new);
+		assert_eq!(diff.added, ["--output"]);
+		assert_eq!(diff.removed, ["build"]);
+		assert_eq!(diff.changed, ["--out", "-V"]);
+		assert!(diff.is_breaking());
+	}
+
+	#[test]
+	fn t_builder_plural() {
+		let mut builder1 = KeyWordsBuilder::default();
+		builder1.push_key("-h");
+		builder1.push_key("--help");
+
+		let mut builder2 = KeyWordsBuilder::default();
+		builder2.push_keys(["-h", "--help"]);
+
+		assert_eq!(builder1.to_string(), builder2.to_string());
+
+		builder1 = KeyWordsBuilder::default();
+		builder1.push_key_with_value("-o");
+		builder1.push_key_with_value("--output");
+
+		builder2 = KeyWordsBuilder::default();
+		builder2.push_keys_with_values(["-o", "--output"]);
+
+		assert_eq!(builder1.to_string(), builder2.to_string());
+
+		builder1 = KeyWordsBuilder::default();
+		builder1.push_command("build");
+		builder1.push_command("check");
+
+		builder2 = KeyWordsBuilder::default();
+		builder2.push_commands(["build", "check"]);
+
+		assert_eq!(builder1.to_string(), builder2.to_string());
+	}
+}
@@ -0,0 +1,173 @@
+/*!
+# Argyle: Stateless Classification.
+*/
+
+use alloc::collections::BTreeSet;
+use super::{
+	keylike,
+	KeyWord,
+	match_keyword,
+};
+
+
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+/// # Classification.
+///
+/// This is how a single raw argument would be interpreted in isolation,
+/// as returned by [`classify`].
+pub enum Classification<'a> {
+	/// # (Sub)command.
+	///
+	/// The argument matches a [`KeyWord::Command`].
+	Command(&'static str),
+
+	/// # Boolean Key.
+	///
+	/// The argument matches a [`KeyWord::Key`], or a
+	/// [`KeyWord::KeyWithOptionalValue`] or [`KeyWord::Prefix`] without a
+	/// value.
+	Key(&'static str),
+
+	/// # Key and Value.
+	///
+	/// The argument matches a [`KeyWord::KeyWithValue`],
+	/// [`KeyWord::KeyWithOptionalValue`], or [`KeyWord::Prefix`] and has a
+	/// value glued onto it, like `-kval`, `--key=val`, or `-O2`.
+	KeyWithValue(&'static str, &'a str),
+
+	/// # Key Needing a Value.
+	///
+	/// The argument matches a [`KeyWord::KeyWithValue`] but has no value of
+	/// its own, so would consume the _next_ argument.
+	KeyNeedsValue(&'static str),
+
+	/// # Unexpected Value.
+	///
+	/// The argument matches a [`KeyWord::Key`] but has a value glued onto
+	/// it anyway.
+	UnexpectedValue(&'static str, &'a str),
+
+	/// # Unknown Key.
+	///
+	/// The argument looks like a key — see [`starts_like_key`](crate::starts_like_key)
+	/// — but doesn't match any of the keywords.
+	UnknownKey,
+
+	/// # Separator.
+	///
+	/// The argument is `--`, which would normally end option parsing.
+	Separator,
+
+	/// # Positional.
+	///
+	/// Anything else.
+	Positional,
+}
+
+
+
+#[must_use]
+/// # Classify.
+///
+/// Classify a single raw argument against `keywords` without setting up an
+/// [`Argue`](crate::Argue) instance, e.g. to validate user-provided strings
+/// from a config file that will later be passed along as CLI arguments.
+///
+/// This uses the same matching logic as [`match_keyword`] — aliases are
+/// resolved to their canonical keywords, etc. — but is otherwise stateless,
+/// so doesn't know anything about what came before or after, or any of the
+/// `Argue` configuration options.
+///
+/// ## Examples
+///
+/// ```
+/// use argyle::{Classification, KeyWord};
+/// use std::collections::BTreeSet;
+///
+/// let keys = BTreeSet::from([
+///     KeyWord::key("-v").unwrap(),
+///     KeyWord::key_with_value("--out").unwrap(),
+///     KeyWord::Alias("-o", "--out"),
+/// ]);
+///
+/// assert_eq!(argyle::classify("-v", &keys), Classification::Key("-v"));
+/// assert_eq!(
+///     argyle::classify("-ofile.txt", &keys),
+///     Classification::KeyWithValue("--out", "file.txt"),
+/// );
+/// assert_eq!(argyle::classify("--out", &keys), Classification::KeyNeedsValue("--out"));
+/// assert_eq!(argyle::classify("--in", &keys), Classification::UnknownKey);
+/// assert_eq!(argyle::classify("file.txt", &keys), Classification::Positional);
+/// ```
+pub fn classify<'a>(raw: &'a str, keywords: &BTreeSet<KeyWord>) -> Classification<'a> {
+	if raw == "--" { return Classification::Separator; }
+
+	let Some((key, value)) = match_keyword(keywords, raw.as_bytes()) else {
+		return
+			if keylike(raw.as_bytes()) { Classification::UnknownKey }
+			else { Classification::Positional };
+	};
+
+	// Values are only ever split off at ASCII boundaries, so this is safe.
+	let value = value.map(|v| &raw[raw.len() - v.len()..]);
+	match (key, value) {
+		(KeyWord::Command(k), _) => Classification::Command(k),
+		(KeyWord::Key(k) | KeyWord::KeyWithOptionalValue(k) | KeyWord::Prefix(k), None) =>
+			Classification::Key(k),
+		(KeyWord::Key(k), Some(v)) => Classification::UnexpectedValue(k, v),
+		(KeyWord::KeyWithValue(k), None) => Classification::KeyNeedsValue(k),
+		(KeyWord::KeyWithValue(k) | KeyWord::KeyWithOptionalValue(k) | KeyWord::Prefix(k), Some(v)) =>
+			Classification::KeyWithValue(k, v),
+		// Aliases are resolved during the match.
+		(KeyWord::Alias(_, _), _) => Classification::Positional,
+	}
+}
+
+
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	#[test]
+	fn t_classify() {
+		let keys = BTreeSet::from([
+			KeyWord::Command("build"),
+			KeyWord::Alias("b", "build"),
+			KeyWord::Key("-v"),
+			KeyWord::KeyWithValue("-o"),
+			KeyWord::KeyWithOptionalValue("--color"),
+			KeyWord::Prefix("-W"),
+		]);
+
+		for (raw, expected) in [
+			("build", Classification::Command("build")),
+			("b", Classification::Command("build")),
+			("build=1", Classification::Positional),
+			("-v", Classification::Key("-v")),
+			("-v1", Classification::UnexpectedValue("-v", "1")),
+			("-v=1", Classification::UnexpectedValue("-v", "1")),
+			("-o", Classification::KeyNeedsValue("-o")),
+			("-oé", Classification::KeyWithValue("-o", "é")),
+			("-o==", Classification::KeyWithValue("-o", "=")),
+			("--color", Classification::Key("--color")),
+			("--color=auto", Classification::KeyWithValue("--color", "auto")),
+			("--colour", Classification::UnknownKey),
+			("-W", Classification::Key("-W")),
+			("-Wall", Classification::KeyWithValue("-W", "all")),
+			("-W=all", Classification::KeyWithValue("-W", "=all")),
+			("-x", Classification::UnknownKey),
+			("--", Classification::Separator),
+			("-", Classification::Positional),
+			("---v", Classification::Positional),
+			("", Classification::Positional),
+			("file.txt", Classification::Positional),
+		] {
+			assert_eq!(classify(raw, &keys), expected, "{raw}");
+		}
+
+		// Nothing is nothing.
+		assert_eq!(classify("-v", &BTreeSet::new()), Classification::UnknownKey);
+	}
+}
@@ -0,0 +1,382 @@
+/*!
+# Argyle: Keyword Table.
+*/
+
+use alloc::{
+	format,
+	string::String,
+	vec,
+	vec::Vec,
+};
+use core::{
+	cmp::Reverse,
+	fmt,
+};
+use super::{
+	KeyWord,
+	KeyWordsBuilder,
+};
+
+
+
+/// # Bucket Size.
+///
+/// The average number of keys per displacement bucket.
+const LAMBDA: usize = 4;
+
+/// # Max Displacement.
+///
+/// The number of displacements to try for a given bucket before giving up
+/// on the current seed.
+const MAX_DISP: u32 = 1 << 20;
+
+
+
+#[derive(Debug, Clone, Copy)]
+/// # Keyword Table.
+///
+/// This is a compile-time perfect hash table of [`KeyWord`]s, generated by
+/// [`KeyWordsBuilder::phf_table`] (or [`KeyWordsBuilder::save_phf`]).
+///
+/// Lookups are O(1) regardless of size, and since everything lives in
+/// `static` memory, there's nothing to allocate or sort at runtime, making
+/// this a good fit for programs with hundreds or thousands of keywords.
+///
+/// Use it with [`Argue::with_keyword_table`](crate::Argue::with_keyword_table),
+/// or directly via [`KeyWordTable::match_keyword`].
+///
+/// ## Examples
+///
+/// In your build script:
+///
+/// ```ignore
+/// let out_dir: &Path = std::env::var("OUT_DIR").unwrap().as_ref();
+/// words.save_phf(out_dir.join("keyz-table.rs"));
+/// ```
+///
+/// And in your program:
+///
+/// ```ignore
+/// static KEYS: argyle::KeyWordTable =
+///     include!(concat!(env!("OUT_DIR"), "/keyz-table.rs"));
+///
+/// let args = argyle::args().with_keyword_table(&KEYS);
+/// ```
+pub struct KeyWordTable {
+	/// # Hash Seed.
+	seed: u64,
+
+	/// # Bucket Displacements.
+	disps: &'static [u32],
+
+	/// # Keywords (in Slot Order).
+	slots: &'static [KeyWord],
+}
+
+impl KeyWordTable {
+	#[doc(hidden)]
+	#[must_use]
+	/// # New (Generated).
+	///
+	/// This is used by the code generated by [`KeyWordsBuilder::phf_table`];
+	/// it is not intended to be called directly.
+	pub const fn __new(seed: u64, disps: &'static [u32], slots: &'static [KeyWord])
+	-> Self {
+		Self { seed, disps, slots }
+	}
+
+	#[must_use]
+	/// # Is Empty?
+	///
+	/// Returns `true` if there are no keywords.
+	pub const fn is_empty(&self) -> bool { self.slots.is_empty() }
+
+	#[must_use]
+	/// # Length.
+	///
+	/// Returns the number of keywords in the table.
+	pub const fn len(&self) -> usize { self.slots.len() }
+
+	#[must_use]
+	/// # Keywords.
+	///
+	/// Return all of the keywords in the table, in no particular order.
+	pub const fn keywords(&self) -> &'static [KeyWord] { self.slots }
+}
+
+impl KeyWordTable {
+	#[must_use]
+	/// # Get Keyword.
+	///
+	/// Return the keyword matching `key` exactly, if any.
+	pub fn get(&self, key: &str) -> Option<KeyWord> {
+		if self.disps.is_empty() { return None; }
+		let h = hash(key, self.seed);
+		let disp = self.disps.get(bucket(h, self.disps.len()))?;
+		let kw = self.slots.get(slot(h, *disp, self.slots.len()))?;
+		(kw.as_str() == key).then_some(*kw)
+	}
+
+	#[must_use]
+	/// # Match Keyword.
+	///
+	/// Same as [`match_keyword`](crate::match_keyword), but for the table.
+	pub fn match_keyword<'a>(&self, raw: &'a [u8]) -> Option<(KeyWord, Option<&'a [u8]>)> {
+		super::match_with(raw, |k| self.get(k))
+	}
+}
+
+
+
+impl KeyWordsBuilder {
+	#[must_use]
+	/// # Perfect Hash Table.
+	///
+	/// Generate the code for a [`KeyWordTable`] containing all of the
+	/// keywords, suitable for assignment to a `static`.
+	///
+	/// Building the table can take a moment for very large sets, but that's
+	/// a one-time cost paid by the build script rather than the program.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use argyle::KeyWordsBuilder;
+	///
+	/// let mut words = KeyWordsBuilder::default();
+	/// words.push_keys(["-h", "--help"]);
+	///
+	/// let code = words.phf_table();
+	/// assert!(code.starts_with("argyle::KeyWordTable::__new("));
+	/// assert!(code.contains("argyle::KeyWord::Key(\"--help\")"));
+	/// ```
+	pub fn phf_table(&self) -> String {
+		use fmt::Write;
+
+		let (seed, disps, keys) = self.phf();
+		let mut out = format!("argyle::KeyWordTable::__new({seed:#x}, &[");
+		for (i, d) in disps.iter().enumerate() {
+			if i != 0 { out.push_str(", "); }
+			let _res = write!(&mut out, "{d}");
+		}
+		out.push_str("], &[");
+		for (i, k) in keys.iter().enumerate() {
+			if i != 0 { out.push_str(", "); }
+			if let Some(meta) = self.0.get(*k) {
+				let _res = meta.kind.write_code(&mut out, k);
+			}
+		}
+		out.push_str("])");
+		out
+	}
+
+	/// # Build Perfect Hash.
+	///
+	/// Find a seed and set of bucket displacements that give each keyword
+	/// its own slot, returning them along with the keys in slot order.
+	fn phf(&self) -> (u64, Vec<u32>, Vec<&str>) {
+		let keys: Vec<&str> = self.0.keys().map(String::as_str).collect();
+		if keys.is_empty() { return (0, Vec::new(), Vec::new()); }
+
+		let buckets = keys.len().div_ceil(LAMBDA);
+		let mut seed = 0;
+		loop {
+			if let Some((disps, slots)) = try_phf(&keys, seed, buckets) {
+				let slots = slots.into_iter().map(|i| keys[i]).collect();
+				return (seed, disps, slots);
+			}
+			seed += 1;
+		}
+	}
+}
+
+
+
+/// # Hash.
+///
+/// Seeded FNV-1a, with a `SplitMix64` finalizer to spread the bits around.
+fn hash(key: &str, seed: u64) -> u64 {
+	let mut h = 0xcbf2_9ce4_8422_2325 ^ seed;
+	for b in key.bytes() {
+		h ^= u64::from(b);
+		h = h.wrapping_mul(0x0100_0000_01b3);
+	}
+	mix(h)
+}
+
+/// # Mix.
+///
+/// The `SplitMix64` finalizer.
+const fn mix(mut x: u64) -> u64 {
+	x = (x ^ (x >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+	x = (x ^ (x >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+	x ^ (x >> 31)
+}
+
+/// # Bucket Index.
+///
+/// Return the displacement bucket for a hash.
+fn bucket(h: u64, len: usize) -> usize { index(h >> 32, len) }
+
+/// # Slot Index.
+///
+/// Return the slot for a hash given its bucket's displacement.
+fn slot(h: u64, disp: u32, len: usize) -> usize {
+	index(mix(h ^ u64::from(disp)), len)
+}
+
+/// # Index.
+///
+/// Reduce `h` to an index less than `len`.
+fn index(h: u64, len: usize) -> usize {
+	u64::try_from(len).ok()
+		.and_then(|len| usize::try_from(h % len).ok())
+		.unwrap_or(0)
+}
+
+/// # Try Perfect Hash.
+///
+/// Try to place each key in its own slot using the given seed, returning
+/// the bucket displacements and the key indices in slot order on success.
+fn try_phf(keys: &[&str], seed: u64, buckets: usize) -> Option<(Vec<u32>, Vec<usize>)> {
+	let len = keys.len();
+	let hashes: Vec<u64> = keys.iter().map(|k| hash(k, seed)).collect();
+
+	// Group the keys by bucket, placing the biggest groups first.
+	let mut groups: Vec<Vec<usize>> = vec![Vec::new(); buckets];
+	for (i, h) in hashes.iter().enumerate() { groups[bucket(*h, buckets)].push(i); }
+	let mut order: Vec<usize> = (0..buckets).collect();
+	order.sort_by_key(|&b| Reverse(groups[b].len()));
+
+	let mut disps = vec![0_u32; buckets];
+	let mut slots: Vec<Option<usize>> = vec![None; len];
+	let mut pos = Vec::new();
+	for b in order {
+		if groups[b].is_empty() { break; }
+
+		// Find a displacement that lands everything in an empty slot.
+		disps[b] = (0..MAX_DISP).find(|&d| {
+			pos.clear();
+			groups[b].iter().all(|&i| {
+				let p = slot(hashes[i], d, len);
+				if slots[p].is_some() || pos.contains(&p) { false }
+				else {
+					pos.push(p);
+					true
+				}
+			})
+		})?;
+
+		for (&i, &p) in groups[b].iter().zip(&pos) { slots[p] = Some(i); }
+	}
+
+	Some((disps, slots.into_iter().collect::<Option<Vec<_>>>()?))
+}
+
+
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use alloc::boxed::Box;
+	use crate::key::KeyKind;
+
+	/// # Table From Builder.
+	///
+	/// Build a table the same way the generated code would, leaking as
+	/// needed to satisfy the lifetimes.
+	fn build_table(words: &KeyWordsBuilder) -> KeyWordTable {
+		let (seed, disps, keys) = words.phf();
+		let slots: Vec<KeyWord> = keys.into_iter()
+			.map(|k| {
+				let k: &'static str = Box::leak(Box::<str>::from(k));
+				match &words.0[k].kind {
+					KeyKind::Command => KeyWord::Command(k),
+					KeyKind::Key => KeyWord::Key(k),
+					KeyKind::KeyWithValue => KeyWord::KeyWithValue(k),
+					KeyKind::KeyWithOptionalValue => KeyWord::KeyWithOptionalValue(k),
+					KeyKind::Prefix => KeyWord::Prefix(k),
+					KeyKind::Alias(t) =>
+						KeyWord::Alias(k, Box::leak(t.clone().into_boxed_str())),
+				}
+			})
+			.collect();
+		KeyWordTable::__new(
+			seed,
+			Box::leak(disps.into_boxed_slice()),
+			Box::leak(slots.into_boxed_slice()),
+		)
+	}
+
+	#[test]
+	fn t_table() {
+		// Empty.
+		let words = KeyWordsBuilder::default();
+		let table = build_table(&words);
+		assert!(table.is_empty());
+		assert_eq!(table.get("-h"), None);
+		assert_eq!(words.phf_table(), "argyle::KeyWordTable::__new(0x0, &[], &[])");
+
+		// Big.
+		let mut words = KeyWordsBuilder::default();
+		for i in 0..1200 { words.push_key(format!("--key-{i}")); }
+		words.push_command("build");
+		words.push_key_with_value("-o");
+		words.push_key_with_optional_value("--color");
+		words.push_alias("-k", "--key-7");
+
+		let table = build_table(&words);
+		assert_eq!(table.len(), 1204);
+		for kw in table.keywords() { assert_eq!(table.get(kw.as_str()), Some(*kw)); }
+		assert_eq!(table.get("--key-1200"), None);
+		assert_eq!(table.get("--key-"), None);
+		assert_eq!(table.get(""), None);
+
+		// Matching works the same as it does for sets.
+		for (raw, expected) in [
+			("build", Some((KeyWord::Command("build"), None))),
+			("--key-99", Some((KeyWord::Key("--key-99"), None))),
+			("-k", Some((KeyWord::Key("--key-7"), None))),
+			("-ofile", Some((KeyWord::KeyWithValue("-o"), Some(&b"file"[..])))),
+			("-o=file", Some((KeyWord::KeyWithValue("-o"), Some(&b"file"[..])))),
+			("--color=never", Some((KeyWord::KeyWithOptionalValue("--color"), Some(&b"never"[..])))),
+			("--nope", None),
+		] {
+			assert_eq!(table.match_keyword(raw.as_bytes()), expected, "{raw}");
+		}
+
+		// The code should list everything.
+		let code = words.phf_table();
+		assert!(code.contains("argyle::KeyWord::Alias(\"-k\", \"--key-7\")"));
+		assert_eq!(code.matches("argyle::KeyWord::").count(), 1204);
+	}
+
+	#[cfg(feature = "std")]
+	#[test]
+	fn t_argue_table() {
+		use crate::{
+			Argue,
+			Argument,
+		};
+		use std::ffi::OsString;
+
+		let mut words = KeyWordsBuilder::default();
+		for i in 0..100 { words.push_key(format!("--key-{i}")); }
+		words.push_key_with_value("-o");
+		let table: &'static KeyWordTable = Box::leak(Box::new(build_table(&words)));
+
+		let mut args = Argue::from([
+			OsString::from("--key-5"),
+			OsString::from("-ofile"),
+			OsString::from("--key-100"),
+			OsString::from("-v"),
+		].into_iter())
+			.with_keywords([KeyWord::Key("-v")])
+			.with_keyword_table(table);
+		assert_eq!(args.next(), Some(Argument::Key("--key-5")));
+		assert_eq!(args.next(), Some(Argument::KeyWithValue("-o", "file".to_owned())));
+		assert_eq!(args.next(), Some(Argument::Other("--key-100".to_owned())));
+		assert_eq!(args.next(), Some(Argument::Key("-v")));
+		assert_eq!(args.next(), None);
+	}
+}
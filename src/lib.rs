@@ -38,12 +38,13 @@ struct Settings {
 }
 
 let args = argyle::args()
-    .with_keywords([
-        KeyWord::key("-h").unwrap(),            // Boolean flag (short).
-        KeyWord::key("--help").unwrap(),        // Boolean flag (long).
-        KeyWord::key_with_value("-j").unwrap(), // Expects a value.
-        KeyWord::key_with_value("--threads").unwrap(),
-    ]);
+    .with_keywords_checked([
+        KeyWord::key("-h"),            // Boolean flag (short).
+        KeyWord::key("--help"),        // Boolean flag (long).
+        KeyWord::key_with_value("-j"), // Expects a value.
+        KeyWord::key_with_value("--threads"),
+    ])
+    .expect("Invalid keywords.");
 
 // Loop and handle!
 let mut settings = Settings::default();
@@ -79,9 +80,24 @@ for arg in args {
 
 // Now that you're set up, do stuff…
 ```
+
+
+
+## Crate Features
+
+| Feature | Default | Description |
+| ------- | ------- | ----------- |
+| `std` | Y | Enables the streaming argument iterator and other `std`-dependent functionality. |
+| `stats` | N | Tracks basic parse totals for profiling; see `Argue::stats`. |
+
+Without `std`, the crate can be used in `no_std` + `alloc` environments, but only the keyword grammar and matching logic — e.g. [`KeyWord`], [`match_keyword`] — will be available.
+
+That core is entirely platform-neutral — no environment, filesystem, or OS-specific code — so also works for targets like `wasm32-unknown-unknown`. The `std` layer builds there too; the handful of Unix- and Windows-specific bits (lossless splitting of non-UTF-8 `--key=value` arguments) are conditionally compiled, with graceful fallbacks for other platforms.
 */
 
 #![forbid(unsafe_code)]
+#![cfg_attr(not(feature = "std"), no_std)]
+#![cfg_attr(docsrs, feature(doc_cfg))]
 
 #![deny(
 	clippy::allow_attributes_without_reason,
@@ -112,7 +128,6 @@ for arg in args {
 	clippy::rest_pat_in_fully_bound_structs,
 	clippy::semicolon_inside_block,
 	clippy::str_to_string,
-	clippy::string_to_string,
 	clippy::todo,
 	clippy::undocumented_unsafe_blocks,
 	clippy::unneeded_field_pattern,
@@ -130,16 +145,59 @@ for arg in args {
 	unused_import_braces,
 )]
 
-#![expect(clippy::module_name_repetitions, reason = "Repetition is preferred.")]
 
 
+extern crate alloc;
+
+#[cfg(test)] use brunch as _;
 
-mod stream;
+mod key;
+#[cfg(feature = "std")] mod stream;
+mod version;
+
+#[doc(hidden)] pub use key::__unique_keywords;
+pub use key::{
+	Classification,
+	classify,
+	CompletionHint,
+	KeyWord,
+	KeyWordsBuilder,
+	KeyWordsDiff,
+	KeyWordTable,
+	match_keyword,
+	starts_like_key,
+};
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
 pub use stream::{
+	ApplyArgument,
 	args,
 	Argue,
 	ArgueEnv,
 	Argument,
-	KeyWord,
-	KeyWordsBuilder,
+	ColorChoice,
+	Diagnostic,
+	explain,
+	fish_completions,
+	LimitExceeded,
+	MainResult,
+	Matches,
+	NdJson,
+	NdJsonError,
+	ParseReport,
+	roundtrip,
+	run,
+	RunConfig,
+	selftest,
+	Token,
+	Tokens,
+	Trace,
+	UsageError,
+	Warning,
+	WhitespacePolicy,
+	zsh_completions,
 };
+#[cfg(feature = "stats")]
+#[cfg_attr(docsrs, doc(cfg(feature = "stats")))]
+pub use stream::ArgueStats;
+pub use version::Version;
@@ -0,0 +1,198 @@
+/*!
+# Argyle: Apply Arguments.
+*/
+
+use crate::{
+	Argue,
+	Argument,
+	Diagnostic,
+	MainResult,
+};
+use std::{
+	error::Error,
+	ffi::OsString,
+	fmt,
+};
+
+
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+/// # Usage Error.
+///
+/// This is the error type for [`ApplyArgument::apply`], holding a short,
+/// human-readable description of the problem.
+///
+/// It converts to a [`MainResult::Usage`] for easy bubbling out of `main`.
+pub struct UsageError(String);
+
+impl Error for UsageError {}
+
+impl fmt::Display for UsageError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.write_str(&self.0)
+	}
+}
+
+impl From<&str> for UsageError {
+	fn from(src: &str) -> Self { Self(src.to_owned()) }
+}
+
+impl From<String> for UsageError {
+	fn from(src: String) -> Self { Self(src) }
+}
+
+impl From<Diagnostic> for UsageError {
+	fn from(src: Diagnostic) -> Self { Self(src.to_string()) }
+}
+
+impl From<UsageError> for MainResult {
+	fn from(src: UsageError) -> Self {
+		Self::Usage(format!("Error: {src}\nRun with --help for usage."))
+	}
+}
+
+impl UsageError {
+	#[must_use]
+	/// # As String Slice.
+	///
+	/// Return the message.
+	pub fn as_str(&self) -> &str { &self.0 }
+}
+
+
+
+/// # Apply Argument.
+///
+/// Implement this for your settings struct to have [`Argue::apply_to`]
+/// feed it each parsed [`Argument`] in turn, replacing the usual
+/// loop-and-match boilerplate with a one-liner while leaving the actual
+/// handling entirely up to you.
+///
+/// ## Examples
+///
+/// ```
+/// use argyle::{Argue, ApplyArgument, Argument, KeyWord, UsageError};
+/// use std::ffi::OsString;
+///
+/// #[derive(Default)]
+/// struct Settings {
+///     threads: usize,
+///     verbose: bool,
+///     paths: Vec<String>,
+/// }
+///
+/// impl ApplyArgument for Settings {
+///     fn apply(&mut self, arg: Argument) -> Result<(), UsageError> {
+///         match arg {
+///             Argument::Key("-v") => { self.verbose = true; },
+///             Argument::KeyWithValue("-j", v) => {
+///                 self.threads = v.parse().map_err(|_| "Invalid thread count.")?;
+///             },
+///             Argument::Other(v) => { self.paths.push(v); },
+///             _ => return Err(UsageError::from("Unexpected argument.")),
+///         }
+///         Ok(())
+///     }
+/// }
+///
+/// let mut settings = Settings::default();
+/// Argue::from([
+///     OsString::from("-v"),
+///     OsString::from("-j4"),
+///     OsString::from("file.txt"),
+/// ])
+///     .with_keywords([KeyWord::key("-v").unwrap(), KeyWord::key_with_value("-j").unwrap()])
+///     .apply_to(&mut settings)
+///     .unwrap();
+///
+/// assert!(settings.verbose);
+/// assert_eq!(settings.threads, 4);
+/// assert_eq!(settings.paths, ["file.txt"]);
+/// ```
+pub trait ApplyArgument {
+	/// # Apply Argument.
+	///
+	/// Update `self` according to `arg`.
+	///
+	/// ## Errors
+	///
+	/// Return an error if the argument is invalid or unexpected; parsing
+	/// stops at the first one.
+	fn apply(&mut self, arg: Argument) -> Result<(), UsageError>;
+}
+
+
+
+impl<I: Iterator<Item=OsString>> Argue<I> {
+	/// # Apply To.
+	///
+	/// Pass each remaining [`Argument`] to `settings` via
+	/// [`ApplyArgument::apply`], stopping at the first error.
+	///
+	/// See [`ApplyArgument`] for an example.
+	///
+	/// ## Errors
+	///
+	/// Any error returned by the trait implementation is passed through
+	/// as-is.
+	pub fn apply_to<T: ApplyArgument>(&mut self, settings: &mut T) -> Result<(), UsageError> {
+		for arg in self.by_ref() { settings.apply(arg)?; }
+		Ok(())
+	}
+}
+
+
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::KeyWord;
+
+	/// # Test Settings.
+	#[derive(Debug, Default)]
+	struct Settings {
+		/// # Keys.
+		keys: Vec<&'static str>,
+	}
+
+	impl ApplyArgument for Settings {
+		fn apply(&mut self, arg: Argument) -> Result<(), UsageError> {
+			match arg {
+				Argument::Key(k) => {
+					self.keys.push(k);
+					Ok(())
+				},
+				Argument::MissingValue(k) => Err(Diagnostic::MissingValue(k).into()),
+				_ => Err("Unexpected argument.".into()),
+			}
+		}
+	}
+
+	#[test]
+	fn t_apply_to() {
+		let mut args = Argue::from([
+			OsString::from("-a"),
+			OsString::from("-b"),
+			OsString::from("-o"),
+			OsString::from("-a"),
+		].into_iter())
+			.with_keywords([KeyWord::Key("-a"), KeyWord::Key("-b"), KeyWord::KeyWithValue("-o")])
+			.with_strict_values();
+
+		// It should stop at the first error.
+		let mut settings = Settings::default();
+		let err = args.apply_to(&mut settings).unwrap_err();
+		assert_eq!(err.as_str(), "-o requires a value.");
+		assert_eq!(settings.keys, ["-a", "-b"]);
+
+		// And pick up where it left off.
+		assert!(args.apply_to(&mut settings).is_ok());
+		assert_eq!(settings.keys, ["-a", "-b", "-a"]);
+
+		// Usage errors become usage results.
+		assert_eq!(
+			MainResult::from(err),
+			MainResult::Usage("Error: -o requires a value.\nRun with --help for usage.".to_owned()),
+		);
+	}
+}
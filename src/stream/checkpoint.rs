@@ -0,0 +1,229 @@
+/*!
+# Argyle: Checkpoints.
+*/
+
+use crate::{
+	Argue,
+	Argument,
+	ColorChoice,
+	KeyWord,
+};
+use std::{
+	collections::{
+		BTreeSet,
+		VecDeque,
+	},
+	ffi::OsString,
+};
+use super::FLAG_SOURCE_DONE;
+
+
+
+/// # Checkpoint.
+///
+/// A snapshot of the parser state taken by [`Argue::checkpoint`], along with
+/// every raw argument pulled from the source since.
+pub(super) struct Checkpoint {
+	/// # Flags.
+	flags: u32,
+
+	/// # Keywords.
+	keys: BTreeSet<KeyWord>,
+
+	/// # Pre-Parsed Arguments.
+	queue: VecDeque<Argument>,
+
+	/// # Pending Arguments.
+	pending: VecDeque<OsString>,
+
+	/// # Seen Switches.
+	seen: BTreeSet<&'static str>,
+
+	/// # Command Path.
+	path: Vec<&'static str>,
+
+	/// # Byte Budget.
+	budget: usize,
+
+	/// # Color Choice.
+	color: ColorChoice,
+
+	/// # Diagnostic Count.
+	diagnostics: usize,
+
+	/// # Warning Count.
+	warnings: usize,
+
+	/// # Consumed Arguments.
+	///
+	/// Raw arguments pulled from the source since the snapshot was taken.
+	consumed: Vec<OsString>,
+}
+
+impl Checkpoint {
+	/// # Record.
+	///
+	/// Keep a copy of a raw argument pulled from the source.
+	pub(super) fn record(&mut self, raw: &OsString) { self.consumed.push(raw.clone()); }
+}
+
+
+
+impl<I> Argue<I> {
+	/// # Checkpoint.
+	///
+	/// Take a snapshot of the parser state so that any arguments parsed
+	/// afterwards can be "un-parsed" by a later call to [`Argue::restore`].
+	/// This makes it possible to speculatively consume a few arguments,
+	/// decide they weren't what you wanted, and hand them back for normal
+	/// processing.
+	///
+	/// Raw arguments pulled from the source while a checkpoint is active are
+	/// buffered — cloned — until [`Argue::restore`] or [`Argue::commit`] is
+	/// called, so checkpoints are best kept short-lived.
+	///
+	/// Only one checkpoint is kept at a time; calling this again replaces
+	/// any existing one.
+	///
+	/// Side effects like abort hooks, key callbacks, and tracers are _not_
+	/// undone by a restore, nor are the running totals kept by the `stats`
+	/// feature, but everything the iterator itself tracks — flags,
+	/// keywords, the command path, byte budget, diagnostics, warnings,
+	/// etc. — is.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use argyle::{Argue, Argument, KeyWord};
+	/// use std::ffi::OsString;
+	///
+	/// let mut args = Argue::from([
+	///     OsString::from("-v"),
+	///     OsString::from("file.txt"),
+	/// ])
+	///     .with_keywords([KeyWord::key("-v").unwrap()]);
+	///
+	/// // Peek ahead for an optional path…
+	/// args.checkpoint();
+	/// assert_eq!(args.next(), Some(Argument::Key("-v")));
+	/// assert_eq!(args.next(), Some(Argument::Other("file.txt".to_owned())));
+	///
+	/// // …then pretend we never looked.
+	/// assert!(args.restore());
+	/// assert_eq!(args.next(), Some(Argument::Key("-v")));
+	/// assert_eq!(args.next(), Some(Argument::Other("file.txt".to_owned())));
+	/// assert_eq!(args.next(), None);
+	/// ```
+	pub fn checkpoint(&mut self) {
+		self.checkpoint = Some(Box::new(Checkpoint {
+			flags: self.flags,
+			keys: self.keys.clone(),
+			queue: self.queue.clone(),
+			pending: self.pending.clone(),
+			seen: self.seen.clone(),
+			path: self.path.clone(),
+			budget: self.budget,
+			color: self.color,
+			diagnostics: self.diagnostics.len(),
+			warnings: self.warnings.len(),
+			consumed: Vec::new(),
+		}));
+	}
+
+	/// # Restore Checkpoint.
+	///
+	/// Roll the parser back to the state it was in when
+	/// [`Argue::checkpoint`] was last called, re-queueing any arguments
+	/// consumed since. The checkpoint is used up in the process.
+	///
+	/// Returns `false` if there was no checkpoint to restore.
+	pub fn restore(&mut self) -> bool {
+		let Some(cp) = self.checkpoint.take() else { return false; };
+		let Checkpoint {
+			flags, keys, queue, mut pending, seen, path, budget, color,
+			diagnostics, warnings, consumed,
+		} = *cp;
+
+		// Anything pulled from the source since goes after whatever was
+		// already pending.
+		pending.extend(consumed);
+
+		// An exhausted source stays exhausted.
+		self.flags = flags | (self.flags & FLAG_SOURCE_DONE);
+		self.keys = keys;
+		self.queue = queue;
+		self.pending = pending;
+		self.seen = seen;
+		self.path = path;
+		self.budget = budget;
+		self.color = color;
+		self.diagnostics.truncate(diagnostics);
+		self.warnings.truncate(warnings);
+		true
+	}
+
+	/// # Commit Checkpoint.
+	///
+	/// Discard the current checkpoint, if any, keeping everything parsed
+	/// since, and stop buffering.
+	pub fn commit(&mut self) { self.checkpoint = None; }
+}
+
+
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	#[test]
+	fn t_checkpoint() {
+		let mut args = Argue::from([
+			OsString::from("-v"),
+			OsString::from("build"),
+			OsString::from("-o"),
+			OsString::from("-v"),
+			OsString::from("file.txt"),
+		].into_iter())
+			.with_keywords([
+				KeyWord::Command("build"),
+				KeyWord::Key("-v"),
+				KeyWord::KeyWithValue("-o"),
+			])
+			.with_subcommands("build", [KeyWord::Key("--release")])
+			.with_diagnostics()
+			.with_warnings()
+			.with_strict_values();
+
+		// Nothing to restore yet.
+		assert!(! args.restore());
+
+		assert_eq!(args.next(), Some(Argument::Key("-v")));
+		args.checkpoint();
+		assert_eq!(args.next(), Some(Argument::Command("build")));
+		assert_eq!(args.next(), Some(Argument::MissingValue("-o")));
+		assert_eq!(args.next(), Some(Argument::Key("-v")));
+		assert_eq!(args.command_path(), ["build"]);
+		assert_eq!(args.warnings().len(), 1);
+
+		// Roll it back.
+		assert!(args.restore());
+		assert!(args.command_path().is_empty());
+		assert!(args.warnings().is_empty());
+		assert!(! args.restore());
+
+		// The same things should come out the second time, and this time
+		// we'll keep them.
+		args.checkpoint();
+		assert_eq!(args.next(), Some(Argument::Command("build")));
+		assert_eq!(args.next(), Some(Argument::MissingValue("-o")));
+		args.commit();
+		assert!(! args.restore());
+		assert_eq!(args.next(), Some(Argument::Key("-v")));
+		assert_eq!(args.next(), Some(Argument::Other("file.txt".to_owned())));
+		assert_eq!(args.next(), None);
+		assert_eq!(args.warnings(), [crate::Warning::Duplicate("-v")]);
+
+		// The diagnostics from the first pass should have been forgotten.
+		assert_eq!(args.finish(), [crate::Diagnostic::MissingValue("-o")]);
+	}
+}
@@ -0,0 +1,217 @@
+/*!
+# Argyle: Color Flags.
+*/
+
+use crate::{
+	Argue,
+	Argument,
+	KeyWord,
+};
+use std::io::IsTerminal;
+use super::{
+	EnvSource,
+	FLAG_COLOR,
+};
+
+
+
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+/// # Color Choice.
+///
+/// This is the user's preference regarding colored output, as parsed by
+/// [`Argue::with_color_flag`] and returned by [`Argue::color_choice`].
+pub enum ColorChoice {
+	#[default]
+	/// # Auto.
+	///
+	/// Use color if the output is a terminal.
+	Auto,
+
+	/// # Always.
+	Always,
+
+	/// # Never.
+	Never,
+}
+
+impl ColorChoice {
+	#[must_use]
+	/// # From Value.
+	///
+	/// Parse a `--color=WHEN` value. In addition to `auto`, `always`, and
+	/// `never`, the GNU synonyms `tty`/`if-tty`, `yes`/`force`, and
+	/// `no`/`none` are accepted.
+	pub fn from_value(src: &str) -> Option<Self> {
+		match src.trim() {
+			"auto" | "tty" | "if-tty" => Some(Self::Auto),
+			"always" | "yes" | "force" => Some(Self::Always),
+			"never" | "no" | "none" => Some(Self::Never),
+			_ => None,
+		}
+	}
+}
+
+
+
+impl<I> Argue<I> {
+	#[must_use]
+	/// # With Color Flag.
+	///
+	/// Register `--color[=WHEN]` and `--no-color`, and handle them
+	/// internally, making the result available via [`Argue::color_choice`].
+	///
+	/// A bare `--color` is the same as `--color=always`. If the flags appear
+	/// more than once, the last one wins.
+	///
+	/// Recognized flags are consumed rather than returned, but an
+	/// unrecognized `WHEN` is passed through as a regular
+	/// [`Argument::KeyWithValue`] so you can complain about it.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use argyle::{Argue, Argument, ColorChoice};
+	/// use std::ffi::OsString;
+	///
+	/// let mut args = Argue::from([
+	///     OsString::from("--color=never"),
+	///     OsString::from("file.txt"),
+	/// ])
+	///     .with_color_flag();
+	///
+	/// assert_eq!(args.next(), Some(Argument::Other("file.txt".to_owned())));
+	/// assert_eq!(args.next(), None);
+	/// assert_eq!(args.color_choice(), ColorChoice::Never);
+	/// ```
+	pub fn with_color_flag(mut self) -> Self {
+		self.flags |= FLAG_COLOR;
+		self.with_keywords([
+			KeyWord::KeyWithOptionalValue("--color"),
+			KeyWord::Key("--no-color"),
+		])
+	}
+
+	#[must_use]
+	/// # Color Choice.
+	///
+	/// Return the user's color preference — as determined by the flags
+	/// parsed so far — or failing that, the environment:
+	/// * A non-empty `NO_COLOR` means [`ColorChoice::Never`];
+	/// * A non-empty, non-zero `CLICOLOR_FORCE` means [`ColorChoice::Always`];
+	///
+	/// [`ColorChoice::Auto`] is resolved by checking whether or not STDOUT is
+	/// a terminal, so the result is always either `Always` or `Never`.
+	///
+	/// This requires [`Argue::with_color_flag`]; without it, only the
+	/// environment is considered.
+	pub fn color_choice(&self) -> ColorChoice {
+		let choice =
+			if matches!(self.color, ColorChoice::Auto) { env_color(self.env) }
+			else { self.color };
+
+		match choice {
+			ColorChoice::Auto =>
+				if std::io::stdout().is_terminal() { ColorChoice::Always }
+				else { ColorChoice::Never },
+			c => c,
+		}
+	}
+
+	/// # Color Argument.
+	///
+	/// Return the corresponding choice if `arg` is a (valid) color flag and
+	/// [`Argue::with_color_flag`] is in effect.
+	pub(super) fn color_arg(&self, arg: &Argument) -> Option<ColorChoice> {
+		if 0 == self.flags & FLAG_COLOR { return None; }
+		match arg {
+			Argument::Key("--color") => Some(ColorChoice::Always),
+			Argument::Key("--no-color") => Some(ColorChoice::Never),
+			Argument::KeyWithValue("--color", v) => ColorChoice::from_value(v),
+			_ => None,
+		}
+	}
+}
+
+
+
+/// # Color From Environment.
+///
+/// Check the `NO_COLOR` and `CLICOLOR_FORCE` environment variables.
+fn env_color(env: EnvSource) -> ColorChoice {
+	let set = |key: &str| env(key).is_some_and(|v| ! v.is_empty() && v != "0");
+	if set("NO_COLOR") { ColorChoice::Never }
+	else if set("CLICOLOR_FORCE") { ColorChoice::Always }
+	else { ColorChoice::Auto }
+}
+
+
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use std::ffi::OsString;
+
+	#[test]
+	fn t_from_value() {
+		for (raw, expected) in [
+			("auto", Some(ColorChoice::Auto)),
+			("if-tty", Some(ColorChoice::Auto)),
+			("always", Some(ColorChoice::Always)),
+			("force", Some(ColorChoice::Always)),
+			("never", Some(ColorChoice::Never)),
+			(" none ", Some(ColorChoice::Never)),
+			("", None),
+			("sometimes", None),
+		] {
+			assert_eq!(ColorChoice::from_value(raw), expected, "{raw}");
+		}
+	}
+
+	#[test]
+	fn t_color_flag() {
+		let mut args = Argue::from([
+			OsString::from("--color"),
+			OsString::from("a"),
+			OsString::from("--color=maybe"),
+			OsString::from("--no-color"),
+			OsString::from("--color=always"),
+		].into_iter())
+			.with_color_flag();
+
+		assert_eq!(args.next(), Some(Argument::Other("a".to_owned())));
+		assert_eq!(args.color, ColorChoice::Always);
+		assert_eq!(args.next(), Some(Argument::KeyWithValue("--color", "maybe".to_owned())));
+		assert_eq!(args.next(), None);
+		assert_eq!(args.color, ColorChoice::Always);
+		assert_eq!(args.color_choice(), ColorChoice::Always);
+
+		// Without the flag, they're just keys.
+		let mut args = Argue::from([OsString::from("--color")].into_iter())
+			.with_keywords([KeyWord::Key("--color")]);
+		assert_eq!(args.next(), Some(Argument::Key("--color")));
+		assert_eq!(args.color, ColorChoice::Auto);
+	}
+
+	#[test]
+	fn t_color_env() {
+		let args = Argue::from(Vec::<OsString>::new())
+			.with_env_source(|k| (k == "NO_COLOR").then(|| OsString::from("1")));
+		assert_eq!(args.color_choice(), ColorChoice::Never);
+
+		let args = Argue::from(Vec::<OsString>::new())
+			.with_env_source(|k| (k == "CLICOLOR_FORCE").then(|| OsString::from("1")));
+		assert_eq!(args.color_choice(), ColorChoice::Always);
+
+		// Zero doesn't count.
+		let args = Argue::from(Vec::<OsString>::new())
+			.with_env_source(|_| Some(OsString::from("0")));
+		assert_eq!(env_color(args.env), ColorChoice::Auto);
+
+		// Flags beat the environment.
+		let mut args = Argue::from([OsString::from("--color")])
+			.with_env_source(|k| (k == "NO_COLOR").then(|| OsString::from("1")))
+			.with_color_flag();
+		assert_eq!(args.next(), None);
+		assert_eq!(args.color_choice(), ColorChoice::Always);
+	}
+}
@@ -0,0 +1,347 @@
+/*!
+# Argyle: Dynamic Completions.
+*/
+
+use crate::{
+	Argue,
+	CompletionHint,
+	KeyWord,
+};
+use std::collections::BTreeSet;
+
+
+
+#[must_use]
+/// # Fish Completions.
+///
+/// Generate a fish completion script for `bin` that calls back into the
+/// binary — `bin --complete -- WORD PREV` — for its suggestions, keeping
+/// them in sync with the app's actual keywords without any regeneration.
+///
+/// The binary is expected to answer such calls with the results of
+/// [`Argue::complete`], one per line. If there aren't any, fish's own file
+/// completion takes over; otherwise files are left out.
+///
+/// ## Examples
+///
+/// ```
+/// let script = argyle::fish_completions("mybin");
+/// assert!(script.contains("mybin --complete -- (commandline -ct) $words[-1]"));
+/// ```
+///
+/// ## Panics
+///
+/// This will panic if `bin` contains anything other than ASCII
+/// alphanumerics, dashes, underscores, and dots.
+pub fn fish_completions(bin: &str) -> String {
+	let func = helper_name(bin);
+	format!(
+		"# Dynamic completions for {bin}.
+function {func}
+	set -l words (commandline -opc)
+	set -l candidates ({bin} --complete -- (commandline -ct) $words[-1] 2>/dev/null)
+	if set -q candidates[1]
+		printf '%s\\n' $candidates
+	else
+		__fish_complete_path (commandline -ct)
+	end
+end
+
+complete -c {bin} -f -a '({func})'
+",
+	)
+}
+
+#[must_use]
+/// # Zsh Completions.
+///
+/// Generate a zsh completion script for `bin` that calls back into the
+/// binary — `bin --complete -- WORD PREV` — for its suggestions, keeping
+/// them in sync with the app's actual keywords without any regeneration.
+///
+/// The binary is expected to answer such calls with the results of
+/// [`Argue::complete`], one per line. If there aren't any, zsh's own file
+/// completion takes over.
+///
+/// ## Examples
+///
+/// ```
+/// let script = argyle::zsh_completions("mybin");
+/// assert!(script.starts_with("#compdef mybin\n"));
+/// ```
+///
+/// ## Panics
+///
+/// This will panic if `bin` contains anything other than ASCII
+/// alphanumerics, dashes, underscores, and dots.
+pub fn zsh_completions(bin: &str) -> String {
+	let func = helper_name(bin);
+	format!(
+		r#"#compdef {bin}
+# Dynamic completions for {bin}.
+{func}() {{
+	local -a candidates
+	candidates=(${{(f)"$({bin} --complete -- "${{words[CURRENT]}}" "${{words[CURRENT-1]}}" 2>/dev/null)"}})
+	if (( ${{#candidates}} )); then
+		compadd -a candidates
+	else
+		_files
+	fi
+}}
+
+compdef {func} {bin}
+"#,
+	)
+}
+
+/// # Helper Function Name.
+///
+/// Return the name to use for the generated shell function, panicking if
+/// `bin` isn't something we can safely embed in a script.
+fn helper_name(bin: &str) -> String {
+	assert!(
+		! bin.is_empty() &&
+		bin.bytes().all(|b| b.is_ascii_alphanumeric() || matches!(b, b'-' | b'_' | b'.')),
+		"Invalid binary name: {bin}",
+	);
+	format!("__{}_complete", bin.replace(['-', '.'], "_"))
+}
+
+
+
+impl<I> Argue<I> {
+	#[must_use]
+	/// # With Completion Hints.
+	///
+	/// Describe the values expected by [`KeyWord::KeyWithValue`] and
+	/// [`KeyWord::KeyWithOptionalValue`] keys — the same
+	/// [`CompletionHint`]s used by [`KeyWordsBuilder::set_hint`](crate::KeyWordsBuilder::set_hint)
+	/// — for the benefit of [`Argue::complete`].
+	///
+	/// [`CompletionHint::Choice`] lists are offered up as candidates; the
+	/// other hints leave the shell to its default (file) completion.
+	///
+	/// This is purely informational; values are _not_ checked against the
+	/// hints during parsing.
+	///
+	/// See [`Argue::complete`] for an example.
+	pub fn with_hints<H>(mut self, hints: H) -> Self
+	where H: IntoIterator<Item=(&'static str, CompletionHint)> {
+		self.hints.extend(hints);
+		self
+	}
+
+	#[must_use]
+	/// # Complete.
+	///
+	/// Return the candidate completions for the `word` being typed, given
+	/// the `prev`ious word (if any), sorted and deduplicated:
+	///
+	/// * If `prev` is a key expecting a value, its [`CompletionHint::Choice`] (see [`Argue::with_hints`]);
+	/// * If `word` is a long key with a partial value, like `--format=j`, the matching `key=choice` pairs;
+	/// * If `word` starts with a dash, the matching keys;
+	/// * Otherwise the matching commands.
+	///
+	/// An empty result means there's nothing to suggest, in which case the
+	/// shell should fall back to its default (file) completion.
+	///
+	/// Only the top-level keywords are considered; subcommands registered
+	/// with [`Argue::with_subcommands`] don't come into scope until their
+	/// parent has been parsed.
+	///
+	/// This is the runtime half of the dynamic completion system; see
+	/// [`fish_completions`] and [`zsh_completions`] for the shell half, which
+	/// call the binary like `mybin --complete -- WORD PREV`.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use argyle::{Argue, CompletionHint, KeyWord};
+	/// use std::ffi::OsString;
+	///
+	/// let args = Argue::from(Vec::<OsString>::new())
+	///     .with_keywords([
+	///         KeyWord::Command("build"),
+	///         KeyWord::Command("bench"),
+	///         KeyWord::Key("--force"),
+	///         KeyWord::KeyWithValue("--format"),
+	///         KeyWord::Alias("-f", "--format"),
+	///     ])
+	///     .with_hints([
+	///         ("--format", CompletionHint::Choice(vec!["json".to_owned(), "yaml".to_owned()])),
+	///     ]);
+	///
+	/// assert_eq!(args.complete("b", None), ["bench", "build"]);
+	/// assert_eq!(args.complete("--f", None), ["--force", "--format"]);
+	/// assert_eq!(args.complete("", Some("-f")), ["json", "yaml"]);
+	/// assert_eq!(args.complete("--format=j", None), ["--format=json"]);
+	///
+	/// // In main, something like:
+	/// // let raw: Vec<String> = std::env::args().skip(1).collect();
+	/// // if let [flag, sep, word, rest @ ..] = raw.as_slice() {
+	/// //     if flag == "--complete" && sep == "--" {
+	/// //         for c in args.complete(word, rest.first().map(String::as_str)) {
+	/// //             println!("{c}");
+	/// //         }
+	/// //         return;
+	/// //     }
+	/// // }
+	/// ```
+	pub fn complete(&self, word: &str, prev: Option<&str>) -> Vec<String> {
+		let mut out = BTreeSet::new();
+
+		// Values for the previous key?
+		if let Some(KeyWord::KeyWithValue(k)) = prev.and_then(|p| self.completion_key(p)) {
+			for c in self.choices(k) {
+				if c.starts_with(word) { out.insert(c.clone()); }
+			}
+		}
+		// Values glued to a long key?
+		else if let Some((raw, partial)) = word.split_once('=').filter(|(k, _)| k.starts_with("--")) {
+			if let Some(KeyWord::KeyWithValue(k) | KeyWord::KeyWithOptionalValue(k)) = self.completion_key(raw) {
+				for c in self.choices(k) {
+					if c.starts_with(partial) { out.insert(format!("{raw}={c}")); }
+				}
+			}
+		}
+		// Keys or commands.
+		else {
+			let keys = word.starts_with('-');
+			for kw in self.all_keywords() {
+				let k = kw.as_str();
+				if k.starts_with(word) && keys == k.starts_with('-') {
+					out.insert(k.to_owned());
+				}
+			}
+		}
+
+		out.into_iter().collect()
+	}
+
+	/// # All Keywords.
+	///
+	/// Return an iterator over the top-level keywords, including those from
+	/// the static table, if any.
+	fn all_keywords(&self) -> impl Iterator<Item=&KeyWord> {
+		self.keys.iter().chain(self.table.map_or(&[][..], |t| t.keywords()))
+	}
+
+	/// # Choices.
+	///
+	/// Return the [`CompletionHint::Choice`] list for `key`, if any.
+	fn choices(&self, key: &str) -> &[String] {
+		match self.hints.get(key) {
+			Some(CompletionHint::Choice(all)) => all,
+			_ => &[],
+		}
+	}
+
+	/// # Completion Key.
+	///
+	/// Return the keyword matching `raw` exactly, with aliases resolved.
+	fn completion_key(&self, raw: &str) -> Option<KeyWord> {
+		let (key, value) = crate::match_keyword(&self.keys, raw.as_bytes())
+			.or_else(|| self.table.and_then(|t| t.match_keyword(raw.as_bytes())))?;
+		if value.is_none() { Some(key) }
+		else { None }
+	}
+}
+
+
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use std::ffi::OsString;
+
+	/// # Choice Hint.
+	fn choice(all: &[&str]) -> CompletionHint {
+		CompletionHint::Choice(all.iter().map(|&s| s.to_owned()).collect())
+	}
+
+	#[test]
+	fn t_complete() {
+		let args = Argue::from([OsString::from("-v")].into_iter())
+			.with_keywords([
+				KeyWord::Command("build"),
+				KeyWord::Alias("b", "build"),
+				KeyWord::Key("-v"),
+				KeyWord::Key("--verbose"),
+				KeyWord::KeyWithValue("-o"),
+				KeyWord::KeyWithValue("--out"),
+				KeyWord::KeyWithOptionalValue("--color"),
+			])
+			.with_subcommands("build", [KeyWord::Command("all")])
+			.with_hints([
+				("--color", choice(&["never"])),
+				("--color", choice(&["never", "always", "auto", "auto"])),
+				("-o", choice(&["a.txt", "b.txt"])),
+				("--out", CompletionHint::File),
+			]);
+
+		// Commands and keys.
+		assert_eq!(args.complete("", None), ["b", "build"]);
+		assert_eq!(args.complete("bu", None), ["build"]);
+		assert_eq!(args.complete("a", None), Vec::<String>::new());
+		assert_eq!(args.complete("-", None), ["--color", "--out", "--verbose", "-o", "-v"]);
+		assert_eq!(args.complete("--v", Some("build")), ["--verbose"]);
+
+		// Values.
+		assert_eq!(args.complete("", Some("-o")), ["a.txt", "b.txt"]);
+		assert_eq!(args.complete("b", Some("-o")), ["b.txt"]);
+		assert_eq!(args.complete("", Some("--out")), Vec::<String>::new());
+		assert_eq!(args.complete("--color=a", None), ["--color=always", "--color=auto"]);
+		assert_eq!(args.complete("--color=", None), ["--color=always", "--color=auto", "--color=never"]);
+		assert_eq!(args.complete("--nope=", None), Vec::<String>::new());
+
+		// Optional values aren't consumed from the next word.
+		assert_eq!(args.complete("", Some("--color")), ["b", "build"]);
+
+		// Nor are glued values.
+		assert_eq!(args.complete("", Some("-oa.txt")), ["b", "build"]);
+	}
+
+	#[test]
+	fn t_completion_scripts() {
+		assert_eq!(
+			fish_completions("my-bin"),
+			"# Dynamic completions for my-bin.
+function __my_bin_complete
+	set -l words (commandline -opc)
+	set -l candidates (my-bin --complete -- (commandline -ct) $words[-1] 2>/dev/null)
+	if set -q candidates[1]
+		printf '%s\\n' $candidates
+	else
+		__fish_complete_path (commandline -ct)
+	end
+end
+
+complete -c my-bin -f -a '(__my_bin_complete)'
+",
+		);
+
+		assert_eq!(
+			zsh_completions("my.bin"),
+			r#"#compdef my.bin
+# Dynamic completions for my.bin.
+__my_bin_complete() {
+	local -a candidates
+	candidates=(${(f)"$(my.bin --complete -- "${words[CURRENT]}" "${words[CURRENT-1]}" 2>/dev/null)"})
+	if (( ${#candidates} )); then
+		compadd -a candidates
+	else
+		_files
+	fi
+}
+
+compdef __my_bin_complete my.bin
+"#,
+		);
+	}
+
+	#[test]
+	#[should_panic(expected = "Invalid binary name: my bin")]
+	fn t_completion_scripts_invalid() {
+		let _res = fish_completions("my bin");
+	}
+}
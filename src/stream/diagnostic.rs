@@ -0,0 +1,214 @@
+/*!
+# Argyle: Diagnostics.
+*/
+
+use crate::{
+	Argue,
+	Argument,
+};
+use std::{
+	ffi::OsString,
+	fmt,
+};
+
+
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+/// # Diagnostic.
+///
+/// This is a parse problem collected by [`Argue`] when
+/// [`Argue::with_diagnostics`] is in effect, or reported by the app itself
+/// via [`Argue::report_invalid_value`].
+///
+/// The `Display` implementation produces short, human-readable messages
+/// suitable for printing as-are.
+pub enum Diagnostic {
+	/// # Unknown Key.
+	///
+	/// A key-like [`Argument::Other`] that didn't match any keyword.
+	UnknownKey(String),
+
+	/// # Missing Value.
+	///
+	/// See [`Argument::MissingValue`].
+	MissingValue(&'static str),
+
+	/// # Unexpected Value.
+	///
+	/// See [`Argument::UnexpectedValue`].
+	UnexpectedValue(&'static str, String),
+
+	/// # Invalid Value.
+	///
+	/// A value rejected by the app.
+	InvalidValue(&'static str, String),
+
+	/// # Invalid UTF-8.
+	///
+	/// See [`Argument::InvalidUtf8`].
+	InvalidUtf8(OsString),
+
+	/// # Invalid UTF-8 Value.
+	///
+	/// See [`Argument::KeyWithInvalidValue`].
+	InvalidUtf8Value(&'static str, OsString),
+
+	/// # Placeholder.
+	///
+	/// See [`Argument::Placeholder`].
+	Placeholder(Option<&'static str>, String),
+
+	/// # Value Too Long.
+	///
+	/// See [`Argument::ValueTooLong`].
+	ValueTooLong(&'static str, usize),
+}
+
+impl fmt::Display for Diagnostic {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Self::UnknownKey(k) => write!(f, "Unknown key: {k}"),
+			Self::MissingValue(k) => write!(f, "{k} requires a value."),
+			Self::UnexpectedValue(k, _) => write!(f, "{k} does not take a value."),
+			Self::InvalidValue(k, v) => write!(f, "Invalid value for {k}: {v}"),
+			Self::InvalidUtf8(v) => write!(f, "Invalid UTF-8: {v:?}"),
+			Self::InvalidUtf8Value(k, v) => write!(f, "Invalid UTF-8 for {k}: {v:?}"),
+			Self::Placeholder(Some(k), v) => write!(f, "Unexpanded placeholder for {k}: {v}"),
+			Self::Placeholder(None, v) => write!(f, "Unexpanded placeholder: {v}"),
+			Self::ValueTooLong(k, len) => write!(f, "The value for {k} is too long ({len} bytes)."),
+		}
+	}
+}
+
+impl Diagnostic {
+	/// # From Argument.
+	///
+	/// Return the diagnostic corresponding to an erroneous [`Argument`], if
+	/// any.
+	///
+	/// Note: unknown keys require context, so aren't handled here.
+	pub(super) fn from_arg(arg: &Argument) -> Option<Self> {
+		match arg {
+			Argument::MissingValue(k) => Some(Self::MissingValue(k)),
+			Argument::UnexpectedValue(k, v) => Some(Self::UnexpectedValue(k, v.clone())),
+			Argument::InvalidUtf8(v) => Some(Self::InvalidUtf8(v.clone())),
+			Argument::KeyWithInvalidValue(k, v) => Some(Self::InvalidUtf8Value(k, v.clone())),
+			Argument::Placeholder { key, value } => Some(Self::Placeholder(*key, value.clone())),
+			Argument::ValueTooLong(k, len) => Some(Self::ValueTooLong(k, *len)),
+			_ => None,
+		}
+	}
+}
+
+
+
+impl<I> Argue<I> {
+	/// # Report Invalid Value.
+	///
+	/// Add an app-level validation failure to the list of diagnostics
+	/// returned by [`Argue::finish`].
+	///
+	/// Unlike the automatic checks, this works regardless of whether or not
+	/// [`Argue::with_diagnostics`] is in effect.
+	pub fn report_invalid_value(&mut self, key: &'static str, value: String) {
+		self.diagnostics.push(Diagnostic::InvalidValue(key, value));
+	}
+}
+
+impl<I: Iterator<Item=OsString>> Argue<I> {
+	#[must_use]
+	/// # Finish.
+	///
+	/// Drive the iterator to completion — discarding any remaining
+	/// arguments — and return all of the [`Diagnostic`]s collected along the
+	/// way, in order.
+	///
+	/// Unless [`Argue::with_diagnostics`] is in effect, this will only include
+	/// problems explicitly reported via [`Argue::report_invalid_value`].
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use argyle::{Argue, Argument, Diagnostic, KeyWord};
+	/// use std::ffi::OsString;
+	///
+	/// let mut args = Argue::from([
+	///     OsString::from("--nope"),
+	///     OsString::from("--threads=many"),
+	///     OsString::from("-v=1"),
+	///     OsString::from("--out"),
+	/// ])
+	///     .with_keywords([
+	///         KeyWord::key("-v").unwrap(),
+	///         KeyWord::key_with_value("--out").unwrap(),
+	///         KeyWord::key_with_value("--threads").unwrap(),
+	///     ])
+	///     .with_diagnostics();
+	///
+	/// while let Some(arg) = args.next() {
+	///     if let Argument::KeyWithValue("--threads", v) = arg {
+	///         if v.parse::<u8>().is_err() {
+	///             args.report_invalid_value("--threads", v);
+	///         }
+	///     }
+	/// }
+	///
+	/// let problems = args.finish();
+	/// assert_eq!(problems.len(), 4);
+	/// assert_eq!(problems[0].to_string(), "Unknown key: --nope");
+	/// assert_eq!(problems[1].to_string(), "Invalid value for --threads: many");
+	/// assert_eq!(problems[2].to_string(), "-v does not take a value.");
+	/// assert_eq!(problems[3].to_string(), "--out requires a value.");
+	/// ```
+	pub fn finish(mut self) -> Vec<Diagnostic> {
+		for _ in self.by_ref() {}
+		self.diagnostics
+	}
+}
+
+
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::KeyWord;
+
+	#[test]
+	fn t_finish() {
+		let cli = [
+			OsString::from("-x"),
+			OsString::from("file.txt"),
+			OsString::from("-y"),
+			OsString::from("--"),
+			OsString::from("-z"),
+		];
+
+		// Nothing without the flag.
+		let args = Argue::from(cli.iter().cloned());
+		assert!(args.finish().is_empty());
+
+		// Key-like others are unknown keys.
+		let args = Argue::from(cli.iter().cloned()).with_diagnostics();
+		assert_eq!(
+			args.finish(),
+			[
+				Diagnostic::UnknownKey("-x".to_owned()),
+				Diagnostic::UnknownKey("-y".to_owned()),
+			],
+		);
+
+		// Unless we're past the point of parsing.
+		let args = Argue::from(cli.iter().cloned())
+			.with_posix()
+			.with_diagnostics();
+		assert_eq!(args.finish(), [Diagnostic::UnknownKey("-x".to_owned())]);
+
+		// Partitioning shouldn't lead to duplicates.
+		let (found, args) = Argue::from(cli.iter().cloned())
+			.with_keywords([KeyWord::Key("-y")])
+			.with_diagnostics()
+			.partition_keys(["-y"]);
+		assert_eq!(found, [Argument::Key("-y")]);
+		assert_eq!(args.finish(), [Diagnostic::UnknownKey("-x".to_owned())]);
+	}
+}
@@ -0,0 +1,160 @@
+/*!
+# Argyle: Explain.
+*/
+
+use crate::Argument;
+use std::fmt::Write;
+
+
+
+/// # Explain.
+///
+/// Consume a stream of parsed [`Argument`]s and return a human-readable
+/// breakdown, one line per argument, suitable for `--dry-run` or debug
+/// output, or for attaching to bug reports about parsing behavior.
+///
+/// Runs of consecutive positional arguments are summarized on a single
+/// line, as are the arguments following a `--` separator.
+///
+/// The exact wording is meant for humans, not machines, and may change
+/// between releases.
+///
+/// ## Examples
+///
+/// ```
+/// use argyle::{Argue, KeyWord};
+/// use std::ffi::OsString;
+///
+/// let args = Argue::from([
+///     OsString::from("--verbose"),
+///     OsString::from("--threads=4"),
+///     OsString::from("a.txt"),
+///     OsString::from("b.txt"),
+///     OsString::from("--"),
+///     OsString::from("-x"),
+/// ])
+///     .with_keywords([
+///         KeyWord::key("--verbose").unwrap(),
+///         KeyWord::key_with_value("--threads").unwrap(),
+///     ]);
+///
+/// assert_eq!(
+///     argyle::explain(args),
+///     "switch --verbose
+/// option --threads = 4
+/// 2 positionals: a.txt, b.txt
+/// 1 arg after --",
+/// );
+/// ```
+pub fn explain<I: IntoIterator<Item=Argument>>(args: I) -> String {
+	let mut out = String::new();
+	let mut others: Vec<String> = Vec::new();
+	for arg in args {
+		// Positionals are held until the run ends.
+		if let Argument::Other(v) = arg {
+			others.push(v);
+			continue;
+		}
+		flush_others(&mut out, &mut others);
+
+		if ! out.is_empty() { out.push('\n'); }
+		let _res = match arg {
+			Argument::Command(k) => write!(&mut out, "command {k}"),
+			Argument::CommandWith(k, v) =>
+				write!(&mut out, "command {k} with {}", plural(v.len(), "arg")),
+			Argument::Key(k) => write!(&mut out, "switch {k}"),
+			Argument::KeyWithValue(k, v) => write!(&mut out, "option {k} = {v}"),
+			Argument::KeyWithOsValue(k, v) =>
+				write!(&mut out, "option {k} = {}", v.to_string_lossy()),
+			Argument::KeyWithValues(k, v) =>
+				write!(&mut out, "option {k} = {}", v.join(", ")),
+			Argument::UnexpectedValue(k, v) =>
+				write!(&mut out, "switch {k} (unexpected value: {v})"),
+			Argument::MissingValue(k) => write!(&mut out, "option {k} (missing value)"),
+			Argument::KeyWithInvalidValue(k, v) =>
+				write!(&mut out, "option {k} = {v:?} (invalid UTF-8)"),
+			Argument::Namespaced(prefix, v) => write!(&mut out, "namespaced {prefix} {v}"),
+			Argument::Help { topic: Some(topic) } => write!(&mut out, "help for {topic}"),
+			Argument::Help { topic: None } => write!(&mut out, "help"),
+			Argument::Stdin => write!(&mut out, "stdin"),
+			Argument::ValueTooLong(k, len) =>
+				write!(&mut out, "option {k} (value too long: {len} bytes)"),
+			Argument::Placeholder { key: Some(k), value } =>
+				write!(&mut out, "option {k} = {value} (unexpanded placeholder)"),
+			Argument::Placeholder { key: None, value } =>
+				write!(&mut out, "positional {value} (unexpanded placeholder)"),
+			Argument::InvalidUtf8(v) => write!(&mut out, "positional {v:?} (invalid UTF-8)"),
+			Argument::End(v) => write!(&mut out, "{} after --", plural(v.len(), "arg")),
+			// Handled above.
+			Argument::Other(_) => Ok(()),
+		};
+	}
+
+	flush_others(&mut out, &mut others);
+	out
+}
+
+/// # Flush Positionals.
+///
+/// Write out the pending run of positional arguments, if any.
+fn flush_others(out: &mut String, others: &mut Vec<String>) {
+	if others.is_empty() { return; }
+	if ! out.is_empty() { out.push('\n'); }
+	let _res =
+		if let [v] = others.as_slice() { write!(out, "positional {v}") }
+		else { write!(out, "{}: {}", plural(others.len(), "positional"), others.join(", ")) };
+	others.truncate(0);
+}
+
+/// # Pluralize.
+///
+/// Return something like "1 arg" or "2 args".
+fn plural(n: usize, noun: &str) -> String {
+	if n == 1 { format!("1 {noun}") }
+	else { format!("{n} {noun}s") }
+}
+
+
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use std::ffi::OsString;
+
+	#[test]
+	fn t_explain() {
+		assert_eq!(explain(Vec::new()), "");
+
+		let args = vec![
+			Argument::Other("a".to_owned()),
+			Argument::Command("build"),
+			Argument::CommandWith("remote", vec!["add".to_owned()]),
+			Argument::Key("-v"),
+			Argument::UnexpectedValue("-v", "1".to_owned()),
+			Argument::KeyWithValues("--size", vec!["1".to_owned(), "2".to_owned()]),
+			Argument::MissingValue("-o"),
+			Argument::Help { topic: Some("build".to_owned()) },
+			Argument::Stdin,
+			Argument::Other("b".to_owned()),
+			Argument::Other("c".to_owned()),
+			Argument::Other("d".to_owned()),
+			Argument::Placeholder { key: None, value: "{}".to_owned() },
+			Argument::End(vec![OsString::from("x"), OsString::from("y")]),
+		];
+		assert_eq!(
+			explain(args),
+			"positional a
+command build
+command remote with 1 arg
+switch -v
+switch -v (unexpected value: 1)
+option --size = 1, 2
+option -o (missing value)
+help for build
+stdin
+3 positionals: b, c, d
+positional {} (unexpanded placeholder)
+2 args after --",
+		);
+	}
+}
@@ -0,0 +1,495 @@
+/*!
+# Argyle: JSON Sources.
+*/
+
+use crate::Argue;
+use std::{
+	error::Error,
+	ffi::OsString,
+	fmt,
+	io::{
+		self,
+		BufRead,
+	},
+	vec::IntoIter,
+};
+
+
+
+#[derive(Debug)]
+/// # NDJSON Error.
+///
+/// This is the error type for [`NdJson`]. Each variant holds the (one-based)
+/// number of the offending line.
+pub enum NdJsonError {
+	/// # Read Error.
+	///
+	/// The reader failed. This is the last thing [`NdJson`] will return.
+	Read(usize, io::Error),
+
+	/// # Invalid UTF-8.
+	Utf8(usize),
+
+	/// # Malformed Record.
+	///
+	/// The line is not a JSON string or `{"key": …, "value": …}` object.
+	Parse(usize),
+}
+
+impl Error for NdJsonError {
+	fn source(&self) -> Option<&(dyn Error + 'static)> {
+		if let Self::Read(_, e) = self { Some(e) }
+		else { None }
+	}
+}
+
+impl fmt::Display for NdJsonError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Self::Read(n, e) => write!(f, "Unable to read NDJSON line {n}: {e}"),
+			Self::Utf8(n) => write!(f, "NDJSON line {n} is not valid UTF-8."),
+			Self::Parse(n) => write!(f, "NDJSON line {n} is not a valid record."),
+		}
+	}
+}
+
+impl NdJsonError {
+	#[must_use]
+	/// # Line Number.
+	///
+	/// Return the (one-based) number of the offending line.
+	pub const fn line(&self) -> usize {
+		match self { Self::Read(n, _) | Self::Utf8(n) | Self::Parse(n) => *n }
+	}
+}
+
+
+
+/// # NDJSON Argument Source.
+///
+/// This iterator reads newline-delimited JSON records from a reader and
+/// converts them into raw arguments suitable for feeding into an
+/// [`Argue`](crate::Argue) instance.
+///
+/// Each non-empty line should contain one of the following:
+/// * A JSON string, like `"--help"`, passed through as-is;
+/// * A JSON object with a `"key"` and optional `"value"`, like `{"key": "--threads", "value": 4}`, passed through as `--threads=4`;
+///
+/// Values may be strings, numbers, booleans, or `null` (the same as no value
+/// at all).
+///
+/// Lines that cannot be parsed or aren't valid UTF-8 are returned as an
+/// [`NdJsonError`], and iteration continues with the next one. Read errors
+/// are returned too, but end the iteration.
+///
+/// ## Examples
+///
+/// ```
+/// use argyle::{Argue, Argument, KeyWord, NdJson, NdJsonError};
+/// use std::ffi::OsString;
+///
+/// let raw = br#"
+/// "--help"
+/// {"key": "--threads", "value": 4}
+/// "/foo/bar"
+/// "#;
+///
+/// let raw: Vec<OsString> = NdJson::new(&raw[..]).collect::<Result<_, _>>().unwrap();
+/// let mut args = Argue::from(raw.into_iter())
+///     .with_keywords([
+///         KeyWord::key("--help").unwrap(),
+///         KeyWord::key_with_value("--threads").unwrap(),
+///     ]);
+///
+/// assert_eq!(args.next(), Some(Argument::Key("--help")));
+/// assert_eq!(args.next(), Some(Argument::KeyWithValue("--threads", "4".to_owned())));
+/// assert_eq!(args.next(), Some(Argument::Other("/foo/bar".to_owned())));
+/// assert_eq!(args.next(), None);
+///
+/// // Bad lines are errors.
+/// let mut raw = NdJson::new(&b"\"--help\"\n--help\n"[..]);
+/// assert_eq!(raw.next().unwrap().unwrap(), "--help");
+/// assert!(matches!(raw.next(), Some(Err(NdJsonError::Parse(2)))));
+/// assert!(raw.next().is_none());
+/// ```
+pub struct NdJson<R: BufRead> {
+	/// # Reader.
+	reader: R,
+
+	/// # Line Buffer.
+	buf: Vec<u8>,
+
+	/// # Line Number.
+	///
+	/// The number of lines read so far.
+	line: usize,
+
+	/// # Done?
+	///
+	/// Set after a read error, since there's no telling what the reader
+	/// might do next.
+	done: bool,
+}
+
+impl<R: BufRead> NdJson<R> {
+	#[inline]
+	#[must_use]
+	/// # New.
+	///
+	/// Wrap the reader.
+	pub const fn new(reader: R) -> Self {
+		Self { reader, buf: Vec::new(), line: 0, done: false }
+	}
+}
+
+impl<R: BufRead> Iterator for NdJson<R> {
+	type Item = Result<OsString, NdJsonError>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		while ! self.done {
+			self.buf.truncate(0);
+			self.line += 1;
+			match self.reader.read_until(b'\n', &mut self.buf) {
+				Ok(0) => { self.done = true; },
+				Ok(_) => {
+					let Ok(line) = std::str::from_utf8(&self.buf) else {
+						return Some(Err(NdJsonError::Utf8(self.line)));
+					};
+
+					// Skip blank lines.
+					let line = ws(line).trim_end();
+					if line.is_empty() { continue; }
+
+					return Some(record(line).ok_or(NdJsonError::Parse(self.line)));
+				},
+				Err(e) => {
+					self.done = true;
+					return Some(Err(NdJsonError::Read(self.line, e)));
+				},
+			}
+		}
+
+		None
+	}
+}
+
+impl<R: BufRead> std::iter::FusedIterator for NdJson<R> {}
+
+
+
+impl Argue<IntoIter<OsString>> {
+	#[must_use]
+	/// # From JSON Array.
+	///
+	/// Create a new instance from a JSON array of arguments, like the
+	/// `["build", "-j", "4"]` style used by Docker's `CMD`, so stored
+	/// invocations can be parsed exactly like the real thing.
+	///
+	/// Entries may be strings, numbers, or booleans; the latter two are
+	/// passed through as they were written.
+	///
+	/// Returns `None` if the array is malformed or contains anything else,
+	/// like `null` or a nested structure.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use argyle::{Argue, Argument, KeyWord};
+	///
+	/// let mut args = Argue::from_json_array(r#"["build", "-j", 4]"#)
+	///     .unwrap()
+	///     .with_keywords([
+	///         KeyWord::command("build").unwrap(),
+	///         KeyWord::key_with_value("-j").unwrap(),
+	///     ]);
+	///
+	/// assert_eq!(args.next(), Some(Argument::Command("build")));
+	/// assert_eq!(args.next(), Some(Argument::KeyWithValue("-j", "4".to_owned())));
+	/// assert_eq!(args.next(), None);
+	///
+	/// // Garbage in, nothing out.
+	/// assert!(Argue::from_json_array(r#"["build", null]"#).is_none());
+	/// ```
+	pub fn from_json_array(src: &str) -> Option<Self> { array(src).map(Self::from) }
+}
+
+
+
+/// # Parse JSON Array.
+///
+/// Parse a JSON array of scalars, returning `None` if malformed.
+fn array(src: &str) -> Option<Vec<OsString>> {
+	let mut rest = ws(ws(src).strip_prefix('[')?);
+	let mut out = Vec::new();
+
+	// Empty?
+	if let Some(r) = rest.strip_prefix(']') { rest = r; }
+	else {
+		loop {
+			let (v, r) = scalar(rest)?;
+			out.push(OsString::from(v?));
+
+			// Another entry or the end?
+			let r = ws(r);
+			if let Some(r) = r.strip_prefix(',') { rest = ws(r); }
+			else {
+				rest = r.strip_prefix(']')?;
+				break;
+			}
+		}
+	}
+
+	// There shouldn't be anything after the array.
+	if ws(rest).is_empty() { Some(out) }
+	else { None }
+}
+
+/// # Parse NDJSON Record.
+///
+/// Parse a single (trimmed) line into an argument, or return `None` if
+/// malformed.
+fn record(line: &str) -> Option<OsString> {
+	// A plain string.
+	if line.starts_with('"') {
+		let (s, rest) = string(line)?;
+		return if ws(rest).is_empty() { Some(OsString::from(s)) } else { None };
+	}
+
+	// An object.
+	let mut rest = ws(line.strip_prefix('{')?);
+	let mut key = None;
+	let mut value = None;
+	loop {
+		let (field, r) = string(rest)?;
+		let (v, r) = scalar(ws(ws(r).strip_prefix(':')?))?;
+		match field.as_str() {
+			"key" => { key.replace(v?); },
+			"value" => { value = v; },
+			_ => return None,
+		}
+
+		// Another field or the end?
+		let r = ws(r);
+		if let Some(r) = r.strip_prefix(',') { rest = ws(r); }
+		else {
+			rest = ws(r.strip_prefix('}')?);
+			break;
+		}
+	}
+
+	// There shouldn't be anything after the object.
+	if ! rest.is_empty() { return None; }
+
+	let mut out = OsString::from(key?);
+	if let Some(v) = value {
+		out.push("=");
+		out.push(v);
+	}
+	Some(out)
+}
+
+/// # Parse Scalar.
+///
+/// Parse a JSON string, number, boolean, or null from the start of `src`,
+/// returning the value — `None` for null — along with the remainder.
+///
+/// Numbers and booleans are returned as they were written.
+fn scalar(src: &str) -> Option<(Option<String>, &str)> {
+	if src.starts_with('"') {
+		let (s, rest) = string(src)?;
+		return Some((Some(s), rest));
+	}
+
+	if let Some(rest) = src.strip_prefix("null") { return Some((None, rest)); }
+	for v in ["true", "false"] {
+		if let Some(rest) = src.strip_prefix(v) {
+			return Some((Some(v.to_owned()), rest));
+		}
+	}
+
+	// Numbers.
+	let len = src.bytes()
+		.take_while(|b| matches!(b, b'-' | b'+' | b'.' | b'e' | b'E' | b'0'..=b'9'))
+		.count();
+	let (num, rest) = src.split_at(len);
+	if num.is_empty() || num.parse::<f64>().is_err() { None }
+	else { Some((Some(num.to_owned()), rest)) }
+}
+
+/// # Parse String Literal.
+///
+/// Parse a JSON string from the start of `src`, returning the decoded value
+/// along with the remainder.
+fn string(src: &str) -> Option<(String, &str)> {
+	let src = src.strip_prefix('"')?;
+	let mut out = String::new();
+	let mut iter = src.char_indices();
+	while let Some((idx, c)) = iter.next() {
+		match c {
+			'"' => return Some((out, &src[idx + 1..])),
+			'\\' => match iter.next()?.1 {
+				'"' => out.push('"'),
+				'\\' => out.push('\\'),
+				'/' => out.push('/'),
+				'b' => out.push('\u{8}'),
+				'f' => out.push('\u{c}'),
+				'n' => out.push('\n'),
+				'r' => out.push('\r'),
+				't' => out.push('\t'),
+				'u' => {
+					let hi = hex4(&mut iter)?;
+					let c =
+						// Surrogates come in pairs.
+						if (0xD800..0xDC00).contains(&hi) {
+							if iter.next()?.1 != '\\' || iter.next()?.1 != 'u' { return None; }
+							let lo = hex4(&mut iter)?;
+							if ! (0xDC00..0xE000).contains(&lo) { return None; }
+							char::from_u32(0x10000 + ((hi - 0xD800) << 10) + (lo - 0xDC00))?
+						}
+						else { char::from_u32(hi)? };
+					out.push(c);
+				},
+				_ => return None,
+			},
+			// Control characters must be escaped.
+			'\0'..='\x1f' => return None,
+			c => out.push(c),
+		}
+	}
+
+	// The string was never closed.
+	None
+}
+
+/// # Parse Four Hex Digits.
+fn hex4(iter: &mut std::str::CharIndices) -> Option<u32> {
+	let mut out = 0;
+	for _ in 0..4 {
+		out = out * 16 + iter.next()?.1.to_digit(16)?;
+	}
+	Some(out)
+}
+
+/// # Trim Leading Whitespace.
+fn ws(src: &str) -> &str {
+	src.trim_start_matches([' ', '\t', '\n', '\r'])
+}
+
+
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	#[test]
+	fn t_string() {
+		for (raw, expected) in [
+			(r#""""#, Some("")),
+			(r#""hello""#, Some("hello")),
+			(r#""a\"b\\c\/d""#, Some("a\"b\\c/d")),
+			(r#""\n\t\r""#, Some("\n\t\r")),
+			(r#""Björk""#, Some("Björk")),
+			(r#""😀""#, Some("😀")),
+			(r#""\ud83d""#, None),
+			(r#""\ude00""#, None),
+			(r#""\x""#, None),
+			(r#""unclosed"#, None),
+			("\"\n\"", None),
+		] {
+			assert_eq!(
+				string(raw).map(|(s, _)| s).as_deref(),
+				expected,
+				"Failed to parse {raw}.",
+			);
+		}
+	}
+
+	#[test]
+	fn t_record() {
+		for (raw, expected) in [
+			(r#""--help""#, Some("--help")),
+			(r#"{"key": "--help"}"#, Some("--help")),
+			(r#"{"key": "--help", "value": null}"#, Some("--help")),
+			(r#"{"key":"-j","value":4}"#, Some("-j=4")),
+			(r#"{ "value" : "a b" , "key" : "--name" }"#, Some("--name=a b")),
+			(r#"{"key": "--on", "value": true}"#, Some("--on=true")),
+			(r#"{"key": "--n", "value": -1.5e3}"#, Some("--n=-1.5e3")),
+			(r#"{"value": "orphan"}"#, None),
+			(r#"{"key": null}"#, None),
+			(r#"{"key": "--help", "other": 1}"#, None),
+			(r#"{"key": "--help"} junk"#, None),
+			("{}", None),
+			(r#""--help" junk"#, None),
+			("--help", None),
+		] {
+			assert_eq!(
+				record(raw),
+				expected.map(OsString::from),
+				"Failed to parse {raw}.",
+			);
+		}
+	}
+
+	#[test]
+	fn t_array() {
+		for (raw, expected) in [
+			("[]", Some(Vec::new())),
+			(" [ ] ", Some(Vec::new())),
+			(r#"["build"]"#, Some(vec!["build"])),
+			(r#"[ "build" , "-j", 4, true ]"#, Some(vec!["build", "-j", "4", "true"])),
+			(r#"["a\"b", ""]"#, Some(vec!["a\"b", ""])),
+			(r#"["build",]"#, None),
+			(r#"["build" "-j"]"#, None),
+			(r#"["build", null]"#, None),
+			(r#"["build", ["-j"]]"#, None),
+			(r#"["build"] junk"#, None),
+			(r#"["build""#, None),
+			(r#""build""#, None),
+		] {
+			assert_eq!(
+				array(raw),
+				expected.map(|v| v.into_iter().map(OsString::from).collect()),
+				"Failed to parse {raw}.",
+			);
+		}
+	}
+
+	#[test]
+	fn t_ndjson() {
+		let raw = b"\"-h\"\n\n  {\"key\": \"--out\", \"value\": \"file.txt\"}  \r\nnot json\n\"\xff\"\n\"-v\"";
+		let parsed: Vec<Result<OsString, usize>> = NdJson::new(&raw[..])
+			.map(|r| r.map_err(|e| e.line()))
+			.collect();
+		assert_eq!(
+			parsed,
+			[
+				Ok(OsString::from("-h")),
+				Ok(OsString::from("--out=file.txt")),
+				Err(4),
+				Err(5),
+				Ok(OsString::from("-v")),
+			],
+		);
+
+		let mut iter = NdJson::new(&raw[..]).skip(2);
+		assert!(matches!(iter.next(), Some(Err(NdJsonError::Parse(4)))));
+		assert!(matches!(iter.next(), Some(Err(NdJsonError::Utf8(5)))));
+	}
+
+	#[test]
+	fn t_ndjson_read_error() {
+		/// # Broken Reader.
+		struct Broken;
+
+		impl io::Read for Broken {
+			fn read(&mut self, _buf: &mut [u8]) -> io::Result<usize> {
+				Err(io::Error::other("nope"))
+			}
+		}
+
+		let mut iter = NdJson::new(io::BufReader::new(Broken));
+		assert!(matches!(iter.next(), Some(Err(NdJsonError::Read(1, _)))));
+		assert!(iter.next().is_none());
+		assert!(iter.next().is_none());
+	}
+}
@@ -0,0 +1,303 @@
+/*!
+# Argyle: Byte Limits.
+*/
+
+use crate::{
+	Argue,
+	Argument,
+};
+use std::{
+	error::Error,
+	ffi::OsString,
+	fmt,
+};
+use super::FLAG_OVER_LIMIT;
+
+
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+/// # Limit Exceeded.
+///
+/// This is the error returned by [`Argue::try_next`] once the byte limit set
+/// by [`Argue::with_byte_limit`] has been hit.
+pub struct LimitExceeded;
+
+impl Error for LimitExceeded {}
+
+impl fmt::Display for LimitExceeded {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.write_str("The argument byte limit was exceeded.")
+	}
+}
+
+
+
+impl<I> Argue<I> {
+	#[must_use]
+	/// # With Byte Limit.
+	///
+	/// Cap the total number of (raw) bytes that will be pulled from the
+	/// source. This is mainly intended for servers parsing arguments received
+	/// from untrusted clients.
+	///
+	/// Arguments are measured _before_ any conversion or copying takes place.
+	/// The first one that would push the total over the limit is discarded,
+	/// along with everything after it, and the iterator behaves as if the
+	/// source had run dry. (This can cause a preceding key to be reported
+	/// as an [`Argument::MissingValue`].)
+	///
+	/// Use [`Argue::try_next`] instead of [`Iterator::next`] to tell the
+	/// difference between the natural end and a truncation.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use argyle::{Argue, Argument, LimitExceeded};
+	/// use std::ffi::OsString;
+	///
+	/// let mut args = Argue::from([
+	///     OsString::from("abc"),
+	///     OsString::from("def"),
+	///     OsString::from("ghi"),
+	/// ])
+	///     .with_byte_limit(8);
+	///
+	/// assert_eq!(args.try_next(), Ok(Some(Argument::Other("abc".to_owned()))));
+	/// assert_eq!(args.try_next(), Ok(Some(Argument::Other("def".to_owned()))));
+	/// assert_eq!(args.try_next(), Err(LimitExceeded));
+	/// ```
+	pub const fn with_byte_limit(mut self, max: usize) -> Self {
+		self.budget = max;
+		self.byte_limit = max;
+		self
+	}
+
+	#[must_use]
+	/// # With Value Limit.
+	///
+	/// Cap the length of any single value, in bytes. Values exceeding the
+	/// limit — glued or separate — are returned as
+	/// [`Argument::ValueTooLong`] instead of whatever they would otherwise
+	/// have been.
+	///
+	/// See [`Argue::with_value_limits`] to set limits for specific keys.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use argyle::{Argue, Argument, KeyWord};
+	/// use std::ffi::OsString;
+	///
+	/// let mut args = Argue::from([
+	///     OsString::from("-kabc"),
+	///     OsString::from("-kabcdef"),
+	/// ])
+	///     .with_keywords([KeyWord::key_with_value("-k").unwrap()])
+	///     .with_value_limit(4);
+	///
+	/// assert_eq!(args.next(), Some(Argument::KeyWithValue("-k", "abc".to_owned())));
+	/// assert_eq!(args.next(), Some(Argument::ValueTooLong("-k", 6)));
+	/// assert_eq!(args.next(), None);
+	/// ```
+	pub const fn with_value_limit(mut self, max: usize) -> Self {
+		self.value_limit = max;
+		self
+	}
+
+	#[must_use]
+	/// # With Per-Key Value Limits.
+	///
+	/// Same as [`Argue::with_value_limit`], but for specific keys. These take
+	/// priority over the global limit, whether larger or smaller.
+	pub fn with_value_limits<I2>(mut self, limits: I2) -> Self
+	where I2: IntoIterator<Item=(&'static str, usize)> {
+		self.value_limits.extend(limits);
+		self
+	}
+
+	/// # Check Value Length.
+	///
+	/// Return an [`Argument::ValueTooLong`] in place of `arg` if its value
+	/// exceeds the limit, otherwise `arg` itself.
+	pub(super) fn check_value_len(&self, arg: Argument) -> Argument {
+		if self.value_limit == usize::MAX && self.value_limits.is_empty() { return arg; }
+
+		let (k, len) = match &arg {
+			Argument::KeyWithValue(k, v) |
+			Argument::UnexpectedValue(k, v) |
+			Argument::Placeholder { key: Some(k), value: v } => (*k, v.len()),
+			Argument::KeyWithOsValue(k, v) |
+			Argument::KeyWithInvalidValue(k, v) => (*k, v.len()),
+			Argument::KeyWithValues(k, v) => (*k, v.iter().map(String::len).max().unwrap_or(0)),
+			_ => return arg,
+		};
+
+		let max = self.value_limits.get(k).copied().unwrap_or(self.value_limit);
+		if max < len { Argument::ValueTooLong(k, len) }
+		else { arg }
+	}
+
+	/// # Spend Budget.
+	///
+	/// Deduct `len` bytes from the budget, returning `false` — and flipping
+	/// the over-limit switch — if there isn't enough left.
+	pub(super) fn spend(&mut self, len: usize) -> bool {
+		if FLAG_OVER_LIMIT == self.flags & FLAG_OVER_LIMIT { return false; }
+		if let Some(left) = self.budget.checked_sub(len) {
+			self.budget = left;
+			true
+		}
+		else {
+			self.flags |= FLAG_OVER_LIMIT;
+			false
+		}
+	}
+}
+
+impl<I: Iterator<Item=OsString>> Argue<I> {
+	/// # Checked Next.
+	///
+	/// Same as [`Iterator::next`], but returns an error if the byte limit set
+	/// by [`Argue::with_byte_limit`] has been exceeded. Any partial result
+	/// from the offending call is discarded.
+	///
+	/// ## Errors
+	///
+	/// This will return an error if the byte limit has been exceeded.
+	pub fn try_next(&mut self) -> Result<Option<Argument>, LimitExceeded> {
+		let next = self.next();
+		if FLAG_OVER_LIMIT == self.flags & FLAG_OVER_LIMIT { Err(LimitExceeded) }
+		else { Ok(next) }
+	}
+}
+
+
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::KeyWord;
+
+	#[test]
+	fn t_value_limit() {
+		let cli = [
+			OsString::from("-kabcdef"),
+			OsString::from("-k"),
+			OsString::from("abc"),
+			OsString::from("--long=abcdef"),
+			OsString::from("--pair"),
+			OsString::from("ab"),
+			OsString::from("abcdef"),
+			OsString::from("-v=abcdef"),
+			OsString::from("abcdefghi"),
+		];
+		let keys = [
+			KeyWord::Key("-v"),
+			KeyWord::KeyWithValue("-k"),
+			KeyWord::KeyWithValue("--long"),
+			KeyWord::KeyWithValue("--pair"),
+		];
+
+		// No limits.
+		let mut args = Argue::from(cli.clone().into_iter())
+			.with_keywords(keys)
+			.nargs("--pair", 2);
+		assert!(args.all(|a| ! matches!(a, Argument::ValueTooLong(_, _))));
+
+		// Global and per-key limits.
+		let mut args = Argue::from(cli.into_iter())
+			.with_keywords(keys)
+			.nargs("--pair", 2)
+			.with_value_limit(4)
+			.with_value_limits([("--long", 10)])
+			.with_diagnostics();
+		assert_eq!(args.next(), Some(Argument::ValueTooLong("-k", 6)));
+		assert_eq!(args.next(), Some(Argument::KeyWithValue("-k", "abc".to_owned())));
+		assert_eq!(args.next(), Some(Argument::KeyWithValue("--long", "abcdef".to_owned())));
+		assert_eq!(args.next(), Some(Argument::ValueTooLong("--pair", 6)));
+		assert_eq!(args.next(), Some(Argument::ValueTooLong("-v", 6)));
+
+		// Positionals aren't values.
+		assert_eq!(args.next(), Some(Argument::Other("abcdefghi".to_owned())));
+		assert_eq!(args.next(), None);
+
+		assert_eq!(args.finish().len(), 3);
+	}
+
+	#[test]
+	fn t_byte_limit() {
+		let cli = [
+			OsString::from("-o"),
+			OsString::from("abcdef"),
+			OsString::from("-o"),
+			OsString::from("ghijkl"),
+		];
+
+		// No limit.
+		let mut args = Argue::from(cli.clone().into_iter())
+			.with_keywords([KeyWord::KeyWithValue("-o")]);
+		assert_eq!(args.try_next(), Ok(Some(Argument::KeyWithValue("-o", "abcdef".to_owned()))));
+		assert_eq!(args.try_next(), Ok(Some(Argument::KeyWithValue("-o", "ghijkl".to_owned()))));
+		assert_eq!(args.try_next(), Ok(None));
+
+		// Exact fit.
+		let mut args = Argue::from(cli.clone().into_iter())
+			.with_keywords([KeyWord::KeyWithValue("-o")])
+			.with_byte_limit(16);
+		assert_eq!(args.try_next(), Ok(Some(Argument::KeyWithValue("-o", "abcdef".to_owned()))));
+		assert_eq!(args.try_next(), Ok(Some(Argument::KeyWithValue("-o", "ghijkl".to_owned()))));
+		assert_eq!(args.try_next(), Ok(None));
+
+		// One byte short.
+		let mut args = Argue::from(cli.clone().into_iter())
+			.with_keywords([KeyWord::KeyWithValue("-o")])
+			.with_byte_limit(15);
+		assert_eq!(args.try_next(), Ok(Some(Argument::KeyWithValue("-o", "abcdef".to_owned()))));
+		assert_eq!(args.try_next(), Err(LimitExceeded));
+		assert_eq!(args.try_next(), Err(LimitExceeded));
+
+		// Plain iteration just stops.
+		let mut args = Argue::from(cli.into_iter())
+			.with_keywords([KeyWord::KeyWithValue("-o")])
+			.with_byte_limit(15);
+		assert_eq!(args.next(), Some(Argument::KeyWithValue("-o", "abcdef".to_owned())));
+		assert_eq!(args.next(), Some(Argument::MissingValue("-o")));
+		assert_eq!(args.next(), None);
+	}
+
+	#[test]
+	fn t_byte_limit_child() {
+		let cli = [
+			OsString::from("-v"),
+			OsString::from("--"),
+			OsString::from("0123456789"),
+			OsString::from("abcdefghij"),
+		];
+
+		// The end args count against the parent…
+		let mut args = Argue::from(cli.into_iter())
+			.with_keywords([KeyWord::Key("-v")])
+			.with_byte_limit(30);
+		assert_eq!(args.try_next(), Ok(Some(Argument::Key("-v"))));
+		let Ok(Some(Argument::End(rest))) = args.try_next() else { panic!("Missing end."); };
+		assert_eq!(rest.len(), 2);
+
+		// …but the child gets a fresh budget of its own.
+		let mut child = args.child(rest);
+		assert_eq!(child.try_next(), Ok(Some(Argument::Other("0123456789".to_owned()))));
+		assert_eq!(child.try_next(), Ok(Some(Argument::Other("abcdefghij".to_owned()))));
+		assert_eq!(child.try_next(), Ok(None));
+
+		// Even if the parent ran out.
+		let mut args = Argue::from([OsString::from("0123456789")].into_iter())
+			.with_byte_limit(5);
+		assert_eq!(args.try_next(), Err(LimitExceeded));
+		let mut child = args.child([OsString::from("abc")]);
+		assert_eq!(child.try_next(), Ok(Some(Argument::Other("abc".to_owned()))));
+		assert_eq!(child.try_next(), Ok(None));
+
+		// The limit still applies, though.
+		let mut child = args.child([OsString::from("abcdef")]);
+		assert_eq!(child.try_next(), Err(LimitExceeded));
+	}
+}
@@ -0,0 +1,246 @@
+/*!
+# Argyle: Matches.
+*/
+
+use crate::{
+	Argue,
+	ParseReport,
+	UsageError,
+};
+use std::{
+	ffi::OsString,
+	str::FromStr,
+};
+
+
+
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+/// # Matches.
+///
+/// This is a lookup-style view of the parsed arguments, as returned by
+/// [`Argue::to_matches`], with accessors loosely mirroring those of clap's
+/// `ArgMatches` to ease migration.
+///
+/// Unlike clap, IDs are simply the keys themselves — `"--verbose"` rather
+/// than `"verbose"` — and values are parsed on demand via [`FromStr`].
+///
+/// Code that needs to work with either crate can hide the differences
+/// behind a small trait of its own, implemented for both types.
+///
+/// The full [`ParseReport`] remains available via [`Matches::report`] for
+/// anything not covered here.
+pub struct Matches(ParseReport);
+
+impl From<ParseReport> for Matches {
+	#[inline]
+	fn from(src: ParseReport) -> Self { Self(src) }
+}
+
+impl From<Matches> for ParseReport {
+	#[inline]
+	fn from(src: Matches) -> Self { src.0 }
+}
+
+impl Matches {
+	#[must_use]
+	/// # Contains ID?
+	///
+	/// Returns `true` if the key or command was present, with or without
+	/// values.
+	pub fn contains_id(&self, id: &str) -> bool {
+		self.0.keys_seen.contains(id) ||
+		self.0.values.contains_key(id) ||
+		self.0.os_values.contains_key(id)
+	}
+
+	#[must_use]
+	/// # Get Flag.
+	///
+	/// Returns `true` if the boolean key — or command — was present.
+	pub fn get_flag(&self, id: &str) -> bool { self.0.keys_seen.contains(id) }
+
+	#[must_use]
+	/// # Get One.
+	///
+	/// Parse and return the value for the key, if any. If the key was passed
+	/// more than once, the last value wins.
+	///
+	/// Values that fail to parse are treated as missing; use
+	/// [`Matches::try_get_one`] to tell the difference.
+	pub fn get_one<T: FromStr>(&self, id: &str) -> Option<T> {
+		self.try_get_one(id).ok().flatten()
+	}
+
+	/// # Try Get One.
+	///
+	/// Same as [`Matches::get_one`], but invalid values are returned as an
+	/// error.
+	///
+	/// ## Errors
+	///
+	/// Returns an error if the value cannot be parsed as a `T`.
+	pub fn try_get_one<T: FromStr>(&self, id: &str) -> Result<Option<T>, UsageError> {
+		self.0.values.get(id)
+			.and_then(|v| v.last())
+			.map(|v| parse(id, v))
+			.transpose()
+	}
+
+	#[must_use]
+	/// # Get Many.
+	///
+	/// Parse and return all values for the key, in order, if any.
+	///
+	/// If any of the values fail to parse, `None` is returned; use
+	/// [`Matches::try_get_many`] to tell the difference.
+	pub fn get_many<T: FromStr>(&self, id: &str) -> Option<Vec<T>> {
+		self.try_get_many(id).ok().flatten()
+	}
+
+	/// # Try Get Many.
+	///
+	/// Same as [`Matches::get_many`], but invalid values are returned as an
+	/// error.
+	///
+	/// ## Errors
+	///
+	/// Returns an error if any of the values cannot be parsed as a `T`.
+	pub fn try_get_many<T: FromStr>(&self, id: &str) -> Result<Option<Vec<T>>, UsageError> {
+		self.0.values.get(id)
+			.map(|v| v.iter().map(|v| parse(id, v)).collect())
+			.transpose()
+	}
+
+	#[must_use]
+	/// # Get Raw.
+	///
+	/// Return the raw values for a key registered with
+	/// [`Argue::with_raw_values`], if any.
+	pub fn get_raw(&self, id: &str) -> Option<&[OsString]> {
+		self.0.os_values.get(id).map(Vec::as_slice)
+	}
+
+	#[must_use]
+	/// # Positionals.
+	///
+	/// Return the [`Argument::Other`](crate::Argument::Other) values, in order.
+	pub fn positionals(&self) -> &[String] { &self.0.others }
+
+	#[must_use]
+	/// # Parse Report.
+	///
+	/// Return the underlying [`ParseReport`].
+	pub const fn report(&self) -> &ParseReport { &self.0 }
+}
+
+
+
+impl<I: Iterator<Item=OsString>> Argue<I> {
+	#[must_use]
+	/// # To Matches.
+	///
+	/// Drive the iterator to completion, collecting the results into a
+	/// clap-like [`Matches`] lookup.
+	///
+	/// The instance is only borrowed, so its diagnostics, warnings, etc.,
+	/// can still be checked afterwards.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use argyle::{Argue, KeyWord};
+	/// use std::ffi::OsString;
+	///
+	/// let mut args = Argue::from([
+	///     OsString::from("-v"),
+	///     OsString::from("--threads=4"),
+	///     OsString::from("--exclude=a"),
+	///     OsString::from("--exclude=b"),
+	///     OsString::from("file.txt"),
+	/// ])
+	///     .with_keywords([
+	///         KeyWord::key("-v").unwrap(),
+	///         KeyWord::key_with_value("--threads").unwrap(),
+	///         KeyWord::key_with_value("--exclude").unwrap(),
+	///     ]);
+	///
+	/// let matches = args.to_matches();
+	/// assert!(matches.get_flag("-v"));
+	/// assert_eq!(matches.get_one::<u8>("--threads"), Some(4));
+	/// assert_eq!(
+	///     matches.get_many::<String>("--exclude"),
+	///     Some(vec!["a".to_owned(), "b".to_owned()]),
+	/// );
+	/// assert_eq!(matches.positionals(), ["file.txt"]);
+	/// ```
+	pub fn to_matches(&mut self) -> Matches { Matches(self.by_ref().collect()) }
+}
+
+
+
+/// # Parse Value.
+///
+/// Parse a value, or return an error naming the key.
+fn parse<T: FromStr>(id: &str, v: &str) -> Result<T, UsageError> {
+	v.parse().map_err(|_| UsageError::from(format!("Invalid value for {id}: {v}")))
+}
+
+
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::KeyWord;
+
+	#[test]
+	fn t_matches() {
+		let mut args = Argue::from([
+			OsString::from("build"),
+			OsString::from("-j"),
+			OsString::from("2"),
+			OsString::from("-j8"),
+			OsString::from("-n=x"),
+			OsString::from("--raw=a"),
+			OsString::from("file.txt"),
+		].into_iter())
+			.with_keywords([
+				KeyWord::Command("build"),
+				KeyWord::Key("-v"),
+				KeyWord::KeyWithValue("-j"),
+				KeyWord::KeyWithValue("-n"),
+				KeyWord::KeyWithValue("--raw"),
+			])
+			.with_raw_values(["--raw"])
+			.with_diagnostics();
+
+		let matches = args.to_matches();
+		assert!(args.finish().is_empty());
+
+		assert!(matches.get_flag("build"));
+		assert!(! matches.get_flag("-v"));
+		assert!(! matches.get_flag("-j"));
+		assert!(matches.contains_id("-j"));
+		assert!(matches.contains_id("--raw"));
+		assert!(! matches.contains_id("-v"));
+
+		// Last wins for one; all for many.
+		assert_eq!(matches.get_one::<u8>("-j"), Some(8));
+		assert_eq!(matches.get_many::<u8>("-j"), Some(vec![2, 8]));
+		assert_eq!(matches.get_one::<u8>("-v"), None);
+		assert_eq!(matches.try_get_one::<u8>("-v"), Ok(None));
+
+		// Bad values.
+		assert_eq!(matches.get_one::<u8>("-n"), None);
+		assert_eq!(
+			matches.try_get_one::<u8>("-n").unwrap_err().as_str(),
+			"Invalid value for -n: x",
+		);
+		assert!(matches.try_get_many::<u8>("-n").is_err());
+		assert_eq!(matches.get_one::<String>("-n"), Some("x".to_owned()));
+
+		// Raw values and positionals.
+		assert_eq!(matches.get_raw("--raw"), Some(&[OsString::from("a")][..]));
+		assert_eq!(matches.positionals(), ["file.txt"]);
+		assert!(matches.report().errors.is_empty());
+	}
+}
@@ -2,21 +2,261 @@
 # Argyle: Streaming Argument Iterator.
 */
 
-mod key;
+mod apply;
+mod checkpoint;
+mod color;
+mod complete;
+mod diagnostic;
+mod explain;
+mod json;
+mod limit;
+mod matches;
+mod payload;
+mod report;
+mod roundtrip;
+mod run;
+mod selftest;
+#[cfg(feature = "stats")] mod stats;
+mod tokens;
+mod trace;
+mod warning;
+mod whitespace;
 
-pub use key::{
+use checkpoint::Checkpoint;
+use payload::Payload;
+use tokens::{
+	lex,
+	Lexeme,
+};
+use crate::{
+	CompletionHint,
 	KeyWord,
-	KeyWordsBuilder,
+	KeyWordTable,
+};
+pub use apply::{
+	ApplyArgument,
+	UsageError,
+};
+pub use color::ColorChoice;
+pub use complete::{
+	fish_completions,
+	zsh_completions,
+};
+pub use diagnostic::Diagnostic;
+pub use explain::explain;
+pub use json::{
+	NdJson,
+	NdJsonError,
+};
+pub use limit::LimitExceeded;
+pub use matches::Matches;
+pub use report::ParseReport;
+pub use roundtrip::roundtrip;
+pub use selftest::selftest;
+pub use run::{
+	MainResult,
+	run,
+	RunConfig,
+};
+#[cfg(feature = "stats")] pub use stats::ArgueStats;
+pub use trace::Trace;
+pub use warning::Warning;
+pub use whitespace::WhitespacePolicy;
+pub use tokens::{
+	Token,
+	Tokens,
 };
 use std::{
-	collections::BTreeSet,
+	collections::{
+		BTreeMap,
+		BTreeSet,
+		VecDeque,
+	},
 	env::ArgsOs,
-	ffi::OsString,
-	iter::Skip,
+	ffi::{
+		OsStr,
+		OsString,
+	},
+	iter::{
+		Map,
+		Skip,
+	},
+	path::Path,
 };
 
 
 
+/// # Flag: POSIX Mode.
+///
+/// Stop parsing options at the first positional argument.
+const FLAG_POSIX: u32 = 0b0000_0000_0000_0000_0000_0000_0000_0001;
+
+/// # Flag: Operands Only.
+///
+/// This is set once a positional argument is encountered in POSIX mode; all
+/// subsequent arguments are yielded as-are.
+const FLAG_OPERANDS: u32 = 0b0000_0000_0000_0000_0000_0000_0000_0010;
+
+/// # Flag: Literal Separators.
+///
+/// Treat `--` like any other argument.
+const FLAG_LITERAL_SEP: u32 = 0b0000_0000_0000_0000_0000_0000_0000_0100;
+
+/// # Flag: Require Equals.
+///
+/// Long keys must use `--key=val` syntax to receive values.
+const FLAG_REQUIRE_EQ: u32 = 0b0000_0000_0000_0000_0000_0000_0000_1000;
+
+/// # Flag: Stdin.
+///
+/// Return a lone `-` as [`Argument::Stdin`].
+const FLAG_STDIN: u32 = 0b0000_0000_0000_0000_0000_0000_0001_0000;
+
+/// # Flag: Strict Values.
+///
+/// Don't consume key-like arguments as values.
+const FLAG_STRICT_VALUES: u32 = 0b0000_0000_0000_0000_0000_0000_0010_0000;
+
+/// # Flag: Standard Help.
+///
+/// Return `help`, `-h`, and `--help` as [`Argument::Help`].
+const FLAG_HELP: u32 = 0b0000_0000_0000_0000_0000_0000_0100_0000;
+
+/// # Flag: Diagnostics.
+///
+/// Collect parse problems for [`Argue::finish`].
+const FLAG_DIAGNOSTICS: u32 = 0b0000_0000_0000_0000_0000_0000_1000_0000;
+
+/// # Flag: Placeholders.
+///
+/// Flag unexpanded template placeholders in values and positionals.
+const FLAG_PLACEHOLDERS: u32 = 0b0000_0000_0000_0000_0000_0001_0000_0000;
+
+/// # Flag: Empty End.
+///
+/// Return a trailing `--` as an empty [`Argument::End`].
+const FLAG_EMPTY_END: u32 = 0b0000_0000_0000_0000_0000_0010_0000_0000;
+
+/// # Flag: Over Limit.
+///
+/// This is set once the byte limit has been exceeded; no further arguments
+/// are pulled from the source.
+const FLAG_OVER_LIMIT: u32 = 0b0000_0000_0000_0000_0000_0100_0000_0000;
+
+/// # Flag: Color.
+///
+/// Handle `--color` and `--no-color` internally.
+const FLAG_COLOR: u32 = 0b0000_0000_0000_0000_0000_1000_0000_0000;
+
+/// # Flag: Command Payloads.
+///
+/// Have commands capture the arguments following them.
+const FLAG_CMD_PAYLOAD: u32 = 0b0000_0000_0000_0000_0001_0000_0000_0000;
+
+/// # Flag: Warnings.
+///
+/// Collect non-fatal issues.
+const FLAG_WARNINGS: u32 = 0b0000_0000_0000_0000_0010_0000_0000_0000;
+
+/// # Flag: Leading Commands.
+///
+/// Only recognize commands ahead of any positional arguments.
+const FLAG_LEADING_CMD: u32 = 0b0000_0000_0000_0000_0100_0000_0000_0000;
+
+/// # Flag: Seen Positional.
+///
+/// This is set once a command or positional argument is encountered.
+const FLAG_POSITIONAL: u32 = 0b0000_0000_0000_0000_1000_0000_0000_0000;
+
+/// # Flag: Classify End.
+///
+/// Keep parsing normally after an end-of-command separator.
+const FLAG_CLASSIFY_END: u32 = 0b0000_0000_0000_0001_0000_0000_0000_0000;
+
+/// # Flag: Strict End.
+///
+/// Always pass everything after an end-of-command separator through as-is.
+const FLAG_STRICT_END: u32 = 0b0000_0000_0000_0010_0000_0000_0000_0000;
+
+/// # Flag: First Commands.
+///
+/// Only recognize commands in the first position (of their level).
+const FLAG_FIRST_CMD: u32 = 0b0000_0000_0000_0100_0000_0000_0000_0000;
+
+/// # Flag: Commands Before Keys.
+///
+/// Only recognize commands ahead of any keys.
+const FLAG_KEYLESS_CMD: u32 = 0b0000_0000_0000_1000_0000_0000_0000_0000;
+
+/// # Flag: Seen Argument.
+///
+/// This is set once anything other than a command is encountered, and
+/// cleared by each command.
+const FLAG_SEEN_ARG: u32 = 0b0000_0000_0001_0000_0000_0000_0000_0000;
+
+/// # Flag: Seen Key.
+///
+/// This is set once a key is encountered.
+const FLAG_SEEN_KEY: u32 = 0b0000_0000_0010_0000_0000_0000_0000_0000;
+
+/// # Flag: Numeric Positionals.
+///
+/// Treat negative numbers as positionals rather than keys.
+const FLAG_NUMERIC: u32 = 0b0000_0000_0100_0000_0000_0000_0000_0000;
+
+/// # Flag: Pairs.
+///
+/// The source is structured key/value pairs; values are never pulled from
+/// the next argument.
+const FLAG_PAIRS: u32 = 0b0000_0000_1000_0000_0000_0000_0000_0000;
+
+/// # Flag: Trim Whitespace.
+///
+/// See [`WhitespacePolicy::Trim`].
+const FLAG_WS_TRIM: u32 = 0b0000_0001_0000_0000_0000_0000_0000_0000;
+
+/// # Flag: Skip Whitespace.
+///
+/// See [`WhitespacePolicy::Skip`].
+const FLAG_WS_SKIP: u32 = 0b0000_0010_0000_0000_0000_0000_0000_0000;
+
+/// # Flag: Deferred End.
+///
+/// Leave everything after an end-of-command separator in the source for
+/// [`Argue::end_into`].
+const FLAG_DEFER_END: u32 = 0b0000_0100_0000_0000_0000_0000_0000_0000;
+
+/// # Flag: In End.
+///
+/// This is set once a deferred end-of-command separator is encountered.
+const FLAG_IN_END: u32 = 0b0000_1000_0000_0000_0000_0000_0000_0000;
+
+/// # Flag: Source Exhausted.
+///
+/// This is set once the source iterator returns `None`, so it is never
+/// called again. (Not all iterators are fused.)
+const FLAG_SOURCE_DONE: u32 = 0b0001_0000_0000_0000_0000_0000_0000_0000;
+
+/// # Flags: Position.
+///
+/// The flags tracking what has been seen so far, reset for each new batch
+/// of arguments.
+const FLAGS_POSITION: u32 = FLAG_OPERANDS | FLAG_POSITIONAL | FLAG_SEEN_ARG | FLAG_SEEN_KEY | FLAG_IN_END;
+
+
+
+/// # Key Callback.
+///
+/// See [`Argue::on_key`].
+type KeyCallback = fn(Option<&str>) -> Vec<KeyWord>;
+
+/// # Environment Source.
+///
+/// See [`Argue::with_env_source`].
+type EnvSource = fn(&str) -> Option<OsString>;
+
+
+
 /// # Alias for Env Args.
 ///
 /// This is the return type for [`args`]. It is kinda clunky so downstream
@@ -47,6 +287,12 @@ pub type ArgueEnv = Argue<Skip<ArgsOs>>;
 /// instead collected and returned as-are in case you want to do anything with
 /// them. See [`Argument::End`] for more details.
 ///
+/// Each call to [`Iterator::next`] consumes as many raw arguments as it
+/// takes to produce one complete [`Argument`] — a key and its value(s)
+/// always travel together — so standard adapters like `skip`, `nth`,
+/// `take_while`, and `peekable` operate on whole arguments and can never
+/// split a pair apart.
+///
 /// ## Examples
 ///
 /// ```
@@ -85,6 +331,159 @@ pub struct Argue<I> {
 
 	/// # Keywords to Look For.
 	keys: BTreeSet<KeyWord>,
+
+	/// # Static Keyword Table.
+	///
+	/// See [`Argue::with_keyword_table`].
+	table: Option<&'static KeyWordTable>,
+
+	/// # Fallback Classifier.
+	fallback: Option<fn(&str) -> Option<Argument>>,
+
+	/// # Argument Transformations.
+	///
+	/// See [`Argue::map_arguments`].
+	maps: Vec<fn(Argument) -> Argument>,
+
+	/// # Flags.
+	flags: u32,
+
+	/// # Pre-Parsed Arguments.
+	///
+	/// These are returned before anything new is pulled from `iter`.
+	queue: VecDeque<Argument>,
+
+	/// # Namespace Prefixes.
+	namespaces: BTreeSet<&'static str>,
+
+	/// # Dash-Friendly Keys.
+	///
+	/// Keys exempt from [`Argue::with_strict_values`].
+	dash_values: BTreeSet<&'static str>,
+
+	/// # Raw Values.
+	///
+	/// See [`Argue::with_raw_values`].
+	raw_values: BTreeSet<&'static str>,
+
+	/// # Verbatim Values.
+	///
+	/// See [`Argue::with_verbatim_values`].
+	verbatim: BTreeSet<&'static str>,
+
+	/// # Diagnostics.
+	///
+	/// Problems found during parsing, if [`Argue::with_diagnostics`] is in
+	/// effect.
+	diagnostics: Vec<Diagnostic>,
+
+	/// # Warnings.
+	///
+	/// Non-fatal issues found during parsing, if [`Argue::with_warnings`] is
+	/// in effect.
+	warnings: Vec<Warning>,
+
+	/// # Deprecated Keys.
+	///
+	/// See [`Argue::with_deprecated_keys`].
+	deprecated: BTreeSet<&'static str>,
+
+	/// # Appended Arguments.
+	///
+	/// Raw arguments added via [`Extend`], to be parsed once `iter` runs dry.
+	appended: VecDeque<OsString>,
+
+	/// # Seen Switches.
+	///
+	/// Boolean keys encountered so far, for duplicate detection.
+	seen: BTreeSet<&'static str>,
+
+	/// # Pending Arguments.
+	///
+	/// Raw arguments to parse before pulling anything new from `iter`.
+	pending: VecDeque<OsString>,
+
+	/// # Abort Hooks.
+	///
+	/// Handlers to call as soon as a given key is found.
+	aborts: BTreeMap<&'static str, fn(&'static str)>,
+
+	/// # Key Callbacks.
+	///
+	/// Handlers for keys that should be dealt with inline.
+	callbacks: BTreeMap<&'static str, KeyCallback>,
+
+	/// # Keyword Payloads.
+	///
+	/// See [`Argue::with_keyword_map`].
+	payloads: BTreeMap<&'static str, Payload>,
+
+	/// # Subcommand Scopes.
+	///
+	/// Keywords that only apply once a given command has been found.
+	scopes: BTreeMap<&'static str, Vec<KeyWord>>,
+
+	/// # Command Path.
+	///
+	/// The commands found so far, in order.
+	path: Vec<&'static str>,
+
+	/// # Byte Budget.
+	///
+	/// The number of raw bytes left to pull before giving up. See
+	/// [`Argue::with_byte_limit`].
+	budget: usize,
+
+	/// # Byte Limit.
+	///
+	/// The budget as originally configured, for children. See
+	/// [`Argue::with_byte_limit`].
+	byte_limit: usize,
+
+	/// # Value Length Limit.
+	///
+	/// See [`Argue::with_value_limit`].
+	value_limit: usize,
+
+	/// # Per-Key Value Length Limits.
+	///
+	/// See [`Argue::with_value_limits`].
+	value_limits: BTreeMap<&'static str, usize>,
+
+	/// # Completion Hints.
+	///
+	/// See [`Argue::with_hints`].
+	hints: BTreeMap<&'static str, CompletionHint>,
+
+	/// # Value Counts.
+	///
+	/// The minimum number of values for multi-value keys, and whether or not
+	/// they can take more.
+	arity: BTreeMap<&'static str, (usize, bool)>,
+
+	/// # Color Choice.
+	///
+	/// See [`Argue::with_color_flag`].
+	color: ColorChoice,
+
+	/// # Tracer.
+	///
+	/// See [`Argue::trace`].
+	tracer: Option<fn(&Trace<'_>)>,
+
+	/// # Environment Source.
+	///
+	/// See [`Argue::with_env_source`].
+	env: EnvSource,
+
+	/// # Checkpoint.
+	///
+	/// See [`Argue::checkpoint`].
+	checkpoint: Option<Box<Checkpoint>>,
+
+	#[cfg(feature = "stats")]
+	/// # Running Totals.
+	stats: ArgueStats,
 }
 
 impl<I: IntoIterator<Item=OsString>> From<I> for Argue<I::IntoIter> {
@@ -93,10 +492,142 @@ impl<I: IntoIterator<Item=OsString>> From<I> for Argue<I::IntoIter> {
 		Self {
 			iter: src.into_iter(),
 			keys: BTreeSet::new(),
+			table: None,
+			fallback: None,
+			maps: Vec::new(),
+			flags: 0,
+			queue: VecDeque::new(),
+			namespaces: BTreeSet::new(),
+			dash_values: BTreeSet::new(),
+			raw_values: BTreeSet::new(),
+			verbatim: BTreeSet::new(),
+			diagnostics: Vec::new(),
+			warnings: Vec::new(),
+			deprecated: BTreeSet::new(),
+			seen: BTreeSet::new(),
+			appended: VecDeque::new(),
+			pending: VecDeque::new(),
+			aborts: BTreeMap::new(),
+			callbacks: BTreeMap::new(),
+			payloads: BTreeMap::new(),
+			scopes: BTreeMap::new(),
+			path: Vec::new(),
+			budget: usize::MAX,
+			byte_limit: usize::MAX,
+			value_limit: usize::MAX,
+			value_limits: BTreeMap::new(),
+			hints: BTreeMap::new(),
+			arity: BTreeMap::new(),
+			color: ColorChoice::Auto,
+			tracer: None,
+			env: env_var,
+			checkpoint: None,
+			#[cfg(feature = "stats")]
+			stats: ArgueStats::default(),
 		}
 	}
 }
 
+impl<I, S> Argue<Map<I, fn(S) -> OsString>>
+where I: Iterator<Item=S>, S: Into<OsString> {
+	#[inline]
+	#[must_use]
+	/// # From Strings.
+	///
+	/// [`Argue::from`] requires `OsString` items. This is a convenience
+	/// method for sources yielding `String`, `&str`, or anything else that
+	/// converts into an `OsString`.
+	///
+	/// (Owned `String`s are converted in place, so this is no more expensive
+	/// than using `OsString`s in the first place.)
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use argyle::{Argue, Argument, KeyWord};
+	///
+	/// let mut args = Argue::from_strings(["-v", "file.txt"])
+	///     .with_keywords([KeyWord::key("-v").unwrap()]);
+	///
+	/// assert_eq!(args.next(), Some(Argument::Key("-v")));
+	/// assert_eq!(args.next(), Some(Argument::Other("file.txt".to_owned())));
+	/// assert_eq!(args.next(), None);
+	///
+	/// // Owned strings work too.
+	/// let raw: Vec<String> = vec!["-v".to_owned()];
+	/// let mut args = Argue::from_strings(raw)
+	///     .with_keywords([KeyWord::key("-v").unwrap()]);
+	/// assert_eq!(args.next(), Some(Argument::Key("-v")));
+	/// ```
+	pub fn from_strings<I2: IntoIterator<IntoIter=I>>(src: I2) -> Self {
+		let cb: fn(S) -> OsString = Into::into;
+		Self::from(src.into_iter().map(cb))
+	}
+}
+
+impl<I> Argue<Map<I, fn((String, Option<String>)) -> OsString>>
+where I: Iterator<Item=(String, Option<String>)> {
+	#[must_use]
+	/// # From Pairs.
+	///
+	/// Create a new instance from structured `(key, value)` pairs — as
+	/// might come from a web form or RPC call — rather than raw CLI
+	/// arguments, so frontends that have already done their own splitting
+	/// can still take advantage of the keyword registry, validation, and
+	/// everything else.
+	///
+	/// Each pair is classified on its own: keys are matched against the
+	/// keywords exactly — never lexed or split — and the value, if any, is
+	/// attached to it exactly as given. Nothing is ever pulled from the next
+	/// pair, so a key missing a required value is always returned as an
+	/// [`Argument::MissingValue`], and help topics and command payloads are
+	/// always empty.
+	///
+	/// Positionals and commands should be passed with a value of `None`.
+	/// (A value attached to one of those, or to an unknown key, will be
+	/// returned as part of an [`Argument::Other`], i.e. `key=value`.)
+	///
+	/// Keys containing an `=`, as well as `--`, can never match anything,
+	/// and are likewise returned as [`Argument::Other`]. (There is no
+	/// end-of-options separator for pairs.)
+	///
+	/// Note: [`Argue::child`] instances created from this one parse raw
+	/// arguments as usual.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use argyle::{Argue, Argument, KeyWord};
+	///
+	/// let mut args = Argue::from_pairs([
+	///     ("--out".to_owned(), Some("-weird=name.txt".to_owned())),
+	///     ("-v".to_owned(), None),
+	///     ("--threads".to_owned(), None),
+	///     ("file.txt".to_owned(), None),
+	/// ])
+	///     .with_keywords([
+	///         KeyWord::key("-v").unwrap(),
+	///         KeyWord::key_with_value("--out").unwrap(),
+	///         KeyWord::key_with_value("--threads").unwrap(),
+	///     ]);
+	///
+	/// assert_eq!(
+	///     args.next(),
+	///     Some(Argument::KeyWithValue("--out", "-weird=name.txt".to_owned())),
+	/// );
+	/// assert_eq!(args.next(), Some(Argument::Key("-v")));
+	/// assert_eq!(args.next(), Some(Argument::MissingValue("--threads")));
+	/// assert_eq!(args.next(), Some(Argument::Other("file.txt".to_owned())));
+	/// assert_eq!(args.next(), None);
+	/// ```
+	pub fn from_pairs<P: IntoIterator<IntoIter=I>>(pairs: P) -> Self {
+		let cb: fn((String, Option<String>)) -> OsString = join_pair;
+		let mut out = Self::from(pairs.into_iter().map(cb));
+		out.flags |= FLAG_PAIRS;
+		out
+	}
+}
+
 impl<I> Argue<I> {
 	#[must_use]
 	/// # With Keywords.
@@ -104,6 +635,13 @@ impl<I> Argue<I> {
 	/// Specify the various keywords you'd like [`Argue`] to keep an eye out
 	/// for during parsing. It'll call them out specially if/when they appear.
 	///
+	/// Keywords can be passed by value or reference, so `static` lists — see
+	/// [`KeyWord::key_unwrap`] and friends — can be used directly.
+	///
+	/// Note: if the same keyword is registered more than once, the last one
+	/// wins. Use [`Argue::try_with_keywords`] instead if you'd rather know
+	/// about any conflicts.
+	///
 	/// ## Examples
 	///
 	/// ```
@@ -128,308 +666,4508 @@ impl<I> Argue<I> {
 	///     }
 	/// }
 	/// ```
-	pub fn with_keywords<I2: IntoIterator<Item=KeyWord>>(mut self, keys: I2) -> Self {
+	pub fn with_keywords<I2>(mut self, keys: I2) -> Self
+	where I2: IntoIterator, I2::Item: Into<KeyWord> {
 		for key in keys {
 			// Note: we're using `replace` instead of `insert` to keep the
 			// variants synced.
-			let _res = self.keys.replace(key);
+			let _res = self.keys.replace(key.into());
 		}
 
 		self
 	}
-}
-
-impl<I> Argue<I> {
-	/// # Find Key.
-	///
-	/// Find and return the key associated with `raw`, if any.
-	fn find_keyword(&self, raw: &str) -> Option<KeyWord> {
-		// Short circuit; keywords must start with a dash or alphanumeric.
-		let bytes = raw.as_bytes();
-		if bytes.is_empty() || ! (bytes[0] == b'-' || bytes[0].is_ascii_alphanumeric()) {
-			return None;
-		}
-
-		// Direct hit!
-		if let Some(key) = self.keys.get(raw) { return Some(*key); }
-
-		// Keylike strings could have a value gumming up the works; separate
-		// and try again if that is the case.
-		if 3 <= bytes.len() && bytes[0] == b'-' {
-			let needle: &str =
-				// Short keys can only be two bytes.
-				if bytes[1].is_ascii_alphanumeric() { raw.get(..2) }
-				// Long keys can only have values if there's an = sign
-				// in there somewhere.
-				else if bytes[1] == b'-' && bytes[2].is_ascii_alphanumeric() {
-					raw.split_once('=').map(|(k, _)| k)
-				}
-				// No dice.
-				else { None }?;
-			self.keys.get(needle).copied()
-		}
-		else { None }
-	}
-}
-
-impl<I: Iterator<Item=OsString>> Iterator for Argue<I> {
-	type Item = Argument;
-
-	fn next(&mut self) -> Option<Self::Item> {
-		loop {
-			// Pull the next value and try to stringify it.
-			let mut next = match self.iter.next()?.into_string() {
-				Ok(next) => next,
-				// We can't do anything with OsString; return as is.
-				Err(e) => return Some(Argument::InvalidUtf8(e)),
-			};
-
-			// Empty values that aren't associated with a key are pointless.
-			if next.is_empty() { continue; }
-
-			// If we've hit a separator, just gobble up the remaining bits and
-			// return them without further effort.
-			if next == "--" {
-				let next = self.iter.by_ref().collect::<Vec<_>>();
-				if next.is_empty() { return None; }
-				return Some(Argument::End(next));
-			}
-
-			// Is this a key?
-			if let Some(key) = self.find_keyword(&next) {
-				// Tease out the matched key.
-				let k = key.as_str();
-
-				// Return whatever we're meant to based on the match type.
-				return Some(match key {
-					KeyWord::Command(_) => Argument::Command(k),
-					KeyWord::Key(_) => Argument::Key(k),
-					KeyWord::KeyWithValue(_) => {
-						// We need a value for this one!
-						let v: String =
-							// Pull it from the next argument.
-							if next == k {
-								match self.iter.next()?.into_string() {
-									Ok(v) => v,
-									// This is awkward! Let's merge the key and
-									// value into a single OsString that can be
-									// returned instead.
-									Err(e) => {
-										let mut boo = OsString::from(k);
-										boo.push("=");
-										boo.push(e);
-										return Some(Argument::InvalidUtf8(boo));
-									},
-								}
-							}
-							// Split it off from the current argument.
-							else {
-								let mut v = next.split_off(k.len());
-								if v.starts_with('=') { v.drain(..1); }
-								v
-							};
-
-						Argument::KeyWithValue(k, v)
-					},
-				});
-			}
-
-			// Whatever it was, it was something else!
-			return Some(Argument::Other(next));
-		}
-	}
-}
 
-
-
-#[derive(Debug, Clone, Eq, PartialEq)]
-/// # Parsed Argument.
-///
-/// This is the return type for the [`Argue`] iterator. In practice, you'll
-/// probably want to use a `match` and take the appropriate action given the
-/// classification.
-pub enum Argument {
-	/// # (Sub)command.
+	#[must_use]
+	/// # With Keyword Table.
 	///
-	/// This is for arguments matching a [`KeyWord::Command`].
-	Command(&'static str),
-
-	/// # Boolean Key.
+	/// Look for the keywords in a static [`KeyWordTable`] — generated at
+	/// compile time by [`KeyWordsBuilder::save_phf`](crate::KeyWordsBuilder::save_phf)
+	/// — in addition to any added via [`Argue::with_keywords`] and friends.
 	///
-	/// This is for arguments matching a [`KeyWord::Key`].
-	Key(&'static str),
-
-	/// # Key and Value.
+	/// Table lookups are O(1) and require no allocation, so this is the way
+	/// to go for CLIs with very large keyword sets. The regular keywords, if
+	/// any, are checked first.
 	///
-	/// This is for arguments matching [`KeyWord::KeyWithValue`], along with
-	/// the associated value.
+	/// Note that [`Argue::verify_keywords`] only considers the regular
+	/// keywords; tables are assumed to have been validated by the builder.
 	///
-	/// Note: values are simply "the next entry" — unless split off from combo
-	/// args like `--key=val` — so may or may not be _logically_ correct, but
-	/// that's CLI arguments in a nutshell. Haha.
-	KeyWithValue(&'static str, String),
-
-	/// # Everything Else.
+	/// ## Examples
 	///
-	/// This is for arguments that don't meet the criteria for a more specific
-	/// [`Argument`] variant.
-	Other(String),
-
-	/// # Invalid UTF-8.
+	/// ```ignore
+	/// static KEYS: argyle::KeyWordTable =
+	///     include!(concat!(env!("OUT_DIR"), "/keyz-table.rs"));
 	///
-	/// This is for arguments that could not be converted to a String because
-	/// of invalid UTF-8. The original [`OsString`] representation is passed
-	/// through for your consideration.
-	InvalidUtf8(OsString),
+	/// let args = argyle::args().with_keyword_table(&KEYS);
+	/// ```
+	pub const fn with_keyword_table(mut self, table: &'static KeyWordTable) -> Self {
+		self.table = Some(table);
+		self
+	}
 
-	/// # Everything after "--".
+	/// # Try With Keywords.
 	///
-	/// This holds all remaining arguments after an end-of-command terminator
-	/// is encountered. (The terminator itself is stripped out.)
+	/// This is a strict alternative to [`Argue::with_keywords`] that refuses
+	/// to overwrite previously registered keywords with different variants,
+	/// such as a [`KeyWord::Key`] with a [`KeyWord::KeyWithValue`] of the
+	/// same name, or an alias pointing somewhere else.
 	///
-	/// The arguments are collected as-are without any normalization or
-	/// parsing. If you _want_ them parsed, you can create a new [`Argue`]
-	/// instance from the collection by passing it to `Argue::from`.
+	/// Exact duplicates are harmless and allowed.
 	///
-	/// ## Example
+	/// ## Examples
 	///
 	/// ```
-	/// use argyle::{Argue, Argument};
+	/// use argyle::{Argue, KeyWord};
+	/// use std::ffi::OsString;
 	///
-	/// let mut args = argyle::args();
-	/// if let Some(Argument::End(extra)) = args.next() {
-	///     for arg in Argue::from(extra.into_iter()) {
-	///         // Do more stuff!
-	///     }
-	/// }
+	/// let args = Argue::from([OsString::from("-h")])
+	///     .try_with_keywords([
+	///         KeyWord::key("-h").unwrap(),
+	///         KeyWord::key("-h").unwrap(), // Fine.
+	///     ])
+	///     .unwrap();
+	///
+	/// // But not this.
+	/// let res = args.try_with_keywords([KeyWord::key_with_value("-h").unwrap()]);
+	/// assert!(matches!(res, Err(KeyWord::KeyWithValue("-h"))));
+	/// ```
+	///
+	/// ## Errors
+	///
+	/// If a conflict is found, the offending keyword is returned as the
+	/// error.
+	pub fn try_with_keywords<I2>(mut self, keys: I2) -> Result<Self, KeyWord>
+	where I2: IntoIterator, I2::Item: Into<KeyWord> {
+		for key in keys {
+			let key = key.into();
+			if let Some(old) = self.keys.get(key.as_str()) {
+				let same = match (old, &key) {
+					(KeyWord::Alias(_, a), KeyWord::Alias(_, b)) => a == b,
+					_ => std::mem::discriminant(old) == std::mem::discriminant(&key),
+				};
+				if ! same { return Err(key); }
+			}
+			else { self.keys.insert(key); }
+		}
+
+		Ok(self)
+	}
+
+	/// # With Keywords (Checked).
+	///
+	/// Same as [`Argue::with_keywords`], but accepts the `Option`s returned
+	/// by [`KeyWord::command`], [`KeyWord::key`], etc., directly, saving you
+	/// from having to `unwrap` each and every one.
+	///
+	/// If any are `None`, nothing is added; an error is returned instead
+	/// listing the (one-based) positions of all the bad entries.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use argyle::{Argue, KeyWord};
+	/// use std::ffi::OsString;
+	///
+	/// let args = Argue::from([OsString::from("-h")])
+	///     .with_keywords_checked([
+	///         KeyWord::key("-h"),
+	///         KeyWord::key_with_value("--out"),
+	///     ])
+	///     .unwrap();
+	///
+	/// let res = args.with_keywords_checked([
+	///     KeyWord::key("--björk"),
+	///     KeyWord::key("-v"),
+	///     KeyWord::command("-q"),
+	/// ]);
+	/// assert_eq!(res.err().as_deref(), Some("Invalid keywords at positions 1, 3."));
+	/// ```
+	///
+	/// ## Errors
+	///
+	/// If any of the keywords are `None`, a human-readable error is returned.
+	pub fn with_keywords_checked<I2>(self, keys: I2) -> Result<Self, String>
+	where I2: IntoIterator<Item=Option<KeyWord>> {
+		let mut good = Vec::new();
+		let mut bad = Vec::new();
+		for (idx, key) in keys.into_iter().enumerate() {
+			if let Some(key) = key { good.push(key); }
+			else { bad.push((idx + 1).to_string()); }
+		}
+
+		match bad.len() {
+			0 => Ok(self.with_keywords(good)),
+			1 => Err(format!("Invalid keyword at position {}.", bad[0])),
+			_ => Err(format!("Invalid keywords at positions {}.", bad.join(", "))),
+		}
+	}
+
+	#[must_use]
+	/// # Number of Values.
+	///
+	/// Require a [`KeyWord::KeyWithValue`] to take exactly `n` values
+	/// instead of the usual one, returning them together as an
+	/// [`Argument::KeyWithValues`]. This is useful for things like
+	/// coordinates or ranges, e.g. `--size W H`.
+	///
+	/// The first value may be attached to the key, as in `--size=W H`, but the
+	/// rest must follow as separate arguments. Key-like arguments and `--`
+	/// are never consumed as values, and neither is anything else for long
+	/// keys when [`Argue::with_require_equals`] is in effect.
+	///
+	/// If there aren't enough values, an [`Argument::MissingValue`] is
+	/// returned instead, and any (separate) values that _were_ found are left
+	/// for normal processing.
+	///
+	/// Note: `n` is clamped to a minimum of one.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use argyle::{Argue, Argument, KeyWord};
+	/// use std::ffi::OsString;
+	///
+	/// let mut args = Argue::from([
+	///     OsString::from("--size"),
+	///     OsString::from("640"),
+	///     OsString::from("480"),
+	///     OsString::from("file.png"),
+	/// ])
+	///     .with_keywords([KeyWord::key_with_value("--size").unwrap()])
+	///     .nargs("--size", 2);
+	///
+	/// assert_eq!(
+	///     args.next(),
+	///     Some(Argument::KeyWithValues("--size", vec!["640".to_owned(), "480".to_owned()])),
+	/// );
+	/// assert_eq!(args.next(), Some(Argument::Other("file.png".to_owned())));
+	/// assert_eq!(args.next(), None);
+	/// ```
+	pub fn nargs(mut self, key: &'static str, n: usize) -> Self {
+		self.arity.insert(key, (n.max(1), false));
+		self
+	}
+
+	#[must_use]
+	/// # Minimum Number of Values.
+	///
+	/// Same as [`Argue::nargs`], except the key will greedily consume as many
+	/// values as it can — at least `n` — stopping at the first key-like
+	/// argument, `--`, or the end.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use argyle::{Argue, Argument, KeyWord};
+	/// use std::ffi::OsString;
+	///
+	/// let mut args = Argue::from([
+	///     OsString::from("--files"),
+	///     OsString::from("a.txt"),
+	///     OsString::from("b.txt"),
+	///     OsString::from("-v"),
+	/// ])
+	///     .with_keywords([
+	///         KeyWord::key_with_value("--files").unwrap(),
+	///         KeyWord::key("-v").unwrap(),
+	///     ])
+	///     .nargs_at_least("--files", 1);
+	///
+	/// assert_eq!(
+	///     args.next(),
+	///     Some(Argument::KeyWithValues("--files", vec!["a.txt".to_owned(), "b.txt".to_owned()])),
+	/// );
+	/// assert_eq!(args.next(), Some(Argument::Key("-v")));
+	/// assert_eq!(args.next(), None);
+	/// ```
+	pub fn nargs_at_least(mut self, key: &'static str, n: usize) -> Self {
+		self.arity.insert(key, (n.max(1), true));
+		self
+	}
+
+	#[must_use]
+	/// # With Fallback.
+	///
+	/// Specify a custom classifier for arguments that would otherwise be
+	/// returned as [`Argument::Other`], allowing for the single-pass
+	/// recognition of domain-specific tokens like `@host:port` endpoints or
+	/// `KEY=VAL` pairs.
+	///
+	/// The callback receives the raw argument and should return `Some` with
+	/// the replacement [`Argument`], or `None` to let it through as `Other`.
+	///
+	/// Note: the fallback is only consulted for valid UTF-8 arguments that
+	/// did not match any [`KeyWord`].
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use argyle::{Argue, Argument};
+	/// use std::ffi::OsString;
+	///
+	/// let mut args = Argue::from([
+	///     OsString::from("@localhost"),
+	///     OsString::from("/foo/bar"),
+	/// ])
+	///     .with_fallback(|raw: &str| {
+	///         let host = raw.strip_prefix('@')?;
+	///         Some(Argument::KeyWithValue("@", host.to_owned()))
+	///     });
+	///
+	/// assert_eq!(args.next(), Some(Argument::KeyWithValue("@", "localhost".to_owned())));
+	/// assert_eq!(args.next(), Some(Argument::Other("/foo/bar".to_owned())));
+	/// assert_eq!(args.next(), None);
+	/// ```
+	pub fn with_fallback(mut self, cb: fn(&str) -> Option<Argument>) -> Self {
+		self.fallback = Some(cb);
+		self
+	}
+
+	#[must_use]
+	/// # Map Arguments.
+	///
+	/// Register a transformation to apply to each newly-parsed [`Argument`]
+	/// before it is yielded, allowing for canonicalization — lowercasing
+	/// enum-like values, trimming whitespace, expanding `~/`, etc. — to
+	/// happen in one place instead of in every match arm.
+	///
+	/// This can be called more than once to build up a chain; the
+	/// transformations are applied in the order they were registered, each
+	/// receiving the output of the one before.
+	///
+	/// Note: the transformations run after the argument has been classified,
+	/// but before it is checked for diagnostics, warnings, and abort hooks,
+	/// so those see the final result.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use argyle::{Argue, Argument, KeyWord};
+	/// use std::ffi::OsString;
+	///
+	/// let mut args = Argue::from([
+	///     OsString::from("--format= JSON "),
+	///     OsString::from("~/file.txt"),
+	/// ])
+	///     .with_keywords([KeyWord::key_with_value("--format").unwrap()])
+	///     .map_arguments(|arg| match arg {
+	///         Argument::KeyWithValue("--format", v) =>
+	///             Argument::KeyWithValue("--format", v.trim().to_ascii_lowercase()),
+	///         arg => arg,
+	///     })
+	///     .map_arguments(|arg| match arg {
+	///         Argument::Other(v) if v.starts_with("~/") =>
+	///             Argument::Other(v.replacen('~', "/home/user", 1)),
+	///         arg => arg,
+	///     });
+	///
+	/// assert_eq!(args.next(), Some(Argument::KeyWithValue("--format", "json".to_owned())));
+	/// assert_eq!(args.next(), Some(Argument::Other("/home/user/file.txt".to_owned())));
+	/// assert_eq!(args.next(), None);
+	/// ```
+	pub fn map_arguments(mut self, cb: fn(Argument) -> Argument) -> Self {
+		self.maps.push(cb);
+		self
+	}
+
+	#[must_use]
+	/// # Abort On.
+	///
+	/// Call `handler` the moment any of the given (canonical) `keys` is
+	/// parsed as an [`Argument::Command`], [`Argument::Key`], or
+	/// [`Argument::KeyWithValue`], before anything else is pulled from the
+	/// source.
+	///
+	/// This lets tools with expensive setup short-circuit on things like
+	/// `--help` without first wading through (potentially) thousands of
+	/// other arguments. The handler is passed the matching key, and will
+	/// usually print something and exit; if it returns instead, parsing
+	/// continues normally.
+	///
+	/// Each key can only have one handler; if set more than once, the last
+	/// one wins.
+	///
+	/// Note: the keys must also be registered via [`Argue::with_keywords`].
+	/// Help requests captured by [`Argue::with_standard_help`] are returned
+	/// as [`Argument::Help`], so are not matched.
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use argyle::{Argue, KeyWord};
+	///
+	/// let args = argyle::args()
+	///     .with_keywords([
+	///         KeyWord::key("-h").unwrap(),
+	///         KeyWord::key("--help").unwrap(),
+	///     ])
+	///     .abort_on(["-h", "--help"], |_| {
+	///         println!("Usage: app [FILES]…");
+	///         std::process::exit(0);
+	///     });
+	/// ```
+	pub fn abort_on<I2>(mut self, keys: I2, handler: fn(&'static str)) -> Self
+	where I2: IntoIterator<Item=&'static str> {
+		self.aborts.extend(keys.into_iter().map(|k| (k, handler)));
+		self
+	}
+
+	#[must_use]
+	/// # On Key.
+	///
+	/// Handle a (canonical) `key` inline: whenever it is parsed as an
+	/// [`Argument::Command`], [`Argument::Key`], or
+	/// [`Argument::KeyWithValue`], `cb` is called with its value — if any —
+	/// and the argument is consumed rather than returned.
+	///
+	/// Any [`KeyWord`]s returned by the callback are added to the set
+	/// (replacing any existing entries with the same name), and take effect
+	/// immediately. This allows for layered configuration, such as a
+	/// `--config` file that enables additional keys, without making two
+	/// passes.
+	///
+	/// Note: the key must also be registered via [`Argue::with_keywords`].
+	/// Each key can only have one callback; if set more than once, the last
+	/// one wins.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use argyle::{Argue, Argument, KeyWord};
+	/// use std::ffi::OsString;
+	///
+	/// let mut args = Argue::from([
+	///     OsString::from("--plugins=extra"),
+	///     OsString::from("--extra"),
+	///     OsString::from("file.txt"),
+	/// ])
+	///     .with_keywords([KeyWord::key_with_value("--plugins").unwrap()])
+	///     .on_key("--plugins", |v| match v {
+	///         Some("extra") => vec![KeyWord::Key("--extra")],
+	///         _ => Vec::new(),
+	///     });
+	///
+	/// // The callback enabled --extra.
+	/// assert_eq!(args.next(), Some(Argument::Key("--extra")));
+	/// assert_eq!(args.next(), Some(Argument::Other("file.txt".to_owned())));
+	/// assert_eq!(args.next(), None);
+	/// ```
+	pub fn on_key(mut self, key: &'static str, cb: fn(Option<&str>) -> Vec<KeyWord>) -> Self {
+		self.callbacks.insert(key, cb);
+		self
+	}
+
+	#[must_use]
+	/// # With Namespace.
+	///
+	/// Set aside any otherwise-unmatched arguments beginning with `prefix`,
+	/// returning them as [`Argument::Namespaced`] with the prefix swapped
+	/// out for a plain `--`.
+	///
+	/// This is mainly intended for apps that embed another tool's CLI,
+	/// making it possible to forward its options — `--x-child-verbose` as
+	/// `--verbose` — without having to declare each one individually.
+	///
+	/// Note: since the child's keys are unknown, values must be attached
+	/// with an `=`, like `--x-child-out=file.txt`.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use argyle::{Argue, Argument};
+	/// use std::ffi::OsString;
+	///
+	/// let mut args = Argue::from([
+	///     OsString::from("--x-child-verbose"),
+	///     OsString::from("--x-child-out=file.txt"),
+	///     OsString::from("--verbose"),
+	/// ])
+	///     .with_namespace("--x-child-");
+	///
+	/// assert_eq!(
+	///     args.next(),
+	///     Some(Argument::Namespaced("--x-child-", "--verbose".to_owned())),
+	/// );
+	/// assert_eq!(
+	///     args.next(),
+	///     Some(Argument::Namespaced("--x-child-", "--out=file.txt".to_owned())),
+	/// );
+	/// assert_eq!(args.next(), Some(Argument::Other("--verbose".to_owned())));
+	/// assert_eq!(args.next(), None);
+	/// ```
+	pub fn with_namespace(mut self, prefix: &'static str) -> Self {
+		self.namespaces.insert(prefix);
+		self
+	}
+
+	#[must_use]
+	/// # With POSIX Mode.
+	///
+	/// Stop parsing options at the first positional argument — the first
+	/// [`Argument::Other`] or [`Argument::InvalidUtf8`] — like POSIX
+	/// `getopt`. Everything after that is returned as-is, without keyword
+	/// matching, including any dash-prefixed items and separators (`--`).
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use argyle::{Argue, Argument, KeyWord};
+	/// use std::ffi::OsString;
+	///
+	/// let mut args = Argue::from([
+	///     OsString::from("-n"),
+	///     OsString::from("file.txt"),
+	///     OsString::from("-n"),
+	/// ])
+	///     .with_keywords([KeyWord::key("-n").unwrap()])
+	///     .with_posix();
+	///
+	/// assert_eq!(args.next(), Some(Argument::Key("-n")));
+	/// assert_eq!(args.next(), Some(Argument::Other("file.txt".to_owned())));
+	/// assert_eq!(args.next(), Some(Argument::Other("-n".to_owned())));
+	/// assert_eq!(args.next(), None);
+	/// ```
+	pub const fn with_posix(mut self) -> Self {
+		self.flags |= FLAG_POSIX;
+		self
+	}
+
+	#[must_use]
+	/// # With Literal Separators.
+	///
+	/// By default, an end-of-command separator (`--`) causes all remaining
+	/// arguments to be collected and returned as an [`Argument::End`].
+	///
+	/// This method disables that behavior, causing `--` to be treated like
+	/// any other (non-keyword) argument instead, i.e. [`Argument::Other`].
+	///
+	/// This can be useful when re-parsing the contents of a previous
+	/// [`Argument::End`], as any nested separators were presumably meant to
+	/// be forwarded as-are.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use argyle::{Argue, Argument};
+	/// use std::ffi::OsString;
+	///
+	/// let raw = [
+	///     OsString::from("a"),
+	///     OsString::from("--"),
+	///     OsString::from("b"),
+	/// ];
+	///
+	/// // Normally the separator is special.
+	/// let mut args = Argue::from(raw.clone());
+	/// assert_eq!(args.next(), Some(Argument::Other("a".to_owned())));
+	/// assert_eq!(args.next(), Some(Argument::End(vec![OsString::from("b")])));
+	/// assert_eq!(args.next(), None);
+	///
+	/// // But it doesn't have to be.
+	/// let mut args = Argue::from(raw).with_literal_separator();
+	/// assert_eq!(args.next(), Some(Argument::Other("a".to_owned())));
+	/// assert_eq!(args.next(), Some(Argument::Other("--".to_owned())));
+	/// assert_eq!(args.next(), Some(Argument::Other("b".to_owned())));
+	/// assert_eq!(args.next(), None);
+	/// ```
+	pub const fn with_literal_separator(mut self) -> Self {
+		self.flags |= FLAG_LITERAL_SEP;
+		self
+	}
+
+	#[must_use]
+	/// # With Empty End.
+	///
+	/// By default, an end-of-command separator (`--`) with nothing after it
+	/// is silently dropped, making it indistinguishable from the natural end
+	/// of the arguments.
+	///
+	/// This method causes such separators to be returned as an empty
+	/// [`Argument::End`] instead, for apps that need to know the user
+	/// explicitly ended option parsing.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use argyle::{Argue, Argument};
+	/// use std::ffi::OsString;
+	///
+	/// let raw = [OsString::from("a"), OsString::from("--")];
+	///
+	/// // Normally the trailing separator just disappears.
+	/// let mut args = Argue::from(raw.clone());
+	/// assert_eq!(args.next(), Some(Argument::Other("a".to_owned())));
+	/// assert_eq!(args.next(), None);
+	///
+	/// // But it doesn't have to.
+	/// let mut args = Argue::from(raw).with_empty_end();
+	/// assert_eq!(args.next(), Some(Argument::Other("a".to_owned())));
+	/// assert_eq!(args.next(), Some(Argument::End(Vec::new())));
+	/// assert_eq!(args.next(), None);
+	/// ```
+	pub const fn with_empty_end(mut self) -> Self {
+		self.flags |= FLAG_EMPTY_END;
+		self
+	}
+
+	#[must_use]
+	/// # Classify End.
+	///
+	/// By default, everything after an end-of-command separator (`--`) is
+	/// collected into an [`Argument::End`] _without_ being looked at. Keys,
+	/// commands, help requests (`-h`, `--help`, `help`), color flags, abort
+	/// hooks, callbacks, etc., are never triggered by anything following the
+	/// separator.
+	///
+	/// This method opts out of that, for the rare tool that wants the
+	/// post-separator arguments classified like any others. The separator
+	/// itself is returned as an empty [`Argument::End`] marker, then parsing
+	/// resumes as normal, with everything — help interception included —
+	/// back in play.
+	///
+	/// This has no effect if [`Argue::with_literal_separator`] is also in
+	/// use.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use argyle::{Argue, Argument, KeyWord};
+	/// use std::ffi::OsString;
+	///
+	/// let raw = [
+	///     OsString::from("-v"),
+	///     OsString::from("--"),
+	///     OsString::from("-v"),
+	///     OsString::from("--help"),
+	/// ];
+	/// let keys = [KeyWord::key("-v").unwrap()];
+	///
+	/// // Normally the separator stops everything.
+	/// let mut args = Argue::from(raw.clone()).with_keywords(keys).with_standard_help();
+	/// assert_eq!(args.next(), Some(Argument::Key("-v")));
+	/// assert_eq!(args.next(), Some(Argument::End(vec![
+	///     OsString::from("-v"),
+	///     OsString::from("--help"),
+	/// ])));
+	/// assert_eq!(args.next(), None);
+	///
+	/// // But it doesn't have to.
+	/// let mut args = Argue::from(raw)
+	///     .with_keywords(keys)
+	///     .with_standard_help()
+	///     .classify_end();
+	/// assert_eq!(args.next(), Some(Argument::Key("-v")));
+	/// assert_eq!(args.next(), Some(Argument::End(Vec::new())));
+	/// assert_eq!(args.next(), Some(Argument::Key("-v")));
+	/// assert_eq!(args.next(), Some(Argument::Help { topic: None }));
+	/// assert_eq!(args.next(), None);
+	/// ```
+	pub const fn classify_end(mut self) -> Self {
+		self.flags |= FLAG_CLASSIFY_END;
+		self
+	}
+
+	#[must_use]
+	/// # With Strict End.
+	///
+	/// Guarantee that an end-of-command separator (`--`) is _always_ returned
+	/// as an [`Argument::End`] holding exactly what followed it, overriding
+	/// any configuration that would otherwise filter or reinterpret the
+	/// remainder:
+	/// * A trailing `--` is returned as an empty [`Argument::End`], as with
+	///   [`Argue::with_empty_end`];
+	/// * [`Argue::classify_end`] is ignored;
+	///
+	/// See [`Argument::End`] for the byte-exactness rules.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use argyle::{Argue, Argument};
+	/// use std::ffi::OsString;
+	///
+	/// let mut args = Argue::from([
+	///     OsString::from("--"),
+	///     OsString::new(),
+	///     OsString::from("--"),
+	/// ])
+	///     .classify_end()
+	///     .with_strict_end();
+	///
+	/// assert_eq!(args.next(), Some(Argument::End(vec![
+	///     OsString::new(),
+	///     OsString::from("--"),
+	/// ])));
+	/// assert_eq!(args.next(), None);
+	/// ```
+	pub const fn with_strict_end(mut self) -> Self {
+		self.flags |= FLAG_STRICT_END;
+		self
+	}
+
+	#[must_use]
+	/// # With Deferred End.
+	///
+	/// By default, everything after an end-of-command separator (`--`) is
+	/// collected into a freshly-allocated [`Argument::End`].
+	///
+	/// This method causes the separator to be returned as an empty
+	/// [`Argument::End`] marker instead — trailing or not — leaving the
+	/// remainder in the source for [`Argue::end_into`], so it can be moved
+	/// straight into a buffer of your choosing. This can help keep peak
+	/// memory down for apps forwarding huge trailing lists.
+	///
+	/// If the remainder is _not_ taken, the next call to `next` returns it
+	/// as a regular [`Argument::End`], unparsed, as usual.
+	///
+	/// This takes priority over [`Argue::classify_end`].
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use argyle::{Argue, Argument};
+	/// use std::ffi::OsString;
+	///
+	/// let mut buf: Vec<OsString> = Vec::with_capacity(64);
+	/// let mut args = Argue::from([
+	///     OsString::from("a"),
+	///     OsString::from("--"),
+	///     OsString::from("b"),
+	///     OsString::from("c"),
+	/// ])
+	///     .with_deferred_end();
+	///
+	/// while let Some(arg) = args.next() {
+	///     match arg {
+	///         Argument::End(_) => { args.end_into(&mut buf); },
+	///         Argument::Other(v) => assert_eq!(v, "a"),
+	///         _ => {},
+	///     }
+	/// }
+	///
+	/// assert_eq!(buf, [OsString::from("b"), OsString::from("c")]);
+	/// ```
+	pub const fn with_deferred_end(mut self) -> Self {
+		self.flags |= FLAG_DEFER_END;
+		self
+	}
+
+	#[must_use]
+	/// # Numeric Positionals.
+	///
+	/// Treat negative numbers — `-` followed by digits, with an optional
+	/// decimal part, like `-123` or `-1.5` — as positionals, bypassing the
+	/// keyword matching entirely, even if a numeric short key like `-1`
+	/// happens to exist.
+	///
+	/// They are also accepted as values in [`Argue::with_strict_values`]
+	/// mode and by [`Argue::nargs`] keys, and never reported as unknown keys.
+	///
+	/// This is handy for tools taking page ranges, offsets, and the like.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use argyle::{Argue, Argument, KeyWord};
+	/// use std::ffi::OsString;
+	///
+	/// let mut args = Argue::from([
+	///     OsString::from("-123"),
+	///     OsString::from("-1.5"),
+	///     OsString::from("-1"),
+	///     OsString::from("-1x"),
+	/// ])
+	///     .with_keywords([KeyWord::key_with_value("-1").unwrap()])
+	///     .numeric_positionals();
+	///
+	/// assert_eq!(args.next(), Some(Argument::Other("-123".to_owned())));
+	/// assert_eq!(args.next(), Some(Argument::Other("-1.5".to_owned())));
+	/// assert_eq!(args.next(), Some(Argument::Other("-1".to_owned())));
+	///
+	/// // Not a number.
+	/// assert_eq!(args.next(), Some(Argument::KeyWithValue("-1", "x".to_owned())));
+	/// assert_eq!(args.next(), None);
+	/// ```
+	pub const fn numeric_positionals(mut self) -> Self {
+		self.flags |= FLAG_NUMERIC;
+		self
+	}
+
+	#[must_use]
+	/// # With Leading Commands.
+	///
+	/// Only recognize [`KeyWord::Command`]s that come before any positional
+	/// arguments — or other commands — returning later matches as plain
+	/// [`Argument::Other`]s instead.
+	///
+	/// This makes the likes of `tool file.txt build` behave the way most
+	/// users would expect. If [`Argue::with_warnings`] is in effect, each
+	/// demotion is also recorded as a [`Warning::LateCommand`].
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use argyle::{Argue, Argument, KeyWord};
+	/// use std::ffi::OsString;
+	///
+	/// let mut args = Argue::from([
+	///     OsString::from("-v"),
+	///     OsString::from("build"),
+	///     OsString::from("file.txt"),
+	///     OsString::from("build"),
+	/// ])
+	///     .with_keywords([KeyWord::Command("build"), KeyWord::Key("-v")])
+	///     .with_leading_commands();
+	///
+	/// assert_eq!(args.next(), Some(Argument::Key("-v")));
+	/// assert_eq!(args.next(), Some(Argument::Command("build")));
+	/// assert_eq!(args.next(), Some(Argument::Other("file.txt".to_owned())));
+	/// assert_eq!(args.next(), Some(Argument::Other("build".to_owned())));
+	/// assert_eq!(args.next(), None);
+	/// ```
+	pub const fn with_leading_commands(mut self) -> Self {
+		self.flags |= FLAG_LEADING_CMD;
+		self
+	}
+
+	#[must_use]
+	/// # With First Commands.
+	///
+	/// Only recognize [`KeyWord::Command`]s in the very first position, or —
+	/// for subcommands — immediately after their parent, returning later
+	/// matches as plain [`Argument::Other`]s instead.
+	///
+	/// This is the strictest of the command policies, leaving commands free
+	/// to double as ordinary values anywhere else, e.g. `tool -v build` or
+	/// `tool --out build build`. As with [`Argue::with_leading_commands`],
+	/// demotions are recorded as [`Warning::LateCommand`]s if
+	/// [`Argue::with_warnings`] is in effect.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use argyle::{Argue, Argument, KeyWord};
+	/// use std::ffi::OsString;
+	///
+	/// let keys = [KeyWord::Command("build"), KeyWord::Key("-v")];
+	///
+	/// let mut args = Argue::from([OsString::from("build"), OsString::from("-v")])
+	///     .with_keywords(keys)
+	///     .with_first_commands();
+	/// assert_eq!(args.next(), Some(Argument::Command("build")));
+	/// assert_eq!(args.next(), Some(Argument::Key("-v")));
+	/// assert_eq!(args.next(), None);
+	///
+	/// let mut args = Argue::from([OsString::from("-v"), OsString::from("build")])
+	///     .with_keywords(keys)
+	///     .with_first_commands();
+	/// assert_eq!(args.next(), Some(Argument::Key("-v")));
+	/// assert_eq!(args.next(), Some(Argument::Other("build".to_owned())));
+	/// assert_eq!(args.next(), None);
+	/// ```
+	pub const fn with_first_commands(mut self) -> Self {
+		self.flags |= FLAG_FIRST_CMD;
+		self
+	}
+
+	#[must_use]
+	/// # With Commands Before Keys.
+	///
+	/// Only recognize [`KeyWord::Command`]s — subcommands included — that
+	/// come before any keys, returning later matches as plain
+	/// [`Argument::Other`]s instead.
+	///
+	/// Unlike [`Argue::with_leading_commands`], positionals don't count, so
+	/// `tool file.txt build` still finds the command, but `tool -v build`
+	/// does not.
+	///
+	/// This can be combined with the other policies; a command has to satisfy
+	/// all of them to be recognized. Demotions are recorded as
+	/// [`Warning::LateCommand`]s if [`Argue::with_warnings`] is in effect.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use argyle::{Argue, Argument, KeyWord};
+	/// use std::ffi::OsString;
+	///
+	/// let mut args = Argue::from([
+	///     OsString::from("file.txt"),
+	///     OsString::from("build"),
+	///     OsString::from("-v"),
+	///     OsString::from("build"),
+	/// ])
+	///     .with_keywords([KeyWord::Command("build"), KeyWord::Key("-v")])
+	///     .with_commands_before_keys();
+	///
+	/// assert_eq!(args.next(), Some(Argument::Other("file.txt".to_owned())));
+	/// assert_eq!(args.next(), Some(Argument::Command("build")));
+	/// assert_eq!(args.next(), Some(Argument::Key("-v")));
+	/// assert_eq!(args.next(), Some(Argument::Other("build".to_owned())));
+	/// assert_eq!(args.next(), None);
+	/// ```
+	pub const fn with_commands_before_keys(mut self) -> Self {
+		self.flags |= FLAG_KEYLESS_CMD;
+		self
+	}
+
+	#[must_use]
+	/// # With Subcommands.
+	///
+	/// Register keywords — subcommands and/or keys — that only come into
+	/// scope once the `parent` command has been found. Nesting can go as
+	/// deep as needed; just register each level under its parent.
+	///
+	/// Each command in the chain is returned as its own
+	/// [`Argument::Command`]; use [`Argue::command_path`] to see how deep
+	/// you are.
+	///
+	/// If [`Argue::with_leading_commands`] is in effect, a subcommand is
+	/// allowed to immediately follow its parent.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use argyle::{Argue, Argument, KeyWord};
+	/// use std::ffi::OsString;
+	///
+	/// let mut args = Argue::from([
+	///     OsString::from("remote"),
+	///     OsString::from("add"),
+	///     OsString::from("-f"),
+	///     OsString::from("origin"),
+	/// ])
+	///     .with_keywords([KeyWord::Command("remote")])
+	///     .with_subcommands("remote", [KeyWord::Command("add")])
+	///     .with_subcommands("add", [KeyWord::Key("-f")]);
+	///
+	/// assert_eq!(args.next(), Some(Argument::Command("remote")));
+	/// assert_eq!(args.next(), Some(Argument::Command("add")));
+	/// assert_eq!(args.command_path(), ["remote", "add"]);
+	/// assert_eq!(args.next(), Some(Argument::Key("-f")));
+	/// assert_eq!(args.next(), Some(Argument::Other("origin".to_owned())));
+	/// assert_eq!(args.next(), None);
+	///
+	/// // Out of scope, "add" is nothing special.
+	/// let mut args = Argue::from([OsString::from("add")])
+	///     .with_keywords([KeyWord::Command("remote")])
+	///     .with_subcommands("remote", [KeyWord::Command("add")]);
+	/// assert_eq!(args.next(), Some(Argument::Other("add".to_owned())));
+	/// ```
+	pub fn with_subcommands<I2>(mut self, parent: &'static str, keys: I2) -> Self
+	where I2: IntoIterator, I2::Item: Into<KeyWord> {
+		self.scopes.entry(parent).or_default().extend(keys.into_iter().map(Into::into));
+		self
+	}
+
+	#[must_use]
+	/// # Command Path.
+	///
+	/// Return the commands found so far, in order. The length is the depth
+	/// of the most recent one.
+	pub fn command_path(&self) -> &[&'static str] { &self.path }
+
+	#[must_use]
+	/// # With Command Payloads.
+	///
+	/// Have each matching [`KeyWord::Command`] greedily capture the
+	/// arguments that follow it — up to the next recognized keyword, an
+	/// end-of-command separator (`--`), or an invalid UTF-8 argument — and
+	/// return them together as an [`Argument::CommandWith`].
+	///
+	/// This is handy for `git remote add origin URL`-style sub-subcommands
+	/// whose deeper levels you'd rather not register individually.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use argyle::{Argue, Argument, KeyWord};
+	/// use std::ffi::OsString;
+	///
+	/// let mut args = Argue::from([
+	///     OsString::from("remote"),
+	///     OsString::from("add"),
+	///     OsString::from("origin"),
+	///     OsString::from("-v"),
+	/// ])
+	///     .with_keywords([KeyWord::Command("remote"), KeyWord::Key("-v")])
+	///     .with_command_payloads();
+	///
+	/// assert_eq!(
+	///     args.next(),
+	///     Some(Argument::CommandWith("remote", vec!["add".to_owned(), "origin".to_owned()])),
+	/// );
+	/// assert_eq!(args.next(), Some(Argument::Key("-v")));
+	/// assert_eq!(args.next(), None);
+	/// ```
+	pub const fn with_command_payloads(mut self) -> Self {
+		self.flags |= FLAG_CMD_PAYLOAD;
+		self
+	}
+
+	#[must_use]
+	/// # With Required Equals.
+	///
+	/// By default, [`KeyWord::KeyWithValue`] keys can receive their values
+	/// either from the same argument, like `--key=val`, or the next one, like
+	/// `--key val`.
+	///
+	/// This method restricts _long_ keys to the former, returning an
+	/// [`Argument::MissingValue`] — without consuming the next argument —
+	/// for the latter.
+	///
+	/// Short keys are unaffected.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use argyle::{Argue, Argument, KeyWord};
+	/// use std::ffi::OsString;
+	///
+	/// let mut args = Argue::from([
+	///     OsString::from("--out=a.txt"),
+	///     OsString::from("--out"),
+	///     OsString::from("b.txt"),
+	/// ])
+	///     .with_keywords([KeyWord::key_with_value("--out").unwrap()])
+	///     .with_require_equals();
+	///
+	/// assert_eq!(args.next(), Some(Argument::KeyWithValue("--out", "a.txt".to_owned())));
+	/// assert_eq!(args.next(), Some(Argument::MissingValue("--out")));
+	/// assert_eq!(args.next(), Some(Argument::Other("b.txt".to_owned())));
+	/// assert_eq!(args.next(), None);
+	/// ```
+	pub const fn with_require_equals(mut self) -> Self {
+		self.flags |= FLAG_REQUIRE_EQ;
+		self
+	}
+
+	#[must_use]
+	/// # With Strict Values.
+	///
+	/// By default, [`KeyWord::KeyWithValue`] keys take the next argument as
+	/// their value, no matter what it looks like. This method tells [`Argue`]
+	/// to refuse any that _look like_ keys — see [`starts_like_key`](crate::starts_like_key)
+	/// — returning [`Argument::MissingValue`] instead, and parsing the would-be
+	/// value as a separate argument.
+	///
+	/// Values attached with `=` or glued to short keys are unaffected, as are
+	/// any keys registered with [`Argue::allow_dash_values`].
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use argyle::{Argue, Argument, KeyWord};
+	/// use std::ffi::OsString;
+	///
+	/// let mut args = Argue::from([
+	///     OsString::from("--out"),
+	///     OsString::from("-v"),
+	///     OsString::from("--out=-v"),
+	/// ])
+	///     .with_keywords([
+	///         KeyWord::key("-v").unwrap(),
+	///         KeyWord::key_with_value("--out").unwrap(),
+	///     ])
+	///     .with_strict_values();
+	///
+	/// assert_eq!(args.next(), Some(Argument::MissingValue("--out")));
+	/// assert_eq!(args.next(), Some(Argument::Key("-v")));
+	/// assert_eq!(args.next(), Some(Argument::KeyWithValue("--out", "-v".to_owned())));
+	/// assert_eq!(args.next(), None);
+	/// ```
+	pub const fn with_strict_values(mut self) -> Self {
+		self.flags |= FLAG_STRICT_VALUES;
+		self
+	}
+
+	#[must_use]
+	/// # Allow Dash Values.
+	///
+	/// Exempt the given keys from [`Argue::with_strict_values`] so they
+	/// always accept the next argument as their value verbatim, even if it
+	/// starts with a dash. This is useful for keys like `--message` or
+	/// `--offset` that might legitimately receive values like `-n is not a flag`
+	/// or `-1`.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use argyle::{Argue, Argument, KeyWord};
+	/// use std::ffi::OsString;
+	///
+	/// let mut args = Argue::from([
+	///     OsString::from("--offset"),
+	///     OsString::from("-1"),
+	/// ])
+	///     .with_keywords([KeyWord::key_with_value("--offset").unwrap()])
+	///     .with_strict_values()
+	///     .allow_dash_values(["--offset"]);
+	///
+	/// assert_eq!(args.next(), Some(Argument::KeyWithValue("--offset", "-1".to_owned())));
+	/// assert_eq!(args.next(), None);
+	/// ```
+	pub fn allow_dash_values<I2: IntoIterator<Item=&'static str>>(mut self, keys: I2) -> Self {
+		self.dash_values.extend(keys);
+		self
+	}
+
+	#[must_use]
+	/// # With Verbatim Values.
+	///
+	/// Have the given short keys treat everything glued onto them as the
+	/// value, exactly as written.
+	///
+	/// Values attached to short keys are normally taken from the third
+	/// byte onward, unless it's an `=`, in which case the value starts
+	/// _after_ the sign. That's fine for `-o=file.txt`, but not for
+	/// compiler-style defines like `-D=x`, where the `=` might be
+	/// meaningful.
+	///
+	/// This doesn't affect long keys, which always need an `=` to separate
+	/// glued values, nor values passed as separate arguments, which are
+	/// always taken as-are.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use argyle::{Argue, Argument, KeyWord};
+	/// use std::ffi::OsString;
+	///
+	/// let mut args = Argue::from([
+	///     OsString::from("-DFOO=bar"),
+	///     OsString::from("-D=baz"),
+	/// ])
+	///     .with_keywords([KeyWord::key_with_value("-D").unwrap()])
+	///     .with_verbatim_values(["-D"]);
+	///
+	/// assert_eq!(args.next(), Some(Argument::KeyWithValue("-D", "FOO=bar".to_owned())));
+	/// assert_eq!(args.next(), Some(Argument::KeyWithValue("-D", "=baz".to_owned())));
+	/// assert_eq!(args.next(), None);
+	/// ```
+	pub fn with_verbatim_values<I2: IntoIterator<Item=&'static str>>(mut self, keys: I2) -> Self {
+		self.verbatim.extend(keys);
+		self
+	}
+
+	#[must_use]
+	/// # With Raw Values.
+	///
+	/// Have the given [`KeyWord::KeyWithValue`] keys return their values
+	/// as-are — without UTF-8 validation — via [`Argument::KeyWithOsValue`].
+	///
+	/// This is useful for paths and other potentially non-Unicode values,
+	/// sparing them both the validation overhead and the awkward
+	/// [`Argument::InvalidUtf8`]/[`Argument::KeyWithInvalidValue`] fallbacks.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use argyle::{Argue, Argument, KeyWord};
+	/// use std::ffi::OsString;
+	///
+	/// let mut args = Argue::from([
+	///     OsString::from("-o"),
+	///     OsString::from("/tmp/out.txt"),
+	///     OsString::from("--name=Björk"),
+	/// ])
+	///     .with_keywords([
+	///         KeyWord::key_with_value("-o").unwrap(),
+	///         KeyWord::key_with_value("--name").unwrap(),
+	///     ])
+	///     .with_raw_values(["-o"]);
+	///
+	/// assert_eq!(
+	///     args.next(),
+	///     Some(Argument::KeyWithOsValue("-o", OsString::from("/tmp/out.txt"))),
+	/// );
+	/// assert_eq!(
+	///     args.next(),
+	///     Some(Argument::KeyWithValue("--name", "Björk".to_owned())),
+	/// );
+	/// ```
+	pub fn with_raw_values<I2: IntoIterator<Item=&'static str>>(mut self, keys: I2) -> Self {
+		self.raw_values.extend(keys);
+		self
+	}
+
+	#[must_use]
+	/// # With Stdin.
+	///
+	/// By convention, a lone `-` is used to indicate input should be read
+	/// from STDIN. This method tells [`Argue`] to call those out as
+	/// [`Argument::Stdin`] rather than lumping them in with [`Argument::Other`].
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use argyle::{Argue, Argument};
+	/// use std::ffi::OsString;
+	///
+	/// let mut args = Argue::from([OsString::from("-"), OsString::from("a.txt")])
+	///     .with_stdin();
+	///
+	/// assert_eq!(args.next(), Some(Argument::Stdin));
+	/// assert_eq!(args.next(), Some(Argument::Other("a.txt".to_owned())));
+	/// assert_eq!(args.next(), None);
+	/// ```
+	pub const fn with_stdin(mut self) -> Self {
+		self.flags |= FLAG_STDIN;
+		self
+	}
+
+	#[must_use]
+	/// # With Diagnostics.
+	///
+	/// Keep track of any problems — unknown keys, missing values, etc. — as
+	/// they are encountered so they can all be dealt with at once at the
+	/// end. See [`Argue::finish`] for more details.
+	pub const fn with_diagnostics(mut self) -> Self {
+		self.flags |= FLAG_DIAGNOSTICS;
+		self
+	}
+
+	#[must_use]
+	/// # With Placeholder Checks.
+	///
+	/// Return any values or positionals containing what look like unexpanded
+	/// template placeholders — `{}`, `$@`, `%1`, etc., but not URL-encoded
+	/// bytes like `%20` — as [`Argument::Placeholder`] instead of the usual
+	/// [`Argument::KeyWithValue`] or [`Argument::Other`].
+	///
+	/// This helps catch templating bugs in `xargs`-style callers at the
+	/// boundary, rather than (say) creating a file literally named `{}`.
+	///
+	/// Arguments following a `--` separator are not checked.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use argyle::{Argue, Argument, KeyWord};
+	/// use std::ffi::OsString;
+	///
+	/// let mut args = Argue::from([
+	///     OsString::from("-o"),
+	///     OsString::from("{}.txt"),
+	///     OsString::from("$@"),
+	///     OsString::from("100%"),
+	/// ])
+	///     .with_keywords([KeyWord::key_with_value("-o").unwrap()])
+	///     .with_placeholder_checks();
+	///
+	/// assert_eq!(
+	///     args.next(),
+	///     Some(Argument::Placeholder { key: Some("-o"), value: "{}.txt".to_owned() }),
+	/// );
+	/// assert_eq!(
+	///     args.next(),
+	///     Some(Argument::Placeholder { key: None, value: "$@".to_owned() }),
+	/// );
+	/// assert_eq!(args.next(), Some(Argument::Other("100%".to_owned())));
+	/// assert_eq!(args.next(), None);
+	/// ```
+	pub const fn with_placeholder_checks(mut self) -> Self {
+		self.flags |= FLAG_PLACEHOLDERS;
+		self
+	}
+
+	#[must_use]
+	/// # With Standard Help.
+	///
+	/// Register `help` as a [`KeyWord::Command`] and `-h`/`--help` as
+	/// [`KeyWord::Key`]s, and return any of them as a unified
+	/// [`Argument::Help`].
+	///
+	/// If a help request is immediately followed by one of your other
+	/// commands, that command is included as the help topic, so `help build`
+	/// and `--help build` both come through as a request for `build`'s help.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use argyle::{Argue, Argument, KeyWord};
+	/// use std::ffi::OsString;
+	///
+	/// let mut args = Argue::from([
+	///     OsString::from("help"),
+	///     OsString::from("build"),
+	///     OsString::from("-h"),
+	///     OsString::from("file.txt"),
+	/// ])
+	///     .with_keywords([KeyWord::command("build").unwrap()])
+	///     .with_standard_help();
+	///
+	/// assert_eq!(args.next(), Some(Argument::Help { topic: Some("build".to_owned()) }));
+	/// assert_eq!(args.next(), Some(Argument::Help { topic: None }));
+	/// assert_eq!(args.next(), Some(Argument::Other("file.txt".to_owned())));
+	/// assert_eq!(args.next(), None);
+	/// ```
+	pub fn with_standard_help(mut self) -> Self {
+		self.flags |= FLAG_HELP;
+		self.with_keywords([
+			KeyWord::Command("help"),
+			KeyWord::Key("-h"),
+			KeyWord::Key("--help"),
+		])
+	}
+
+	#[must_use]
+	/// # With Environment Arguments.
+	///
+	/// Split the value of the environment variable `key` — if any — into
+	/// words, shell-style, and parse them before anything else. This makes
+	/// it easy to support `MYTOOL_FLAGS`-style defaults.
+	///
+	/// Words are separated by whitespace. Single and double quotes can be
+	/// used to group words with spaces, and backslashes can be used to escape
+	/// individual characters (other than within single quotes). No other
+	/// shell features — variables, globs, etc. — are supported.
+	///
+	/// The variable is read at the time this method is called. If it is
+	/// not valid UTF-8, it is passed through as a single argument.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use argyle::{Argue, Argument, KeyWord};
+	/// use std::ffi::OsString;
+	///
+	/// # std::env::set_var("MYTOOL_FLAGS", "-q --name 'Björk G'");
+	/// // MYTOOL_FLAGS="-q --name 'Björk G'"
+	/// let mut args = Argue::from([OsString::from("file.txt")])
+	///     .with_keywords([
+	///         KeyWord::key("-q").unwrap(),
+	///         KeyWord::key_with_value("--name").unwrap(),
+	///     ])
+	///     .with_env_args("MYTOOL_FLAGS");
+	///
+	/// assert_eq!(args.next(), Some(Argument::Key("-q")));
+	/// assert_eq!(args.next(), Some(Argument::KeyWithValue("--name", "Björk G".to_owned())));
+	/// assert_eq!(args.next(), Some(Argument::Other("file.txt".to_owned())));
+	/// assert_eq!(args.next(), None);
+	/// ```
+	pub fn with_env_args(mut self, key: &str) -> Self {
+		if let Some(raw) = (self.env)(key) {
+			#[cfg(feature = "stats")] let before = self.pending.len();
+			match raw.into_string() {
+				Ok(raw) => self.pending.extend(shell_words(&raw).into_iter().map(OsString::from)),
+				Err(raw) => self.pending.push_back(raw),
+			}
+			#[cfg(feature = "stats")] { self.stats.args += self.pending.len() - before; }
+		}
+		self
+	}
+
+	#[must_use]
+	/// # With POSIX Mode (Conditionally).
+	///
+	/// Same as [`Argue::with_posix`], but only enabled if the
+	/// `POSIXLY_CORRECT` environment variable is set (at the time this method
+	/// is called).
+	pub fn with_posixly_correct(self) -> Self {
+		if (self.env)("POSIXLY_CORRECT").is_some() { self.with_posix() }
+		else { self }
+	}
+}
+
+impl<I> Argue<I> {
+	#[must_use]
+	/// # With Environment Source.
+	///
+	/// Replace the function used to look up environment variables — by
+	/// default [`std::env::var_os`] — with your own.
+	///
+	/// This is mainly intended for testing, allowing the likes of
+	/// [`Argue::with_env_args`], [`Argue::with_posixly_correct`], and
+	/// [`Argue::color_choice`] to be exercised deterministically without
+	/// mutating the (shared) process environment.
+	///
+	/// Note that the environment-based builders read their variables at the
+	/// time they're called, so this must come first.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use argyle::{Argue, Argument, KeyWord};
+	/// use std::ffi::OsString;
+	///
+	/// let mut args = Argue::from([OsString::from("file.txt")])
+	///     .with_keywords([KeyWord::key("-q").unwrap()])
+	///     .with_env_source(|k| match k {
+	///         "MYTOOL_FLAGS" => Some(OsString::from("-q")),
+	///         _ => None,
+	///     })
+	///     .with_env_args("MYTOOL_FLAGS");
+	///
+	/// assert_eq!(args.next(), Some(Argument::Key("-q")));
+	/// assert_eq!(args.next(), Some(Argument::Other("file.txt".to_owned())));
+	/// assert_eq!(args.next(), None);
+	/// ```
+	pub const fn with_env_source(mut self, src: fn(&str) -> Option<OsString>) -> Self {
+		self.env = src;
+		self
+	}
+}
+
+impl<I> Argue<I> {
+	#[must_use]
+	/// # Child Parser.
+	///
+	/// Return a new [`Argue`] for `args` — typically the contents of an
+	/// [`Argument::End`] — with all of the same configuration as this one:
+	/// keywords, fallback, transformations, namespaces, dash, raw, and
+	/// verbatim values, deprecations, abort hooks, key callbacks, keyword
+	/// payloads, subcommands, value counts, limits, and hints, tracer,
+	/// environment source, and any `with_*` behaviors.
+	///
+	/// The child starts fresh otherwise; it has its own diagnostics and
+	/// warnings and a full byte budget, doesn't inherit any not-yet-parsed
+	/// arguments, and isn't stuck in operand mode just because the parent
+	/// was (see [`Argue::with_posix`]).
+	///
+	/// [`Argument::End`] arguments are collected in their original order —
+	/// duplicates and all — so the child will see them exactly as the user
+	/// passed them.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use argyle::{Argue, Argument, KeyWord};
+	/// use std::ffi::OsString;
+	///
+	/// let mut args = Argue::from([
+	///     OsString::from("-v"),
+	///     OsString::from("--"),
+	///     OsString::from("-v"),
+	///     OsString::from("file.txt"),
+	/// ])
+	///     .with_keywords([KeyWord::key("-v").unwrap()]);
+	///
+	/// assert_eq!(args.next(), Some(Argument::Key("-v")));
+	/// let Some(Argument::End(rest)) = args.next() else { panic!("No end?"); };
+	///
+	/// let mut child = args.child(rest);
+	/// assert_eq!(child.next(), Some(Argument::Key("-v")));
+	/// assert_eq!(child.next(), Some(Argument::Other("file.txt".to_owned())));
+	/// assert_eq!(child.next(), None);
+	/// ```
+	pub fn child<I2: IntoIterator<Item=OsString>>(&self, args: I2) -> Argue<I2::IntoIter> {
+		let mut out = Argue::from(args);
+		out.keys.clone_from(&self.keys);
+		out.table = self.table;
+		out.fallback = self.fallback;
+		out.maps.clone_from(&self.maps);
+		out.flags = self.flags & ! (FLAGS_POSITION | FLAG_PAIRS | FLAG_OVER_LIMIT | FLAG_SOURCE_DONE);
+		out.namespaces.clone_from(&self.namespaces);
+		out.dash_values.clone_from(&self.dash_values);
+		out.raw_values.clone_from(&self.raw_values);
+		out.verbatim.clone_from(&self.verbatim);
+		out.deprecated.clone_from(&self.deprecated);
+		out.aborts.clone_from(&self.aborts);
+		out.callbacks.clone_from(&self.callbacks);
+		out.payloads.clone_from(&self.payloads);
+		out.scopes.clone_from(&self.scopes);
+		out.budget = self.byte_limit;
+		out.byte_limit = self.byte_limit;
+		out.value_limit = self.value_limit;
+		out.value_limits.clone_from(&self.value_limits);
+		out.hints.clone_from(&self.hints);
+		out.arity.clone_from(&self.arity);
+		out.tracer = self.tracer;
+		out.env = self.env;
+		out
+	}
+}
+
+impl<I> Argue<I> {
+	#[must_use]
+	/// # Verify Keywords.
+	///
+	/// Check the configuration for combinations that are technically valid
+	/// but likely to cause surprises, returning a human-readable warning for
+	/// each. The following are checked:
+	/// * Aliases pointing to unknown keys or other aliases;
+	/// * Numeric short keys like `-1`, which look like negative numbers;
+	/// * Keys passed to [`Argue::allow_dash_values`] that don't take values;
+	/// * Keys shadowing a [`Argue::with_namespace`] prefix;
+	///
+	/// This is meant as a development aid, e.g. for use in a `debug_assert`
+	/// or unit test.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use argyle::{Argue, KeyWord};
+	/// use std::ffi::OsString;
+	///
+	/// let args = Argue::from(Vec::<OsString>::new())
+	///     .with_keywords([
+	///         KeyWord::key("-1").unwrap(),
+	///         KeyWord::key_with_value("--out").unwrap(),
+	///         KeyWord::Alias("-o", "--output"),
+	///     ]);
+	///
+	/// assert_eq!(
+	///     args.verify_keywords(),
+	///     [
+	///         "Short key -1 could be mistaken for a negative number.",
+	///         "Alias -o points to an unknown key (--output).",
+	///     ],
+	/// );
+	/// ```
+	pub fn verify_keywords(&self) -> Vec<String> {
+		let mut out = Vec::new();
+
+		for key in &self.keys {
+			let k = key.as_str();
+			match key {
+				KeyWord::Alias(_, target) => match self.keys.get(*target) {
+					Some(KeyWord::Alias(_, _)) => out.push(format!(
+						"Alias {k} points to another alias ({target}).",
+					)),
+					None => out.push(format!(
+						"Alias {k} points to an unknown key ({target}).",
+					)),
+					_ => {},
+				},
+				KeyWord::Command(_) => {},
+				KeyWord::Key(_) | KeyWord::KeyWithValue(_) |
+				KeyWord::KeyWithOptionalValue(_) | KeyWord::Prefix(_) =>
+					if let [b'-', b'0'..=b'9'] = k.as_bytes() {
+						out.push(format!(
+							"Short key {k} could be mistaken for a negative number.",
+						));
+					},
+			}
+
+			for prefix in &self.namespaces {
+				if k.starts_with(prefix) {
+					out.push(format!("Key {k} shadows namespace {prefix}."));
+				}
+			}
+		}
+
+		for k in &self.dash_values {
+			if ! matches!(self.keys.get(*k), Some(KeyWord::KeyWithValue(_))) {
+				out.push(format!("Dash values allowed for {k}, but it doesn't take a value."));
+			}
+		}
+
+		out
+	}
+}
+
+impl<I> Argue<I> {
+	/// # Stop Parsing (POSIX).
+	///
+	/// In POSIX mode, positional arguments mark the end of option parsing.
+	/// This is called whenever one is encountered to flip the switch.
+	fn stop_posix(&mut self) {
+		self.flags |= FLAG_POSITIONAL;
+		if FLAG_POSIX == self.flags & FLAG_POSIX { self.flags |= FLAG_OPERANDS; }
+	}
+
+	/// # Split Values?
+	///
+	/// Returns `true` if the value for `k` may be pulled from the next
+	/// argument, i.e. neither [`Argue::with_require_equals`] (for long keys)
+	/// nor [`Argue::from_pairs`] is in effect.
+	fn split_values(&self, k: &str) -> bool {
+		0 == self.flags & FLAG_PAIRS &&
+		! (FLAG_REQUIRE_EQ == self.flags & FLAG_REQUIRE_EQ && k.starts_with("--"))
+	}
+
+	/// # Lex.
+	///
+	/// Classify `raw` by shape, honoring [`Argue::with_literal_separator`].
+	fn lex(&self, raw: &str) -> Lexeme {
+		lex(raw, FLAG_LITERAL_SEP == self.flags & FLAG_LITERAL_SEP)
+	}
+
+	/// # Looks Like a Key?
+	///
+	/// Same as [`starts_like_key`](crate::starts_like_key), except negative
+	/// numbers are let through when [`Argue::numeric_positionals`] is in
+	/// effect.
+	const fn looks_like_key(&self, raw: &str) -> bool {
+		crate::starts_like_key(raw) &&
+		! (FLAG_NUMERIC == self.flags & FLAG_NUMERIC && is_number(raw.as_bytes()))
+	}
+
+	/// # Namespaced Argument.
+	///
+	/// If `raw` begins with one of our namespace prefixes — and has
+	/// something after it — return it as an [`Argument::Namespaced`].
+	fn namespaced(&self, raw: &str) -> Option<Argument> {
+		self.namespaces.iter().find_map(|&prefix| {
+			let rest = raw.strip_prefix(prefix)?;
+			if rest.is_empty() { None }
+			else { Some(Argument::Namespaced(prefix, format!("--{rest}"))) }
+		})
+	}
+
+	/// # Match Keyword.
+	///
+	/// Look for `raw` among the regular keywords, then the static table,
+	/// if any.
+	fn match_keyword<'a>(&self, raw: &'a [u8]) -> Option<(KeyWord, Option<&'a [u8]>)> {
+		let (key, value) = crate::match_keyword(&self.keys, raw)
+			.or_else(|| self.table.and_then(|t| t.match_keyword(raw)))?;
+
+		// Verbatim short keys keep the whole remainder, = and all.
+		if
+			matches!(raw, [b'-', b, b'=', ..] if *b != b'-') &&
+			value.is_some() &&
+			self.verbatim.contains(key.as_str())
+		{
+			return Some((key, Some(&raw[2..])));
+		}
+
+		Some((key, value))
+	}
+
+	/// # Split Invalid Key/Value.
+	///
+	/// Try to split an invalid UTF-8 argument like `--key=<bad>` into its
+	/// key and raw value, returning an [`Argument::KeyWithInvalidValue`] if
+	/// the key is a known [`KeyWord::KeyWithValue`] or
+	/// [`KeyWord::KeyWithOptionalValue`].
+	fn split_invalid(&self, raw: &OsStr) -> Option<Argument> {
+		let (key, value) = split_raw(raw)?;
+		match self.match_keyword(key.as_bytes())? {
+			(KeyWord::KeyWithValue(k), None) if self.raw_values.contains(k) =>
+				Some(Argument::KeyWithOsValue(k, value)),
+			(KeyWord::KeyWithValue(k) | KeyWord::KeyWithOptionalValue(k), None) =>
+				Some(Argument::KeyWithInvalidValue(k, value)),
+			_ => None,
+		}
+	}
+}
+
+impl<I: Iterator<Item=OsString>> Argue<I> {
+	#[must_use]
+	/// # Partition Keys.
+	///
+	/// Extract all arguments keyed by any of the given `keys` — wherever they
+	/// may appear — returning them along with an iterator over everything
+	/// else, with the original order preserved.
+	///
+	/// The following variants are extracted, including the malformed ones,
+	/// so a global key never leaks into the remainder:
+	/// * [`Argument::Key`]
+	/// * [`Argument::CommandWith`]
+	/// * [`Argument::KeyWithValue`]
+	/// * [`Argument::KeyWithOsValue`]
+	/// * [`Argument::KeyWithValues`]
+	/// * [`Argument::KeyWithInvalidValue`]
+	/// * [`Argument::MissingValue`]
+	/// * [`Argument::UnexpectedValue`]
+	/// * [`Argument::ValueTooLong`]
+	///
+	/// Plain [`Argument::Command`]s are _not_ extracted, even if named.
+	///
+	/// This is useful for CLIs with global flags that could appear before
+	/// _or after_ a subcommand.
+	///
+	/// Note: to find all the needles, the underlying source must be driven
+	/// to completion, so the remaining arguments will have already been
+	/// parsed (with the current settings) by the time you get them.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use argyle::{Argue, Argument, KeyWord};
+	/// use std::ffi::OsString;
+	///
+	/// let (globals, mut args) = Argue::from([
+	///     OsString::from("build"),
+	///     OsString::from("--verbose"),
+	///     OsString::from("--release"),
+	/// ])
+	///     .with_keywords([
+	///         KeyWord::command("build").unwrap(),
+	///         KeyWord::key("--release").unwrap(),
+	///         KeyWord::key("--verbose").unwrap(),
+	///     ])
+	///     .partition_keys(["--verbose"]);
+	///
+	/// assert_eq!(globals, [Argument::Key("--verbose")]);
+	/// assert_eq!(args.next(), Some(Argument::Command("build")));
+	/// assert_eq!(args.next(), Some(Argument::Key("--release")));
+	/// assert_eq!(args.next(), None);
+	/// ```
+	pub fn partition_keys<'a, K: IntoIterator<Item=&'a str>>(mut self, keys: K)
+	-> (Vec<Argument>, Self) {
+		let keys: BTreeSet<&str> = keys.into_iter().collect();
+		let mut found = Vec::new();
+		let mut rest = VecDeque::new();
+		for arg in self.by_ref() {
+			match arg {
+				Argument::Key(k) |
+				Argument::CommandWith(k, _) |
+				Argument::KeyWithValue(k, _) |
+				Argument::KeyWithOsValue(k, _) |
+				Argument::KeyWithValues(k, _) |
+				Argument::KeyWithInvalidValue(k, _) |
+				Argument::MissingValue(k) |
+				Argument::UnexpectedValue(k, _) |
+				Argument::ValueTooLong(k, _) if keys.contains(k) => { found.push(arg); },
+				_ => { rest.push_back(arg); },
+			}
+		}
+
+		self.queue = rest;
+		(found, self)
+	}
+
+	/// # Is Empty?
+	///
+	/// Returns `true` if there are no more arguments to parse, without
+	/// consuming anything. (If necessary, one raw argument is pulled from the
+	/// source and set aside for later.)
+	///
+	/// Called before iteration, this can be used to detect an app invoked
+	/// with no arguments at all, e.g. to print usage and exit.
+	///
+	/// Note that raw arguments are counted, not parsed ones, so an empty
+	/// string — which would otherwise be skipped — counts as something.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use argyle::{Argue, Argument};
+	/// use std::ffi::OsString;
+	///
+	/// let mut args = Argue::from(Vec::<OsString>::new());
+	/// assert!(args.is_empty());
+	///
+	/// let mut args = Argue::from([OsString::from("a")]);
+	/// assert!(! args.is_empty());
+	/// assert_eq!(args.next(), Some(Argument::Other("a".to_owned()))); // Still there.
+	/// assert!(args.is_empty());
+	/// ```
+	pub fn is_empty(&mut self) -> bool {
+		if ! self.queue.is_empty() || ! self.pending.is_empty() { return false; }
+		let Some(next) = self.next_source() else { return true; };
+		self.pending.push_back(next);
+		false
+	}
+
+	#[must_use]
+	/// # Into End.
+	///
+	/// Stop parsing, returning everything that's left as raw arguments, the
+	/// same as if they had followed a `--`.
+	///
+	/// Any arguments already parsed and set aside — see
+	/// [`Argue::partition_keys`] — are converted back to raw form first, the
+	/// same way [`roundtrip`] does it.
+	///
+	/// Since this consumes the instance, the keywords and other configuration
+	/// are released along with it, making this a good way to hang onto the
+	/// remainder in long-running processes once classification is no longer
+	/// needed.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use argyle::{Argue, Argument, KeyWord};
+	/// use std::ffi::OsString;
+	///
+	/// let mut args = Argue::from([
+	///     OsString::from("exec"),
+	///     OsString::from("ls"),
+	///     OsString::from("-l"),
+	/// ])
+	///     .with_keywords([KeyWord::command("exec").unwrap()]);
+	///
+	/// assert_eq!(args.next(), Some(Argument::Command("exec")));
+	/// assert_eq!(
+	///     args.into_end(),
+	///     [OsString::from("ls"), OsString::from("-l")],
+	/// );
+	/// ```
+	pub fn into_end(mut self) -> Vec<OsString> {
+		let keys: Vec<KeyWord> = self.keys.iter()
+			.chain(self.table.map_or(&[][..], |t| t.keywords()))
+			.copied()
+			.collect();
+		let mut out = Vec::new();
+		for arg in std::mem::take(&mut self.queue) {
+			roundtrip::emit(arg, &keys, &mut out);
+		}
+		self.end_into(&mut out);
+		out
+	}
+
+	/// # End Into.
+	///
+	/// Append everything that's left in the source to `buf` as raw
+	/// arguments, without parsing or collecting them first.
+	///
+	/// This is mainly intended for use with [`Argue::with_deferred_end`],
+	/// allowing the arguments following a `--` to be moved straight into a
+	/// (reusable) buffer of your own, but can be called at any time to stop
+	/// parsing early.
+	///
+	/// Unlike [`Argue::into_end`], the instance is only borrowed, and any
+	/// arguments set aside by [`Argue::partition_keys`] are left alone.
+	///
+	/// See [`Argue::with_deferred_end`] for an example.
+	pub fn end_into(&mut self, buf: &mut Vec<OsString>) {
+		// Pairs are returned as key=value.
+		if FLAG_PAIRS == self.flags & FLAG_PAIRS {
+			buf.extend(std::iter::from_fn(|| self.next_raw()).map(|raw| match raw.into_string() {
+				Ok(raw) => OsString::from(pair_display(&raw)),
+				Err(raw) => raw,
+			}));
+		}
+		else { buf.extend(std::iter::from_fn(|| self.next_raw())); }
+	}
+
+	/// # Parse Line.
+	///
+	/// Split a line of input into words, shell-style — see
+	/// [`Argue::with_env_args`] for the rules — and parse them with the
+	/// current configuration, returning the results all at once.
+	///
+	/// This is intended for interactive prompts embedded in an app, allowing
+	/// a single, pre-configured instance to be reused for each line.
+	/// (Typically you'd create it with an empty source for this purpose;
+	/// anything left in the original source is parsed first.)
+	///
+	/// Each line starts fresh as far as [`Argue::with_posix`] operand mode
+	/// is concerned.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use argyle::{Argue, Argument, KeyWord};
+	/// use std::ffi::OsString;
+	///
+	/// let mut repl = Argue::from(Vec::<OsString>::new())
+	///     .with_keywords([
+	///         KeyWord::command("open").unwrap(),
+	///         KeyWord::key("-f").unwrap(),
+	///     ]);
+	///
+	/// assert_eq!(
+	///     repl.parse_line(r#"open -f "My File.txt""#),
+	///     [
+	///         Argument::Command("open"),
+	///         Argument::Key("-f"),
+	///         Argument::Other("My File.txt".to_owned()),
+	///     ],
+	/// );
+	///
+	/// assert_eq!(repl.parse_line("  "), []);
+	/// ```
+	pub fn parse_line(&mut self, line: &str) -> Vec<Argument> {
+		self.flags &= ! FLAGS_POSITION;
+		self.extend(shell_words(line).into_iter().map(OsString::from));
+		self.by_ref().collect()
+	}
+
+	/// # Next Raw Argument.
+	///
+	/// Return the next pending argument, if any, or pull a new one from the
+	/// source.
+	fn next_raw(&mut self) -> Option<OsString> {
+		let next =
+			if let Some(next) = self.pending.pop_front() { next }
+			else { self.next_source()? };
+
+		if self.spend(next.len()) { Some(next) }
+		else { None }
+	}
+
+	/// # Next Source Argument.
+	///
+	/// Pull a new argument from the source — or anything appended to it —
+	/// unless the byte limit has been exceeded.
+	fn next_source(&mut self) -> Option<OsString> {
+		if FLAG_OVER_LIMIT == self.flags & FLAG_OVER_LIMIT { return None; }
+		let next = self.source_next().or_else(|| self.appended.pop_front())?;
+		if let Some(cp) = &mut self.checkpoint { cp.record(&next); }
+		#[cfg(feature = "stats")] { self.stats.args += 1; }
+		Some(next)
+	}
+
+	/// # Source Next.
+	///
+	/// Pull the next argument from the source iterator proper, unless it has
+	/// already run dry.
+	fn source_next(&mut self) -> Option<OsString> {
+		if FLAG_SOURCE_DONE == self.flags & FLAG_SOURCE_DONE { return None; }
+		let next = self.iter.next();
+		if next.is_none() { self.flags |= FLAG_SOURCE_DONE; }
+		next
+	}
+
+	/// # End.
+	///
+	/// Handle an end-of-command separator, returning everything after it —
+	/// or nothing — as appropriate.
+	fn end(&mut self) -> Option<Argument> {
+		// Leave the rest for later?
+		if FLAG_DEFER_END == self.flags & FLAG_DEFER_END {
+			self.flags |= FLAG_IN_END;
+			return Some(Argument::End(Vec::new()));
+		}
+
+		// Unless we're supposed to keep going.
+		let strict = FLAG_STRICT_END == self.flags & FLAG_STRICT_END;
+		if ! strict && FLAG_CLASSIFY_END == self.flags & FLAG_CLASSIFY_END {
+			return Some(Argument::End(Vec::new()));
+		}
+
+		let next = std::iter::from_fn(|| self.next_raw()).collect::<Vec<_>>();
+		if next.is_empty() && ! strict && 0 == self.flags & FLAG_EMPTY_END {
+			return None;
+		}
+		#[cfg(feature = "stats")]
+		if ! next.is_empty() { self.stats.alloc_end += 1; }
+		Some(Argument::End(next))
+	}
+
+	/// # Help Topic.
+	///
+	/// Check if the argument following a help request is one of our
+	/// commands, and if so, return it. If not, the argument is set aside for
+	/// normal processing.
+	fn help_topic(&mut self) -> Option<String> {
+		// Pairs stand alone.
+		if FLAG_PAIRS == self.flags & FLAG_PAIRS { return None; }
+		let next = self.next_raw()?;
+
+		let topic = next.to_str()
+			.and_then(|s| self.match_keyword(s.as_bytes()))
+			.and_then(|found| match found {
+				(KeyWord::Command(c), None) if c != "help" => Some(c),
+				_ => None,
+			});
+		if let Some(c) = topic { return Some(c.to_owned()); }
+
+		self.pending.push_front(next);
+		None
+	}
+
+	/// # Next Value.
+	///
+	/// Pull the value for key `k` from the next argument, or return the
+	/// appropriate [`Argument`] error if that doesn't work out.
+	fn next_value(&mut self, k: &'static str) -> Result<String, Argument> {
+		let Some(v) = self.next_raw() else {
+			return Err(Argument::MissingValue(k));
+		};
+
+		match v.into_string() {
+			// Key-like values might not be allowed.
+			Ok(v) if
+				FLAG_STRICT_VALUES == self.flags & FLAG_STRICT_VALUES &&
+				self.looks_like_key(&v) &&
+				! self.dash_values.contains(k)
+			=> {
+				self.pending.push_front(OsString::from(v));
+				Err(Argument::MissingValue(k))
+			},
+			Ok(v) => {
+				self.emit_trace(&Trace::Value { key: k, raw: &v });
+				Ok(v)
+			},
+			// Pass invalid values through as-are.
+			Err(e) => Err(Argument::KeyWithInvalidValue(k, e)),
+		}
+	}
+
+	/// # Raw Value.
+	///
+	/// Return the value for a key registered with [`Argue::with_raw_values`],
+	/// either the one glued to it, or the next argument, which — unlike
+	/// `next_value` — is not subject to UTF-8 validation.
+	fn os_value(&mut self, k: &'static str, glued: Option<String>) -> Argument {
+		if let Some(v) = glued { return Argument::KeyWithOsValue(k, OsString::from(v)); }
+
+		// Long keys might not be allowed to split values.
+		if ! self.split_values(k) { return Argument::MissingValue(k); }
+
+		let Some(v) = self.next_raw() else { return Argument::MissingValue(k); };
+
+		// Key-like values might not be allowed.
+		if
+			FLAG_STRICT_VALUES == self.flags & FLAG_STRICT_VALUES &&
+			self.looks_like_key(&v.to_string_lossy()) &&
+			! self.dash_values.contains(k)
+		{
+			self.pending.push_front(v);
+			Argument::MissingValue(k)
+		}
+		else { Argument::KeyWithOsValue(k, v) }
+	}
+
+	/// # Command.
+	///
+	/// Return the matched command — with its payload, if applicable — or,
+	/// if it's late to the party, the raw argument as an
+	/// [`Argument::Other`]. See [`Argue::with_leading_commands`],
+	/// [`Argue::with_first_commands`], and [`Argue::with_commands_before_keys`].
+	fn command(&mut self, k: &'static str, raw: String) -> Argument {
+		const LATE: u32 = FLAG_LEADING_CMD | FLAG_POSITIONAL;
+		const AFTER_KEY: u32 = FLAG_KEYLESS_CMD | FLAG_SEEN_KEY;
+		if
+			(LATE == self.flags & LATE && ! self.is_subcommand(k)) ||
+			(
+				FLAG_FIRST_CMD == self.flags & FLAG_FIRST_CMD &&
+				(
+					FLAG_SEEN_ARG == self.flags & FLAG_SEEN_ARG ||
+					// Only commands have been seen if we get this far.
+					(FLAG_POSITIONAL == self.flags & FLAG_POSITIONAL && ! self.is_subcommand(k))
+				)
+			) ||
+			AFTER_KEY == self.flags & AFTER_KEY
+		{
+			if FLAG_WARNINGS == self.flags & FLAG_WARNINGS {
+				self.warnings.push(Warning::LateCommand(k));
+			}
+			self.stop_posix();
+			return Argument::Other(raw);
+		}
+
+		// Bring the next level into scope, if any.
+		self.flags |= FLAG_POSITIONAL;
+		self.path.push(k);
+		if let Some(keys) = self.scopes.get(k) {
+			for key in keys { self.keys.replace(*key); }
+		}
+
+		if 0 == self.flags & FLAG_CMD_PAYLOAD { Argument::Command(k) }
+		else { self.command_payload(k) }
+	}
+
+	/// # Track Position.
+	///
+	/// Note what kind of argument was just parsed, for the benefit of the
+	/// command policies.
+	fn track_position(&mut self, arg: &Argument) {
+		match arg {
+			Argument::Command(_) | Argument::CommandWith(_, _) => {
+				self.flags &= ! FLAG_SEEN_ARG;
+			},
+			Argument::Key(_) | Argument::KeyWithValue(_, _) |
+			Argument::KeyWithValues(_, _) | Argument::KeyWithOsValue(_, _) |
+			Argument::UnexpectedValue(_, _) | Argument::MissingValue(_) |
+			Argument::Help { .. } | Argument::KeyWithInvalidValue(_, _) |
+			Argument::Namespaced(_, _) | Argument::ValueTooLong(_, _) |
+			Argument::Placeholder { key: Some(_), .. } => {
+				self.flags |= FLAG_SEEN_ARG | FLAG_SEEN_KEY;
+			},
+			_ => { self.flags |= FLAG_SEEN_ARG; },
+		}
+	}
+
+	/// # Is Subcommand?
+	///
+	/// Returns `true` if `k` is a subcommand of the most recent command.
+	fn is_subcommand(&self, k: &str) -> bool {
+		self.path.last()
+			.and_then(|p| self.scopes.get(p))
+			.is_some_and(|keys| keys.iter().any(|kw| matches!(kw, KeyWord::Command(c) if *c == k)))
+	}
+
+	/// # Next Values.
+	///
+	/// Collect the values for a multi-value key — see [`Argue::nargs`] —
+	/// starting with the one attached to the key, if any.
+	fn next_values(&mut self, k: &'static str, first: Option<String>, min: usize, more: bool)
+	-> Argument {
+		let glued = usize::from(first.is_some());
+		let mut out: Vec<String> = first.into_iter().collect();
+		while (more || out.len() < min) && self.split_values(k) {
+			let Some(raw) = self.next_raw() else { break; };
+			match raw.into_string() {
+				Ok(v) if self.lex(&v) != Lexeme::Separator && ! self.looks_like_key(&v) => {
+					self.emit_trace(&Trace::Value { key: k, raw: &v });
+					out.push(v);
+				},
+				Ok(v) => {
+					self.pending.push_front(OsString::from(v));
+					break;
+				},
+				Err(e) => {
+					self.pending.push_front(e);
+					break;
+				},
+			}
+		}
+
+		if out.len() < min {
+			// Give back whatever we took.
+			for v in out.drain(glued..).rev() { self.pending.push_front(OsString::from(v)); }
+			Argument::MissingValue(k)
+		}
+		else { Argument::KeyWithValues(k, out) }
+	}
+
+	/// # Command Payload.
+	///
+	/// Collect the arguments following command `k` up to the next keyword,
+	/// separator, or invalid UTF-8.
+	fn command_payload(&mut self, k: &'static str) -> Argument {
+		let mut out = Vec::new();
+		while 0 == self.flags & FLAG_PAIRS {
+			let Some(raw) = self.next_raw() else { break; };
+			match raw.into_string() {
+				Ok(v) if self.lex(&v) != Lexeme::Separator && self.match_keyword(v.as_bytes()).is_none() => {
+					self.emit_trace(&Trace::Value { key: k, raw: &v });
+					out.push(v);
+				},
+				Ok(v) => {
+					self.pending.push_front(OsString::from(v));
+					break;
+				},
+				Err(e) => {
+					self.pending.push_front(e);
+					break;
+				},
+			}
+		}
+
+		Argument::CommandWith(k, out)
+	}
+
+	/// # Parse Next (With Callbacks).
+	///
+	/// Same as `parse_next`, but consumes any arguments handled by
+	/// [`Argue::on_key`] callbacks or [`Argue::with_color_flag`], returning
+	/// the first one that isn't.
+	fn parse_callbacks(&mut self) -> Option<Argument> {
+		loop {
+			let next = self.parse_next()?;
+			let next = self.check_value_len(next);
+			self.track_position(&next);
+			if let Some(color) = self.color_arg(&next) {
+				self.color = color;
+				continue;
+			}
+
+			let (k, v) = match &next {
+				Argument::Command(k) | Argument::CommandWith(k, _) | Argument::Key(k) => (*k, None),
+				Argument::KeyWithValue(k, v) => (*k, Some(v.as_str())),
+				Argument::KeyWithOsValue(k, v) => (*k, v.to_str()),
+				_ => return Some(next),
+			};
+			let Some(cb) = self.callbacks.get(k) else { return Some(next); };
+			for key in cb(v) { self.keys.replace(key); }
+		}
+	}
+
+	/// # Parse Found.
+	///
+	/// Return the appropriate [`Argument`] for a matched keyword, given the
+	/// raw argument and the starting position of the value attached to it,
+	/// if any.
+	fn parse_found(&mut self, key: KeyWord, start: Option<usize>, next: String) -> Argument {
+		let k = key.as_str();
+
+		// Help is special.
+		if
+			FLAG_HELP == self.flags & FLAG_HELP &&
+			start.is_none() &&
+			matches!(k, "help" | "-h" | "--help")
+		{
+			return Argument::Help { topic: self.help_topic() };
+		}
+
+		// Return whatever we're meant to based on the match type.
+		match key {
+			// Aliases are resolved during the search.
+			KeyWord::Command(_) | KeyWord::Alias(_, _) => self.command(k, next),
+			// Boolean keys aren't supposed to have values!
+			KeyWord::Key(_) => start.map_or(
+				Argument::Key(k),
+				|start| Argument::UnexpectedValue(k, take_value(next, start)),
+			),
+			KeyWord::KeyWithValue(_) => {
+				// We might need several values.
+				if let Some(&(min, more)) = self.arity.get(k) {
+					let first = start.map(|start| take_value(next, start));
+					return self.next_values(k, first, min, more);
+				}
+
+				// Raw values are handled separately.
+				if self.raw_values.contains(k) {
+					let glued = start.map(|start| take_value(next, start));
+					return self.os_value(k, glued);
+				}
+
+				// We need a value for this one!
+				let v: String =
+					// Split it off from the current argument.
+					if let Some(start) = start { take_value(next, start) }
+					// Long keys might not be allowed to do that.
+					else if ! self.split_values(k) {
+						return Argument::MissingValue(k);
+					}
+					// Pull it from the next argument.
+					else {
+						match self.next_value(k) {
+							Ok(v) => v,
+							Err(e) => return e,
+						}
+					};
+
+				Argument::KeyWithValue(k, v)
+			},
+			KeyWord::KeyWithOptionalValue(_) | KeyWord::Prefix(_) => start.map_or(
+				Argument::Key(k),
+				|start| Argument::KeyWithValue(k, take_value(next, start)),
+			),
+		}
+	}
+
+	/// # Parse Pair.
+	///
+	/// Classify a structured key/value pair — see [`Argue::from_pairs`] —
+	/// matching the key exactly, without any lexical splitting, and
+	/// attaching the value, if any, as-is. `None` is returned for empty
+	/// pairs, which are skipped.
+	fn parse_pair(&mut self, raw: &str) -> Option<Argument> {
+		let Some((key, value)) = split_pair(raw) else {
+			self.stop_posix();
+			return Some(Argument::Other(pair_display(raw)));
+		};
+
+		// Whitespace policies only apply to the key.
+		let key = self.apply_whitespace(key.to_owned());
+		if key.is_empty() && value.is_none() { return None; }
+
+		let found =
+			if FLAG_OPERANDS == self.flags & FLAG_OPERANDS { None }
+			else {
+				self.match_keyword(key.as_bytes()).and_then(|(kw, v)|
+					// Commands can't have values.
+					if v.is_none() && (value.is_none() || ! matches!(kw, KeyWord::Command(_))) {
+						Some(kw)
+					}
+					else { None }
+				)
+			};
+		#[cfg(feature = "stats")] {
+			if found.is_some() { self.stats.hits += 1; }
+			else { self.stats.misses += 1; }
+		}
+
+		// Put the key and value back together so the value can be split
+		// off in place.
+		let start = value.map(|_| key.len());
+		let mut next = key;
+		if let Some(v) = value { next.push_str(v); }
+		self.emit_trace(&found.map_or(
+			Trace::NoMatch { raw: &next },
+			|keyword| Trace::Match { raw: &next, keyword, split: start },
+		));
+
+		if let Some(key) = found { return Some(self.parse_found(key, start, next)); }
+
+		// Read from STDIN?
+		if next == "-" && FLAG_STDIN == self.flags & FLAG_STDIN {
+			self.stop_posix();
+			return Some(Argument::Stdin);
+		}
+
+		// Whatever it was, it was something else!
+		if let Some(start) = start { next.insert(start, '='); }
+		self.stop_posix();
+		Some(Argument::Other(next))
+	}
+
+	/// # Parse Next.
+	///
+	/// Pull and parse the next argument from the source.
+	fn parse_next(&mut self) -> Option<Argument> {
+		// Anything left after a deferred end is still off-limits.
+		if FLAG_IN_END == self.flags & FLAG_IN_END {
+			let mut rest = Vec::new();
+			self.end_into(&mut rest);
+			return
+				if rest.is_empty() { None }
+				else { Some(Argument::End(rest)) };
+		}
+
+		loop {
+			// Pull the next value and try to stringify it. (This reuses the
+			// OsString's buffer, so is about as cheap as it gets.)
+			let next = match self.next_raw()?.into_string() {
+				Ok(next) => next,
+				// We can't do much with OsString, but if it's a key with an
+				// attached value, we might be able to salvage the key.
+				Err(e) => {
+					if 0 == self.flags & FLAG_OPERANDS {
+						if let Some(arg) = self.split_invalid(&e) {
+							#[cfg(feature = "stats")] { self.stats.alloc_invalid += 1; }
+							return Some(arg);
+						}
+					}
+					self.stop_posix();
+					return Some(Argument::InvalidUtf8(e));
+				},
+			};
+
+			// Structured pairs play by their own rules.
+			if FLAG_PAIRS == self.flags & FLAG_PAIRS {
+				match self.parse_pair(&next) {
+					Some(arg) => return Some(arg),
+					None => continue,
+				}
+			}
+
+			// Empty values that aren't associated with a key are pointless.
+			let next = self.apply_whitespace(next);
+			let lexeme = self.lex(&next);
+			if lexeme == Lexeme::Empty { continue; }
+
+			// If we're past the point of parsing, return it as-is.
+			if FLAG_OPERANDS == self.flags & FLAG_OPERANDS {
+				return Some(Argument::Other(next));
+			}
+
+			// If we've hit a separator, just gobble up the remaining bits and
+			// return them without further effort.
+			if lexeme == Lexeme::Separator { return self.end(); }
+
+			// Numbers might be positional no matter what.
+			if FLAG_NUMERIC == self.flags & FLAG_NUMERIC && is_number(next.as_bytes()) {
+				self.stop_posix();
+				return Some(Argument::Other(next));
+			}
+
+			// Read from STDIN?
+			if next == "-" && FLAG_STDIN == self.flags & FLAG_STDIN {
+				self.stop_posix();
+				return Some(Argument::Stdin);
+			}
+
+			// Is this a key?
+			let found = self.match_keyword(next.as_bytes());
+			#[cfg(feature = "stats")] {
+				if found.is_some() { self.stats.hits += 1; }
+				else { self.stats.misses += 1; }
+			}
+			self.emit_trace(&found.map_or(
+				Trace::NoMatch { raw: &next },
+				|(keyword, value)| Trace::Match {
+					raw: &next,
+					keyword,
+					split: value.map(|v| next.len() - v.len()),
+				},
+			));
+
+			if let Some((key, value)) = found {
+				// Tease out the starting position of the value, if any.
+				let start = value.map(|v| next.len() - v.len());
+				return Some(self.parse_found(key, start, next));
+			}
+
+			// Is it namespaced?
+			if let Some(arg) = self.namespaced(&next) { return Some(arg); }
+
+			// Give the fallback a crack at it, if any.
+			if let Some(arg) = self.fallback.and_then(|cb| cb(&next)) {
+				return Some(arg);
+			}
+
+			// Whatever it was, it was something else!
+			self.stop_posix();
+			return Some(Argument::Other(next));
+		}
+	}
+}
+
+impl<I: Iterator<Item=OsString>> Iterator for Argue<I> {
+	type Item = Argument;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		// Return anything we've already parsed first.
+		if let Some(next) = self.queue.pop_front() { return Some(next); }
+
+		// Key-like things are only unknown keys if we're still parsing.
+		let parsing = 0 == self.flags & FLAG_OPERANDS;
+		let mut next = self.parse_callbacks()?;
+
+		// Look for template leftovers?
+		if FLAG_PLACEHOLDERS == self.flags & FLAG_PLACEHOLDERS {
+			next = match next {
+				Argument::KeyWithValue(k, v) if has_placeholder(&v) =>
+					Argument::Placeholder { key: Some(k), value: v },
+				Argument::Other(v) if has_placeholder(&v) =>
+					Argument::Placeholder { key: None, value: v },
+				_ => next,
+			};
+		}
+
+		// Transform it?
+		for cb in &self.maps { next = cb(next); }
+
+		// Keep track of any problems?
+		if FLAG_DIAGNOSTICS == self.flags & FLAG_DIAGNOSTICS {
+			if let Some(d) = Diagnostic::from_arg(&next) { self.diagnostics.push(d); }
+			else if let Argument::Other(s) = &next {
+				if parsing && self.looks_like_key(s) {
+					self.diagnostics.push(Diagnostic::UnknownKey(s.clone()));
+				}
+			}
+		}
+
+		self.check_warnings(&next);
+
+		// Bail early?
+		if let
+			Argument::Command(k) | Argument::CommandWith(k, _) | Argument::Key(k) |
+			Argument::KeyWithValue(k, _) | Argument::KeyWithOsValue(k, _) |
+			Argument::KeyWithValues(k, _) = next
+		{
+			if let Some(cb) = self.aborts.get(k) { cb(k); }
+		}
+
+		Some(next)
+	}
+
+	/// # Size Hint.
+	///
+	/// Every raw argument yields at most one [`Argument`], so the upper bound
+	/// is everything parsed, set aside, or remaining in the source.
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let lower = self.queue.len();
+		let upper =
+			if FLAG_OVER_LIMIT == self.flags & FLAG_OVER_LIMIT { Some(0) }
+			else if FLAG_SOURCE_DONE == self.flags & FLAG_SOURCE_DONE { Some(self.appended.len()) }
+			else {
+				self.iter.size_hint().1.and_then(|n| n.checked_add(self.appended.len()))
+			};
+
+		(
+			lower,
+			upper.and_then(|n| n.checked_add(lower + self.pending.len())),
+		)
+	}
+}
+
+/// # Append Arguments.
+///
+/// Additional raw arguments can be appended to a live [`Argue`] at any time.
+/// They are parsed — with all the same configuration and state — once the
+/// original source runs dry.
+///
+/// This allows a single instance to be reused across multiple batches of
+/// input, REPL-style. (Note that an [`Argue`] that has returned `None` will
+/// resume once more arguments are added.)
+///
+/// ## Examples
+///
+/// ```
+/// use argyle::{Argue, Argument, KeyWord};
+/// use std::ffi::OsString;
+///
+/// let mut args = Argue::from([OsString::from("-v")])
+///     .with_keywords([KeyWord::key("-v").unwrap()]);
+///
+/// assert_eq!(args.next(), Some(Argument::Key("-v")));
+/// assert_eq!(args.next(), None);
+///
+/// args.extend([OsString::from("file.txt"), OsString::from("-v")]);
+/// assert_eq!(args.next(), Some(Argument::Other("file.txt".to_owned())));
+/// assert_eq!(args.next(), Some(Argument::Key("-v")));
+/// assert_eq!(args.next(), None);
+/// ```
+impl<I> Extend<OsString> for Argue<I> {
+	fn extend<T: IntoIterator<Item=OsString>>(&mut self, iter: T) {
+		self.appended.extend(iter);
+	}
+}
+
+
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+/// # Parsed Argument.
+///
+/// This is the return type for the [`Argue`] iterator. In practice, you'll
+/// probably want to use a `match` and take the appropriate action given the
+/// classification.
+pub enum Argument {
+	/// # (Sub)command.
+	///
+	/// This is for arguments matching a [`KeyWord::Command`].
+	Command(&'static str),
+
+	/// # (Sub)command With Payload.
+	///
+	/// This is returned instead of [`Argument::Command`] when
+	/// [`Argue::with_command_payloads`] is in effect, along with the
+	/// (possibly empty) run of arguments that followed it.
+	CommandWith(&'static str, Vec<String>),
+
+	/// # Boolean Key.
+	///
+	/// This is for arguments matching a [`KeyWord::Key`], or a
+	/// [`KeyWord::KeyWithOptionalValue`] or [`KeyWord::Prefix`] without a
+	/// value.
+	Key(&'static str),
+
+	/// # Key and Value.
+	///
+	/// This is for arguments matching [`KeyWord::KeyWithValue`] — or a
+	/// [`KeyWord::KeyWithOptionalValue`] or [`KeyWord::Prefix`] with a value
+	/// — along with the associated value.
+	///
+	/// Note: values are simply "the next entry" — unless split off from combo
+	/// args like `--key=val` — so may or may not be _logically_ correct, but
+	/// that's CLI arguments in a nutshell. Haha.
+	///
+	/// ## Duplicates
+	///
+	/// Keys passed more than once — `--out a --out b` — are returned once per
+	/// occurrence, in the order they appeared; nothing is ever merged,
+	/// deduplicated, or dropped. Whether the first or last one should win is
+	/// up to the implementing library; see [`ParseReport::first_value`](crate::ParseReport::first_value)
+	/// and [`ParseReport::last_value`](crate::ParseReport::last_value) for
+	/// collection-time helpers.
+	KeyWithValue(&'static str, String),
+
+	/// # Key and Values.
+	///
+	/// This is for [`KeyWord::KeyWithValue`] keys configured to take more
+	/// than one value via [`Argue::nargs`] or [`Argue::nargs_at_least`].
+	KeyWithValues(&'static str, Vec<String>),
+
+	/// # Key and Raw Value.
+	///
+	/// This is returned instead of [`Argument::KeyWithValue`] for keys
+	/// registered with [`Argue::with_raw_values`], with the value passed
+	/// through as-is.
+	KeyWithOsValue(&'static str, OsString),
+
+	/// # Boolean Key With Value.
+	///
+	/// This is for arguments matching a [`KeyWord::Key`] that were
+	/// nonetheless given a value, either using the `=` syntax, like
+	/// `--verbose=yes`, or glued onto a short key, like `-t2`.
+	///
+	/// Boolean keys aren't supposed to have values, so this is most likely a
+	/// user error, but how you handle it is up to you.
+	UnexpectedValue(&'static str, String),
+
+	/// # Key Without Value.
+	///
+	/// This is for arguments matching [`KeyWord::KeyWithValue`] for which no
+	/// value could be found, either because the key was the last argument,
+	/// because [`Argue::with_require_equals`] is in effect and the value
+	/// wasn't attached with an `=`, or because [`Argue::with_strict_values`]
+	/// is in effect and the next argument looked like a key.
+	MissingValue(&'static str),
+
+	/// # Help.
+	///
+	/// This is for `help`, `-h`, and `--help` arguments, but only if
+	/// [`Argue::with_standard_help`] is in effect.
+	///
+	/// If the request was immediately followed by a (different) command, it
+	/// is included as the `topic`.
+	Help {
+		/// # Help Topic.
+		topic: Option<String>,
+	},
+
+	/// # Key With Invalid Value.
+	///
+	/// This is for arguments matching a [`KeyWord::KeyWithValue`] or
+	/// [`KeyWord::KeyWithOptionalValue`] whose _value_ is not valid UTF-8.
+	/// The raw value is returned as-is.
+	///
+	/// Values passed as separate arguments, like `--key value`, are
+	/// supported everywhere; glued values, like `--key=value`, only on Unix
+	/// and Windows platforms. (Elsewhere the latter are returned as
+	/// [`Argument::InvalidUtf8`].)
+	KeyWithInvalidValue(&'static str, OsString),
+
+	/// # Namespaced Argument.
+	///
+	/// This is for arguments matching one of the prefixes registered with
+	/// [`Argue::with_namespace`]. The first value is the prefix; the second
+	/// is the re-keyed argument, with the prefix replaced by `--`.
+	Namespaced(&'static str, String),
+
+	/// # Placeholder.
+	///
+	/// This is for values and positionals containing what look like
+	/// unexpanded template placeholders, but only if
+	/// [`Argue::with_placeholder_checks`] is in effect.
+	///
+	/// The `key` is included for values.
+	Placeholder {
+		/// # Key.
+		key: Option<&'static str>,
+
+		/// # Value.
+		value: String,
+	},
+
+	/// # Value Too Long.
+	///
+	/// This is for values exceeding the limits set by
+	/// [`Argue::with_value_limit`] or [`Argue::with_value_limits`]. The key
+	/// is returned along with the length of the offending value, in bytes;
+	/// the value itself is discarded.
+	ValueTooLong(&'static str, usize),
+
+	/// # Read From STDIN.
+	///
+	/// This is for lone `-` arguments, but only if [`Argue::with_stdin`] is
+	/// in effect; otherwise they're returned as [`Argument::Other`].
+	Stdin,
+
+	/// # Everything Else.
+	///
+	/// This is for arguments that don't meet the criteria for a more specific
+	/// [`Argument`] variant.
+	Other(String),
+
+	/// # Invalid UTF-8.
+	///
+	/// This is for arguments that could not be converted to a String because
+	/// of invalid UTF-8. The original [`OsString`] representation is passed
+	/// through for your consideration.
+	///
+	/// This is lossless on all platforms. (On Windows, ill-formed UTF-16 is
+	/// kept in the standard library's internal WTF-8 encoding, and converted
+	/// back exactly when handed to an OS API.) See also
+	/// [`Argument::into_os_string`].
+	InvalidUtf8(OsString),
+
+	/// # Everything after "--".
+	///
+	/// This holds all remaining arguments after an end-of-command terminator
+	/// is encountered. (The terminator itself is stripped out.)
+	///
+	/// The collection is never empty unless [`Argue::with_empty_end`],
+	/// [`Argue::classify_end`], or [`Argue::with_strict_end`] is in effect.
+	///
+	/// ## Byte Exactness
+	///
+	/// The items are byte-for-byte identical to what the OS delivered, in
+	/// the original order. Unlike arguments _before_ the separator, empty
+	/// strings are kept, as are additional `--` and anything else that
+	/// might otherwise have been special. Nothing is converted, trimmed, or
+	/// validated.
+	///
+	/// The only things that can cut the collection short are the byte limit
+	/// set by [`Argue::with_byte_limit`], and [`Argue::classify_end`], which
+	/// opts out of the collection altogether (unless overridden by
+	/// [`Argue::with_strict_end`]).
+	///
+	/// The arguments are collected as-are without any normalization or
+	/// parsing; nothing after the separator is ever matched against the
+	/// keywords or intercepted as a help request, color flag, etc. (See
+	/// [`Argue::classify_end`] to change that.) If you _want_ them parsed, you can create a new [`Argue`]
+	/// instance from the collection by passing it to `Argue::from`, or to
+	/// [`Argue::child`] to reuse the current settings. (You may also want to
+	/// use [`Argue::with_literal_separator`] in such cases to prevent any
+	/// nested `--` from being treated specially.)
+	///
+	/// ## Example
+	///
+	/// ```
+	/// use argyle::{Argue, Argument};
+	///
+	/// let mut args = argyle::args();
+	/// if let Some(Argument::End(extra)) = args.next() {
+	///     for arg in Argue::from(extra.into_iter()) {
+	///         // Do more stuff!
+	///     }
+	/// }
 	/// ```
 	End(Vec<OsString>),
 }
 
+impl Argument {
+	#[must_use]
+	/// # As `OsStr`.
+	///
+	/// Return the positional value — that of an [`Argument::Other`],
+	/// [`Argument::InvalidUtf8`], or key-less [`Argument::Placeholder`] — as
+	/// an `OsStr`, regardless of whether or not it is valid Unicode.
+	///
+	/// This lets apps handle paths and the like uniformly, without caring
+	/// which variant they wound up in.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use argyle::Argument;
+	/// use std::ffi::OsStr;
+	///
+	/// let arg = Argument::Other("file.txt".to_owned());
+	/// assert_eq!(arg.as_os_str(), Some(OsStr::new("file.txt")));
+	///
+	/// assert_eq!(Argument::Key("-v").as_os_str(), None);
+	/// ```
+	pub fn as_os_str(&self) -> Option<&OsStr> {
+		match self {
+			Self::Other(v) | Self::Placeholder { key: None, value: v } => Some(OsStr::new(v)),
+			Self::InvalidUtf8(v) => Some(v.as_os_str()),
+			_ => None,
+		}
+	}
+
+	#[must_use]
+	/// # Into `OsString`.
+	///
+	/// Same as [`Argument::as_os_str`], but consuming and owned.
+	pub fn into_os_string(self) -> Option<OsString> {
+		match self {
+			Self::Other(v) | Self::Placeholder { key: None, value: v } => Some(OsString::from(v)),
+			Self::InvalidUtf8(v) => Some(v),
+			_ => None,
+		}
+	}
+
+	#[must_use]
+	/// # Ends With?
+	///
+	/// Returns `true` if the positional value — see [`Argument::as_os_str`]
+	/// — ends with `suffix`, comparing the raw bytes so invalid Unicode is no
+	/// obstacle. The match is case-sensitive.
+	///
+	/// This is mainly intended for filtering paths by extension.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use argyle::{Argue, Argument};
+	/// use std::ffi::OsString;
+	///
+	/// let files: Vec<OsString> = Argue::from([
+	///     OsString::from("one.jpg"),
+	///     OsString::from("two.png"),
+	///     OsString::from("three.jpg"),
+	/// ])
+	///     .filter(|a| a.ends_with(".jpg"))
+	///     .filter_map(Argument::into_os_string)
+	///     .collect();
+	///
+	/// assert_eq!(files, [OsString::from("one.jpg"), OsString::from("three.jpg")]);
+	///
+	/// // Keys aren't positional.
+	/// assert!(! Argument::Key("--x.jpg").ends_with(".jpg"));
+	/// ```
+	pub fn ends_with(&self, suffix: &str) -> bool {
+		self.as_os_str().is_some_and(|v| v.as_encoded_bytes().ends_with(suffix.as_bytes()))
+	}
+
+	#[must_use]
+	/// # File Name.
+	///
+	/// Return the final component of the positional value — see
+	/// [`Argument::as_os_str`] — when treated as a path, if any, the same as
+	/// [`Path::file_name`].
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use argyle::Argument;
+	/// use std::ffi::OsStr;
+	///
+	/// let arg = Argument::Other("/tmp/file.txt".to_owned());
+	/// assert_eq!(arg.file_name(), Some(OsStr::new("file.txt")));
+	///
+	/// assert_eq!(Argument::Other("/tmp/..".to_owned()).file_name(), None);
+	/// assert_eq!(Argument::Key("-v").file_name(), None);
+	/// ```
+	pub fn file_name(&self) -> Option<&OsStr> {
+		self.as_os_str().and_then(|v| Path::new(v).file_name())
+	}
+}
+
+
+
+/// # Shell Words.
+///
+/// Split a string into words, shell-style, honoring quotes and backslash
+/// escapes. Unterminated quotes run to the end of the string.
+fn shell_words(src: &str) -> Vec<String> {
+	let mut out = Vec::new();
+	let mut buf = String::new();
+	let mut word = false; // Distinguishes "" from nothing.
+	let mut chars = src.chars();
+	while let Some(c) = chars.next() {
+		match c {
+			'\'' => {
+				word = true;
+				buf.extend(chars.by_ref().take_while(|&c| c != '\''));
+			},
+			'"' => {
+				word = true;
+				while let Some(c) = chars.next() {
+					match c {
+						'"' => break,
+						'\\' => match chars.next() {
+							Some(c @ ('"' | '\\' | '$' | '`')) => buf.push(c),
+							Some(c) => { buf.push('\\'); buf.push(c); },
+							None => buf.push('\\'),
+						},
+						c => buf.push(c),
+					}
+				}
+			},
+			'\\' => {
+				word = true;
+				if let Some(c) = chars.next() { buf.push(c); }
+			},
+			c if c.is_whitespace() => if word {
+				out.push(std::mem::take(&mut buf));
+				word = false;
+			},
+			c => {
+				word = true;
+				buf.push(c);
+			},
+		}
+	}
+
+	if word { out.push(buf); }
+	out
+}
+
+#[cfg(unix)]
+/// # Split Raw Key/Value.
+///
+/// Split an `OsStr` at the first `=`, returning the key — if valid UTF-8 —
+/// and raw value.
+fn split_raw(raw: &OsStr) -> Option<(&str, OsString)> {
+	use std::os::unix::ffi::OsStrExt;
+
+	let bytes = raw.as_bytes();
+	let pos = bytes.iter().position(|&b| b == b'=')?;
+	let key = std::str::from_utf8(&bytes[..pos]).ok()?;
+	Some((key, OsStr::from_bytes(&bytes[pos + 1..]).to_os_string()))
+}
+
+#[cfg(windows)]
+/// # Split Raw Key/Value.
+///
+/// Split an `OsStr` at the first `=`, returning the key — if valid UTF-16 —
+/// and raw value.
+///
+/// Windows strings aren't bytes, so this works with the wide encoding
+/// instead.
+fn split_raw(raw: &OsStr) -> Option<(String, OsString)> {
+	use std::os::windows::ffi::{
+		OsStrExt,
+		OsStringExt,
+	};
+
+	let wide: Vec<u16> = raw.encode_wide().collect();
+	let pos = wide.iter().position(|&w| w == u16::from(b'='))?;
+	let key = String::from_utf16(&wide[..pos]).ok()?;
+	Some((key, OsString::from_wide(&wide[pos + 1..])))
+}
+
+#[cfg(not(any(unix, windows)))]
+/// # Split Raw Key/Value.
+///
+/// This isn't supported on other platforms.
+const fn split_raw(_raw: &OsStr) -> Option<(&str, OsString)> { None }
+
+/// # Take Value.
+///
+/// Strip the key from the front of an argument like `-kval` or `--key=val`,
+/// returning the value portion in the original buffer.
+///
+/// Values are usually tiny, so shifting them down is much cheaper than
+/// allocating a new `String` for them.
+fn take_value(mut raw: String, start: usize) -> String {
+	raw.replace_range(..start, "");
+	raw
+}
+
+/// # Environment Variable.
+///
+/// This is the default [`EnvSource`].
+fn env_var(key: &str) -> Option<OsString> { std::env::var_os(key) }
+
+/// # Join Pair.
+///
+/// Pack a key and value into a single argument for [`Argue::from_pairs`],
+/// separated by a NUL, so [`split_pair`] can tell them apart again without
+/// any guesswork.
+///
+/// Keys containing a NUL of their own can't be packed that way; they get
+/// a leading NUL instead, marking them as unmatchable.
+fn join_pair((mut key, value): (String, Option<String>)) -> OsString {
+	if key.contains('\0') {
+		key.insert(0, '\0');
+		if let Some(value) = value {
+			key.push('=');
+			key.push_str(&value);
+		}
+	}
+	else if let Some(value) = value {
+		key.push('\0');
+		key.push_str(&value);
+	}
+	OsString::from(key)
+}
+
+/// # Split Pair.
+///
+/// Unpack an argument packed by [`join_pair`] into its key and value, or
+/// return `None` if the key can never match anything: keys with embedded
+/// NULs or `=` signs, and `--`.
+fn split_pair(raw: &str) -> Option<(&str, Option<&str>)> {
+	if raw.starts_with('\0') { return None; }
+	let (key, value) = match raw.split_once('\0') {
+		Some((key, value)) => (key, Some(value)),
+		None => (raw, None),
+	};
+	if key == "--" || key.contains('=') { None }
+	else { Some((key, value)) }
+}
+
+/// # Pair Display.
+///
+/// Return a packed pair as `key=value`, or just `key` if there's no value.
+fn pair_display(raw: &str) -> String {
+	raw.strip_prefix('\0').map_or_else(
+		|| raw.replacen('\0', "=", 1),
+		str::to_owned,
+	)
+}
+
+/// # Is (Negative) Number?
+///
+/// Returns `true` if the value is a dash followed by one or more digits,
+/// optionally followed by a dot and one or more digits, e.g. `-1` or `-1.5`.
+const fn is_number(mut src: &[u8]) -> bool {
+	let [b'-', b'0'..=b'9', rest @ ..] = src else { return false; };
+	src = rest;
+	while let [b'0'..=b'9', rest @ ..] = src { src = rest; }
+	if let [b'.', b'0'..=b'9', rest @ ..] = src {
+		src = rest;
+		while let [b'0'..=b'9', rest @ ..] = src { src = rest; }
+	}
+	src.is_empty()
+}
+
+/// # Has Placeholder?
+///
+/// Returns `true` if the string contains `{}`, `$@`, `$*`, or `%1`–`%9`.
+/// (Percent-encoded bytes like `%20` don't count.)
+fn has_placeholder(src: &str) -> bool {
+	let bytes = src.as_bytes();
+	src.contains("{}") ||
+	src.contains("$@") ||
+	src.contains("$*") ||
+	bytes.iter().enumerate().any(|(k, &b)|
+		b == b'%' &&
+		matches!(bytes.get(k + 1), Some(b'1'..=b'9')) &&
+		! bytes.get(k + 2).is_some_and(u8::is_ascii_hexdigit)
+	)
+}
+
+#[must_use]
+/// # CLI Argument Iterator.
+///
+/// Return an [`Argue`] iterator seeded with [`ArgsOs`], skipping the first
+/// entry — the script path — since that isn't super useful.
+///
+/// (If you disagree on that last point, create your instance using
+/// `Argue::from(std::env::args_os())` instead.)
+pub fn args() -> Argue<Skip<ArgsOs>> {
+	Argue::from(std::env::args_os().skip(1))
+}
+
+
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use std::ffi::OsString;
+
+	#[test]
+	fn t_argue() {
+		let mut cli = vec![
+			OsString::from(""),
+			OsString::from("-s"),
+			OsString::from("--long"),
+			OsString::from("-t2"),
+			OsString::from("--m=yar"),
+			OsString::from("--n"),
+			OsString::from("yar"),
+			OsString::from("-u"),
+			OsString::from("2"),
+			OsString::from("/foo/bar"),
+			OsString::from("--"),
+		];
+
+		// Without keywords, everything should turn up other.
+		let mut args = Argue::from(cli.iter().cloned());
+		assert_eq!(args.next(), Some(Argument::Other("-s".to_owned())));
+		assert_eq!(args.next(), Some(Argument::Other("--long".to_owned())));
+		assert_eq!(args.next(), Some(Argument::Other("-t2".to_owned())));
+		assert_eq!(args.next(), Some(Argument::Other("--m=yar".to_owned())));
+		assert_eq!(args.next(), Some(Argument::Other("--n".to_owned())));
+		assert_eq!(args.next(), Some(Argument::Other("yar".to_owned())));
+		assert_eq!(args.next(), Some(Argument::Other("-u".to_owned())));
+		assert_eq!(args.next(), Some(Argument::Other("2".to_owned())));
+		assert_eq!(args.next(), Some(Argument::Other("/foo/bar".to_owned())));
+		assert_eq!(args.next(), None); // Without trailing arguments, the end is noned.
+
+		// Try again with some keywords.
+		args = Argue::from(cli.iter().cloned())
+			.with_keywords([
+				KeyWord::Key("-s"),
+				KeyWord::Key("--long"),
+				KeyWord::KeyWithValue("-t"),
+				KeyWord::KeyWithValue("--m"),
+				KeyWord::KeyWithValue("--n"),
+				KeyWord::KeyWithValue("-u"),
+			]);
+		assert_eq!(args.next(), Some(Argument::Key("-s")));
+		assert_eq!(args.next(), Some(Argument::Key("--long")));
+		assert_eq!(args.next(), Some(Argument::KeyWithValue("-t", "2".to_owned())));
+		assert_eq!(args.next(), Some(Argument::KeyWithValue("--m", "yar".to_owned())));
+		assert_eq!(args.next(), Some(Argument::KeyWithValue("--n", "yar".to_owned())));
+		assert_eq!(args.next(), Some(Argument::KeyWithValue("-u", "2".to_owned())));
+		assert_eq!(args.next(), Some(Argument::Other("/foo/bar".to_owned())));
+		assert_eq!(args.next(), None); // Without trailing arguments, the end is noned.
+
+		// Add some trailing arguments for good measure.
+		cli.push(OsString::from("Björk"));
+		cli.push(OsString::from("is"));
+		cli.push(OsString::from("best"));
+
+		args = Argue::from(cli.iter().cloned())
+			.with_keywords([
+				KeyWord::Key("-s"),
+				KeyWord::Key("--long"),
+				KeyWord::KeyWithValue("-t"),
+				KeyWord::KeyWithValue("--m"),
+				KeyWord::KeyWithValue("--n"),
+				KeyWord::KeyWithValue("-u"),
+			]);
+		assert_eq!(args.next(), Some(Argument::Key("-s")));
+		assert_eq!(args.next(), Some(Argument::Key("--long")));
+		assert_eq!(args.next(), Some(Argument::KeyWithValue("-t", "2".to_owned())));
+		assert_eq!(args.next(), Some(Argument::KeyWithValue("--m", "yar".to_owned())));
+		assert_eq!(args.next(), Some(Argument::KeyWithValue("--n", "yar".to_owned())));
+		assert_eq!(args.next(), Some(Argument::KeyWithValue("-u", "2".to_owned())));
+		assert_eq!(args.next(), Some(Argument::Other("/foo/bar".to_owned())));
+		assert_eq!(args.next(), Some(Argument::End(vec![
+			OsString::from("Björk"),
+			OsString::from("is"),
+			OsString::from("best"),
+		])));
+		assert_eq!(args.next(), None);
+
+		// Shorten the test so we can focus on key types.
+		cli.truncate(0);
+		cli.push(OsString::from("-t2"));
+		cli.push(OsString::from("--m=yar"));
+
+		// As before.
+		args = Argue::from(cli.iter().cloned())
+			.with_keywords([
+				KeyWord::Key("--long"), // Unused.
+				KeyWord::KeyWithValue("-t"),
+				KeyWord::KeyWithValue("--m"),
+			]);
+		assert_eq!(args.next(), Some(Argument::KeyWithValue("-t", "2".to_owned())));
+		assert_eq!(args.next(), Some(Argument::KeyWithValue("--m", "yar".to_owned())));
+		assert_eq!(args.next(), None);
+
+		// Booleans shouldn't have values, glued or otherwise.
+		args = Argue::from(cli.iter().cloned())
+			.with_keywords([
+				KeyWord::Key("-t"),
+				KeyWord::Key("--m"),
+			]);
+		assert_eq!(args.next(), Some(Argument::UnexpectedValue("-t", "2".to_owned())));
+		assert_eq!(args.next(), Some(Argument::UnexpectedValue("--m", "yar".to_owned())));
+		assert_eq!(args.next(), None);
+	}
+
+	#[test]
+	fn t_argue_alias() {
+		let cli = [
+			OsString::from("-h"),
+			OsString::from("--help"),
+			OsString::from("-ofile.txt"),
+			OsString::from("-o=file.txt"),
+			OsString::from("-o"),
+			OsString::from("file.txt"),
+			OsString::from("--output=file.txt"),
+			OsString::from("-q"),
+		];
+
+		let mut args = Argue::from(cli.into_iter())
+			.with_keywords(KeyWord::pair("-h", "--help", false).unwrap())
+			.with_keywords(KeyWord::pair("--output", "-o", true).unwrap())
+			.with_keywords([KeyWord::Alias("-q", "--quiet")]); // Orphan.
+
+		assert_eq!(args.next(), Some(Argument::Key("--help")));
+		assert_eq!(args.next(), Some(Argument::Key("--help")));
+		for _ in 0..4 {
+			assert_eq!(
+				args.next(),
+				Some(Argument::KeyWithValue("--output", "file.txt".to_owned())),
+			);
+		}
+		assert_eq!(args.next(), Some(Argument::Other("-q".to_owned())));
+		assert_eq!(args.next(), None);
+	}
+
+	#[test]
+	fn t_argue_fallback() {
+		let cli = [
+			OsString::from("-h"),
+			OsString::from("FOO=bar"),
+			OsString::from("baz"),
+		];
+
+		let mut args = Argue::from(cli.into_iter())
+			.with_keywords([KeyWord::Key("-h")])
+			.with_fallback(|raw: &str| {
+				let (k, v) = raw.split_once('=')?;
+				if k == "FOO" { Some(Argument::KeyWithValue("FOO", v.to_owned())) }
+				else { None }
+			});
+		assert_eq!(args.next(), Some(Argument::Key("-h")));
+		assert_eq!(args.next(), Some(Argument::KeyWithValue("FOO", "bar".to_owned())));
+		assert_eq!(args.next(), Some(Argument::Other("baz".to_owned())));
+		assert_eq!(args.next(), None);
+	}
+
+	#[test]
+	fn t_abort_on() {
+		use std::sync::atomic::{
+			AtomicUsize,
+			Ordering::SeqCst,
+		};
+
+		static PULLED: AtomicUsize = AtomicUsize::new(0);
+		static ABORTED: AtomicUsize = AtomicUsize::new(0);
+
+		let cli = [
+			OsString::from("a.txt"),
+			OsString::from("--help"),
+			OsString::from("b.txt"),
+			OsString::from("-v"),
+		];
+
+		let args = Argue::from(cli.into_iter().inspect(|_| { PULLED.fetch_add(1, SeqCst); }))
+			.with_keywords([KeyWord::Key("--help"), KeyWord::Key("-v")])
+			.abort_on(["--help"], |k| {
+				assert_eq!(k, "--help");
+				// Nothing after the key should have been touched yet.
+				ABORTED.store(PULLED.load(SeqCst), SeqCst);
+			});
+
+		// The handler returns, so parsing should continue as normal.
+		assert_eq!(args.count(), 4);
+		assert_eq!(ABORTED.load(SeqCst), 2);
+	}
+
+	#[test]
+	fn t_placeholders() {
+		for (raw, expected) in [
+			("", false),
+			("{", false),
+			("{x}", false),
+			("%", false),
+			("100%", false),
+			("$", false),
+			("$HOME", false),
+			("{}", true),
+			("a/{}.txt", true),
+			("$@", true),
+			("$*", true),
+			("%0", false),
+			("a%20b", false),
+			("https://x.com/?q=a%2Fb%3d", false),
+			("%1", true),
+			("x%9", true),
+			("%1 %2", true),
+			("%1.txt", true),
+			("a%20b/%1", true),
+		] {
+			assert_eq!(has_placeholder(raw), expected, "{raw}");
+		}
+
+		let cli = [
+			OsString::from("-o"),
+			OsString::from("{}"),
+			OsString::from("-o%1"),
+			OsString::from("-oa.txt"),
+			OsString::from("b.txt"),
+			OsString::from("-ohttps://x.com/a%20b"),
+			OsString::from("c/{}"),
+			OsString::from("--"),
+			OsString::from("{}"),
+		];
+		let mut args = Argue::from(cli.into_iter())
+			.with_keywords([KeyWord::KeyWithValue("-o")])
+			.with_placeholder_checks()
+			.with_diagnostics();
+		assert_eq!(args.next(), Some(Argument::Placeholder { key: Some("-o"), value: "{}".to_owned() }));
+		assert_eq!(args.next(), Some(Argument::Placeholder { key: Some("-o"), value: "%1".to_owned() }));
+		assert_eq!(args.next(), Some(Argument::KeyWithValue("-o", "a.txt".to_owned())));
+		assert_eq!(args.next(), Some(Argument::Other("b.txt".to_owned())));
+		assert_eq!(args.next(), Some(Argument::KeyWithValue("-o", "https://x.com/a%20b".to_owned())));
+		assert_eq!(args.next(), Some(Argument::Placeholder { key: None, value: "c/{}".to_owned() }));
+		assert_eq!(args.next(), Some(Argument::End(vec![OsString::from("{}")])));
+		assert_eq!(args.next(), None);
+
+		assert_eq!(
+			args.finish(),
+			[
+				Diagnostic::Placeholder(Some("-o"), "{}".to_owned()),
+				Diagnostic::Placeholder(Some("-o"), "%1".to_owned()),
+				Diagnostic::Placeholder(None, "c/{}".to_owned()),
+			],
+		);
+	}
+
+	#[test]
+	fn t_child() {
+		let mut args = Argue::from([OsString::from("a"), OsString::from("-v")].into_iter())
+			.with_keywords([KeyWord::KeyWithValue("-o"), KeyWord::Key("-v")])
+			.with_namespace("--x-")
+			.with_stdin()
+			.with_posix();
+
+		assert_eq!(args.next(), Some(Argument::Other("a".to_owned())));
+		assert_eq!(args.next(), Some(Argument::Other("-v".to_owned())));
+		assert_eq!(args.next(), None);
+
+		// The child should pick up all the settings, but not the operand mode.
+		let mut child = args.child([
+			OsString::from("-o"),
+			OsString::from("-"),
+			OsString::from("--x-v"),
+			OsString::from("b"),
+			OsString::from("-v"),
+		]);
+		assert_eq!(child.next(), Some(Argument::KeyWithValue("-o", "-".to_owned())));
+		assert_eq!(child.next(), Some(Argument::Namespaced("--x-", "--v".to_owned())));
+		assert_eq!(child.next(), Some(Argument::Other("b".to_owned())));
+		assert_eq!(child.next(), Some(Argument::Other("-v".to_owned())));
+		assert_eq!(child.next(), None);
+	}
+
+	#[test]
+	fn t_on_key() {
+		let cli = [
+			OsString::from("-x"),
+			OsString::from("--load"),
+			OsString::from("-x"),
+			OsString::from("--load=-y"),
+			OsString::from("-x"),
+			OsString::from("-y"),
+			OsString::from("--quiet"),
+		];
+		let mut args = Argue::from(cli.into_iter())
+			.with_keywords([KeyWord::KeyWithValue("--load"), KeyWord::Key("--quiet")])
+			.on_key("--load", |v| match v {
+				Some("-x") => vec![KeyWord::Key("-x")],
+				Some("-y") => vec![KeyWord::KeyWithValue("-x"), KeyWord::Key("-y")],
+				_ => Vec::new(),
+			})
+			.on_key("--quiet", |v| {
+				assert!(v.is_none());
+				Vec::new()
+			});
+
+		assert_eq!(args.next(), Some(Argument::Other("-x".to_owned())));
+		assert_eq!(args.next(), Some(Argument::KeyWithValue("-x", "-y".to_owned())));
+		assert_eq!(args.next(), None);
+	}
+
+	#[test]
+	fn t_keywords_checked() {
+		let args = Argue::from(std::iter::empty())
+			.with_keywords_checked([KeyWord::key("-v"), KeyWord::command("build")])
+			.unwrap();
+		assert_eq!(args.keys.len(), 2);
+
+		// One bad.
+		let res = args.with_keywords_checked([KeyWord::key("-x"), KeyWord::key("x")]);
+		assert_eq!(res.err().as_deref(), Some("Invalid keyword at position 2."));
+
+		// Nothing should be added if there are errors.
+		let res = Argue::from(std::iter::empty())
+			.with_keywords_checked([KeyWord::key("-x"), KeyWord::key("x"), KeyWord::key("")]);
+		assert_eq!(res.err().as_deref(), Some("Invalid keywords at positions 2, 3."));
+	}
+
+	#[test]
+	fn t_nargs() {
+		let cli = [
+			OsString::from("--size=1"),
+			OsString::from("2"),
+			OsString::from("--size"),
+			OsString::from("3"),
+			OsString::from("4"),
+			OsString::from("5"),
+			OsString::from("--files"),
+			OsString::from("a"),
+			OsString::from("b"),
+			OsString::from("-v"),
+			OsString::from("--files"),
+			OsString::from("-v"),
+			OsString::from("--size"),
+			OsString::from("6"),
+			OsString::from("--"),
+			OsString::from("7"),
+		];
+		let mut args = Argue::from(cli.into_iter())
+			.with_keywords([
+				KeyWord::KeyWithValue("--files"),
+				KeyWord::KeyWithValue("--size"),
+				KeyWord::Key("-v"),
+			])
+			.nargs("--size", 2)
+			.nargs_at_least("--files", 1);
+
+		assert_eq!(args.next(), Some(Argument::KeyWithValues("--size", vec!["1".to_owned(), "2".to_owned()])));
+		assert_eq!(args.next(), Some(Argument::KeyWithValues("--size", vec!["3".to_owned(), "4".to_owned()])));
+		assert_eq!(args.next(), Some(Argument::Other("5".to_owned())));
+		assert_eq!(args.next(), Some(Argument::KeyWithValues("--files", vec!["a".to_owned(), "b".to_owned()])));
+		assert_eq!(args.next(), Some(Argument::Key("-v")));
+
+		// Not enough values; the key-like argument should be left alone.
+		assert_eq!(args.next(), Some(Argument::MissingValue("--files")));
+		assert_eq!(args.next(), Some(Argument::Key("-v")));
+
+		// Not enough values; the value should be given back.
+		assert_eq!(args.next(), Some(Argument::MissingValue("--size")));
+		assert_eq!(args.next(), Some(Argument::Other("6".to_owned())));
+		assert_eq!(args.next(), Some(Argument::End(vec![OsString::from("7")])));
+		assert_eq!(args.next(), None);
+
+		// Long keys can't take separate values if equals are required.
+		let cli = [
+			OsString::from("--size"),
+			OsString::from("1"),
+			OsString::from("2"),
+			OsString::from("--size=3"),
+			OsString::from("4"),
+			OsString::from("--files=a"),
+			OsString::from("b"),
+			OsString::from("-s"),
+			OsString::from("5"),
+			OsString::from("6"),
+		];
+		let mut args = Argue::from(cli.into_iter())
+			.with_keywords([
+				KeyWord::KeyWithValue("--files"),
+				KeyWord::KeyWithValue("--size"),
+				KeyWord::KeyWithValue("-s"),
+			])
+			.with_require_equals()
+			.nargs("--size", 2)
+			.nargs("-s", 2)
+			.nargs_at_least("--files", 1);
+		assert_eq!(args.next(), Some(Argument::MissingValue("--size")));
+		assert_eq!(args.next(), Some(Argument::Other("1".to_owned())));
+		assert_eq!(args.next(), Some(Argument::Other("2".to_owned())));
+		assert_eq!(args.next(), Some(Argument::MissingValue("--size")));
+		assert_eq!(args.next(), Some(Argument::Other("4".to_owned())));
+		assert_eq!(args.next(), Some(Argument::KeyWithValues("--files", vec!["a".to_owned()])));
+		assert_eq!(args.next(), Some(Argument::Other("b".to_owned())));
+		assert_eq!(args.next(), Some(Argument::KeyWithValues("-s", vec!["5".to_owned(), "6".to_owned()])));
+		assert_eq!(args.next(), None);
+	}
+
+	#[test]
+	fn t_argue_help() {
+		let cli = [
+			OsString::from("--help"),
+			OsString::from("-v"),
+			OsString::from("help"),
+			OsString::from("help"),
+			OsString::from("-h=x"),
+			OsString::from("help"),
+		];
+		let keys = [KeyWord::Key("-v"), KeyWord::Command("build")];
+
+		// Without the flag, nothing special happens.
+		let mut args = Argue::from(cli.iter().cloned()).with_keywords(keys);
+		assert_eq!(args.next(), Some(Argument::Other("--help".to_owned())));
+
+		let mut args = Argue::from(cli.iter().cloned())
+			.with_keywords(keys)
+			.with_standard_help();
+		assert_eq!(args.next(), Some(Argument::Help { topic: None }));
+		assert_eq!(args.next(), Some(Argument::Key("-v")));
+		assert_eq!(args.next(), Some(Argument::Help { topic: None }));
+		assert_eq!(args.next(), Some(Argument::Help { topic: None }));
+		assert_eq!(args.next(), Some(Argument::UnexpectedValue("-h", "x".to_owned())));
+		assert_eq!(args.next(), Some(Argument::Help { topic: None }));
+		assert_eq!(args.next(), None);
+	}
+
+	#[test]
+	fn t_argue_end_passthrough() {
+		let cli = [
+			OsString::from("--"),
+			OsString::from("--help"),
+			OsString::from("help"),
+			OsString::from("-h"),
+			OsString::from("--version"),
+			OsString::from("--no-color"),
+			OsString::from("-"),
+			OsString::from("--plugins"),
+		];
+		let keys = [KeyWord::Key("--version"), KeyWord::Key("--plugins")];
+
+		// Nothing after the separator should be touched.
+		let mut args = Argue::from(cli.iter().cloned())
+			.with_keywords(keys)
+			.with_standard_help()
+			.with_color_flag()
+			.with_stdin()
+			.abort_on(["--version"], |_| panic!("Aborted after the separator!"))
+			.on_key("--plugins", |_| panic!("Called back after the separator!"));
+		assert_eq!(args.next(), Some(Argument::End(cli[1..].to_vec())));
+		assert_eq!(args.next(), None);
+		assert_eq!(args.color, ColorChoice::Auto);
+
+		// Unless we ask for it.
+		let mut args = Argue::from(cli.iter().take(7).cloned())
+			.with_keywords(keys)
+			.with_standard_help()
+			.with_color_flag()
+			.with_stdin()
+			.classify_end();
+		assert_eq!(args.next(), Some(Argument::End(Vec::new())));
+		assert_eq!(args.next(), Some(Argument::Help { topic: None }));
+		assert_eq!(args.next(), Some(Argument::Help { topic: None }));
+		assert_eq!(args.next(), Some(Argument::Help { topic: None }));
+		assert_eq!(args.next(), Some(Argument::Key("--version")));
+		assert_eq!(args.next(), Some(Argument::Stdin));
+		assert_eq!(args.next(), None);
+		assert_eq!(args.color, ColorChoice::Never);
+
+		// Literal separators take priority.
+		let mut args = Argue::from(cli.iter().take(2).cloned())
+			.with_standard_help()
+			.with_literal_separator()
+			.classify_end();
+		assert_eq!(args.next(), Some(Argument::Other("--".to_owned())));
+		assert_eq!(args.next(), Some(Argument::Help { topic: None }));
+		assert_eq!(args.next(), None);
+	}
+
+	#[test]
+	fn t_argue_end_exact() {
+		let tail = vec![
+			OsString::new(),
+			OsString::from("--"),
+			OsString::from(" -v "),
+			OsString::from("-"),
+			OsString::new(),
+			OsString::from("--"),
+		];
+
+		#[cfg(unix)]
+		let tail = {
+			use std::os::unix::ffi::OsStrExt;
+			let mut tail = tail;
+			tail.push(OsStr::from_bytes(b"--out=\xff").to_os_string());
+			tail
+		};
+
+		let mut cli = vec![OsString::new(), OsString::from("-v"), OsString::new()];
+		cli.push(OsString::from("--"));
+		cli.extend(tail.iter().cloned());
+
+		// Empties before the separator are skipped; everything after is kept
+		// exactly.
+		let mut args = Argue::from(cli.iter().cloned())
+			.with_keywords([KeyWord::Key("-v"), KeyWord::KeyWithValue("--out")])
+			.with_stdin();
+		assert_eq!(args.next(), Some(Argument::Key("-v")));
+		assert_eq!(args.next(), Some(Argument::End(tail.clone())));
+		assert_eq!(args.next(), None);
+
+		// Strict mode doesn't change that, but does override the things that
+		// would.
+		let mut args = Argue::from(cli.iter().cloned())
+			.with_keywords([KeyWord::Key("-v")])
+			.classify_end()
+			.with_strict_end();
+		assert_eq!(args.next(), Some(Argument::Key("-v")));
+		assert_eq!(args.next(), Some(Argument::End(tail)));
+		assert_eq!(args.next(), None);
+
+		let mut args = Argue::from([OsString::from("--")]).with_strict_end();
+		assert_eq!(args.next(), Some(Argument::End(Vec::new())));
+		assert_eq!(args.next(), None);
+	}
+
+	#[test]
+	fn t_argue_deferred_end() {
+		let cli = [
+			OsString::from("-v"),
+			OsString::from("--"),
+			OsString::from("-v"),
+			OsString::new(),
+			OsString::from("--"),
+		];
+		let tail = cli[2..].to_vec();
+
+		// Take the remainder ourselves, reusing a buffer.
+		let mut buf = vec![OsString::from("old")];
+		buf.clear();
+		let mut args = Argue::from(cli.iter().cloned())
+			.with_keywords([KeyWord::Key("-v")])
+			.classify_end()
+			.with_deferred_end();
+		assert_eq!(args.next(), Some(Argument::Key("-v")));
+		assert_eq!(args.next(), Some(Argument::End(Vec::new())));
+		args.end_into(&mut buf);
+		assert_eq!(buf, tail);
+		assert_eq!(args.next(), None);
+
+		// Or leave it be.
+		let mut args = Argue::from(cli.iter().cloned())
+			.with_keywords([KeyWord::Key("-v")])
+			.with_deferred_end();
+		assert_eq!(args.next(), Some(Argument::Key("-v")));
+		assert_eq!(args.next(), Some(Argument::End(Vec::new())));
+		assert_eq!(args.next(), Some(Argument::End(tail)));
+		assert_eq!(args.next(), None);
+
+		// Trailing separators still get a marker.
+		let mut args = Argue::from([OsString::from("--")]).with_deferred_end();
+		assert_eq!(args.next(), Some(Argument::End(Vec::new())));
+		assert_eq!(args.next(), None);
+
+		// Each line starts fresh.
+		let mut repl = Argue::from(Vec::<OsString>::new())
+			.with_keywords([KeyWord::Key("-v")])
+			.with_deferred_end();
+		assert_eq!(
+			repl.parse_line("-- -v"),
+			[Argument::End(Vec::new()), Argument::End(vec![OsString::from("-v")])],
+		);
+		assert_eq!(repl.parse_line("-v"), [Argument::Key("-v")]);
+	}
+
+	#[test]
+	fn t_argue_missing_value() {
+		let cli = [
+			OsString::from("-o"),
+			OsString::from("a.txt"),
+			OsString::from("--out"),
+			OsString::from("b.txt"),
+			OsString::from("--out"),
+		];
+		let keys = [
+			KeyWord::KeyWithValue("-o"),
+			KeyWord::KeyWithValue("--out"),
+		];
+
+		// The trailing key has no value.
+		let mut args = Argue::from(cli.iter().cloned()).with_keywords(keys);
+		assert_eq!(args.next(), Some(Argument::KeyWithValue("-o", "a.txt".to_owned())));
+		assert_eq!(args.next(), Some(Argument::KeyWithValue("--out", "b.txt".to_owned())));
+		assert_eq!(args.next(), Some(Argument::MissingValue("--out")));
+		assert_eq!(args.next(), None);
+
+		// Now long keys need an equal sign.
+		let mut args = Argue::from(cli.iter().cloned())
+			.with_keywords(keys)
+			.with_require_equals();
+		assert_eq!(args.next(), Some(Argument::KeyWithValue("-o", "a.txt".to_owned())));
+		assert_eq!(args.next(), Some(Argument::MissingValue("--out")));
+		assert_eq!(args.next(), Some(Argument::Other("b.txt".to_owned())));
+		assert_eq!(args.next(), Some(Argument::MissingValue("--out")));
+		assert_eq!(args.next(), None);
+	}
+
+	#[cfg(unix)]
+	#[test]
+	fn t_argue_invalid_value() {
+		use std::os::unix::ffi::OsStrExt;
+
+		let bad = |raw: &[u8]| OsStr::from_bytes(raw).to_os_string();
+		let mut args = Argue::from([
+			bad(b"--out=\xff.txt"),
+			bad(b"-o=\xff"),
+			bad(b"--color=\xff"),
+			bad(b"-v=\xff"),
+			bad(b"--nope=\xff"),
+			bad(b"\xff=--out"),
+			bad(b"-o"),
+			bad(b"\xff"),
+		])
+			.with_keywords([
+				KeyWord::Key("-v"),
+				KeyWord::KeyWithValue("--out"),
+				KeyWord::Alias("-o", "--out"),
+				KeyWord::KeyWithOptionalValue("--color"),
+			]);
+
+		assert_eq!(args.next(), Some(Argument::KeyWithInvalidValue("--out", bad(b"\xff.txt"))));
+		assert_eq!(args.next(), Some(Argument::KeyWithInvalidValue("--out", bad(b"\xff"))));
+		assert_eq!(args.next(), Some(Argument::KeyWithInvalidValue("--color", bad(b"\xff"))));
+
+		// Boolean and unknown keys are still just invalid.
+		assert_eq!(args.next(), Some(Argument::InvalidUtf8(bad(b"-v=\xff"))));
+		assert_eq!(args.next(), Some(Argument::InvalidUtf8(bad(b"--nope=\xff"))));
+		assert_eq!(args.next(), Some(Argument::InvalidUtf8(bad(b"\xff=--out"))));
+
+		// Separate values work too.
+		assert_eq!(args.next(), Some(Argument::KeyWithInvalidValue("--out", bad(b"\xff"))));
+		assert_eq!(args.next(), None);
+	}
+
+	#[cfg(windows)]
+	#[test]
+	fn t_argue_invalid_value() {
+		use std::os::windows::ffi::OsStringExt;
+
+		// Lone surrogates aren't valid UTF-16.
+		let bad = |prefix: &str| {
+			let mut raw: Vec<u16> = prefix.encode_utf16().collect();
+			raw.push(0xD800);
+			OsString::from_wide(&raw)
+		};
+		let mut args = Argue::from([bad("--out="), bad("-v=")])
+			.with_keywords([
+				KeyWord::Key("-v"),
+				KeyWord::KeyWithValue("--out"),
+			]);
+
+		assert_eq!(args.next(), Some(Argument::KeyWithInvalidValue("--out", bad(""))));
+		assert_eq!(args.next(), Some(Argument::InvalidUtf8(bad("-v="))));
+		assert_eq!(args.next(), None);
+	}
+
+	#[test]
+	fn t_argue_dash_values() {
+		let cli = [
+			OsString::from("--message"),
+			OsString::from("-n is not a flag"),
+			OsString::from("-m"),
+			OsString::from("-n"),
+			OsString::from("-m-n"),
+			OsString::from("-m=-n"),
+			OsString::from("--message=-n"),
+			OsString::from("--offset"),
+			OsString::from("-1"),
+		];
+		let keys = [
+			KeyWord::Key("-n"),
+			KeyWord::KeyWithValue("-m"),
+			KeyWord::KeyWithValue("--message"),
+			KeyWord::KeyWithValue("--offset"),
+		];
+
+		// By default, dash-leading values are always accepted.
+		let mut args = Argue::from(cli.iter().cloned()).with_keywords(keys);
+		assert_eq!(args.next(), Some(Argument::KeyWithValue("--message", "-n is not a flag".to_owned())));
+		assert_eq!(args.next(), Some(Argument::KeyWithValue("-m", "-n".to_owned())));
+		assert_eq!(args.next(), Some(Argument::KeyWithValue("-m", "-n".to_owned())));
+		assert_eq!(args.next(), Some(Argument::KeyWithValue("-m", "-n".to_owned())));
+		assert_eq!(args.next(), Some(Argument::KeyWithValue("--message", "-n".to_owned())));
+		assert_eq!(args.next(), Some(Argument::KeyWithValue("--offset", "-1".to_owned())));
+		assert_eq!(args.next(), None);
+
+		// Strict mode only affects the next-arg form.
+		let mut args = Argue::from(cli.iter().cloned())
+			.with_keywords(keys)
+			.with_strict_values();
+		assert_eq!(args.next(), Some(Argument::MissingValue("--message")));
+		assert_eq!(args.next(), Some(Argument::UnexpectedValue("-n", " is not a flag".to_owned())));
+		assert_eq!(args.next(), Some(Argument::MissingValue("-m")));
+		assert_eq!(args.next(), Some(Argument::Key("-n")));
+		assert_eq!(args.next(), Some(Argument::KeyWithValue("-m", "-n".to_owned())));
+		assert_eq!(args.next(), Some(Argument::KeyWithValue("-m", "-n".to_owned())));
+		assert_eq!(args.next(), Some(Argument::KeyWithValue("--message", "-n".to_owned())));
+		assert_eq!(args.next(), Some(Argument::MissingValue("--offset")));
+		assert_eq!(args.next(), Some(Argument::Other("-1".to_owned())));
+		assert_eq!(args.next(), None);
+
+		// Unless the keys are exempt.
+		let mut args = Argue::from(cli.iter().cloned())
+			.with_keywords(keys)
+			.with_strict_values()
+			.allow_dash_values(["--message", "--offset"]);
+		assert_eq!(args.next(), Some(Argument::KeyWithValue("--message", "-n is not a flag".to_owned())));
+		assert_eq!(args.next(), Some(Argument::MissingValue("-m")));
+		assert_eq!(args.next(), Some(Argument::Key("-n")));
+		assert_eq!(args.next(), Some(Argument::KeyWithValue("-m", "-n".to_owned())));
+		assert_eq!(args.next(), Some(Argument::KeyWithValue("-m", "-n".to_owned())));
+		assert_eq!(args.next(), Some(Argument::KeyWithValue("--message", "-n".to_owned())));
+		assert_eq!(args.next(), Some(Argument::KeyWithValue("--offset", "-1".to_owned())));
+		assert_eq!(args.next(), None);
+	}
+
+	#[test]
+	fn t_argue_raw_values() {
+		let keys = [
+			KeyWord::Key("-v"),
+			KeyWord::KeyWithValue("-o"),
+			KeyWord::KeyWithValue("--out"),
+			KeyWord::KeyWithValue("--name"),
+		];
+
+		let mut args = Argue::from([
+			OsString::from("-o"),
+			OsString::from("a.txt"),
+			OsString::from("-ob.txt"),
+			OsString::from("--out=c.txt"),
+			OsString::from("--out="),
+			OsString::from("--name=d"),
+			OsString::from("--out"),
+		].into_iter())
+			.with_keywords(keys)
+			.with_raw_values(["-o", "--out"]);
+		assert_eq!(args.next(), Some(Argument::KeyWithOsValue("-o", OsString::from("a.txt"))));
+		assert_eq!(args.next(), Some(Argument::KeyWithOsValue("-o", OsString::from("b.txt"))));
+		assert_eq!(args.next(), Some(Argument::KeyWithOsValue("--out", OsString::from("c.txt"))));
+		assert_eq!(args.next(), Some(Argument::KeyWithOsValue("--out", OsString::new())));
+		assert_eq!(args.next(), Some(Argument::KeyWithValue("--name", "d".to_owned())));
+		assert_eq!(args.next(), Some(Argument::MissingValue("--out")));
+		assert_eq!(args.next(), None);
+
+		// Strict and require-eq rules still apply.
+		let mut args = Argue::from([
+			OsString::from("-o"),
+			OsString::from("-v"),
+			OsString::from("--out"),
+			OsString::from("e.txt"),
+		].into_iter())
+			.with_keywords(keys)
+			.with_raw_values(["-o", "--out"])
+			.with_strict_values()
+			.with_require_equals();
+		assert_eq!(args.next(), Some(Argument::MissingValue("-o")));
+		assert_eq!(args.next(), Some(Argument::Key("-v")));
+		assert_eq!(args.next(), Some(Argument::MissingValue("--out")));
+		assert_eq!(args.next(), Some(Argument::Other("e.txt".to_owned())));
+		assert_eq!(args.next(), None);
+
+		// Invalid UTF-8 is no problem.
+		#[cfg(unix)]
+		{
+			use std::os::unix::ffi::OsStrExt;
+			let bad = std::ffi::OsStr::from_bytes(b"\xff.txt").to_os_string();
+			let mut raw = OsString::from("--out=");
+			raw.push(&bad);
+
+			let mut args = Argue::from([
+				OsString::from("-o"),
+				bad.clone(),
+				raw,
+			].into_iter())
+				.with_keywords(keys)
+				.with_raw_values(["-o", "--out"]);
+			assert_eq!(args.next(), Some(Argument::KeyWithOsValue("-o", bad.clone())));
+			assert_eq!(args.next(), Some(Argument::KeyWithOsValue("--out", bad)));
+			assert_eq!(args.next(), None);
+		}
+	}
+
+	#[test]
+	fn t_argue_verbatim_values() {
+		let cli = [
+			OsString::from("-DFOO=bar"),
+			OsString::from("-D"),
+			OsString::from("FOO=bar"),
+			OsString::from("-D=FOO"),
+			OsString::from("-D-x"),
+			OsString::from("-D="),
+			OsString::from("--define=FOO=bar"),
+			OsString::from("--define==FOO"),
+			OsString::from("-d=FOO"),
+		];
+		let keys = [
+			KeyWord::KeyWithValue("-D"),
+			KeyWord::KeyWithValue("--define"),
+			KeyWord::Alias("-d", "-D"),
+		];
+
+		// By default, a leading = is treated as a separator.
+		let mut args = Argue::from(cli.iter().cloned()).with_keywords(keys);
+		for expected in [
+			"FOO=bar", "FOO=bar", "FOO", "-x", "",
+		] {
+			assert_eq!(args.next(), Some(Argument::KeyWithValue("-D", expected.to_owned())));
+		}
+		assert_eq!(args.next(), Some(Argument::KeyWithValue("--define", "FOO=bar".to_owned())));
+		assert_eq!(args.next(), Some(Argument::KeyWithValue("--define", "=FOO".to_owned())));
+		assert_eq!(args.next(), Some(Argument::KeyWithValue("-D", "FOO".to_owned())));
+		assert_eq!(args.next(), None);
+
+		// Verbatim keeps the remainder as-is; long keys are unaffected.
+		let mut args = Argue::from(cli.iter().cloned())
+			.with_keywords(keys)
+			.with_verbatim_values(["-D", "--define"]);
+		for expected in [
+			"FOO=bar", "FOO=bar", "=FOO", "-x", "=",
+		] {
+			assert_eq!(args.next(), Some(Argument::KeyWithValue("-D", expected.to_owned())));
+		}
+		assert_eq!(args.next(), Some(Argument::KeyWithValue("--define", "FOO=bar".to_owned())));
+		assert_eq!(args.next(), Some(Argument::KeyWithValue("--define", "=FOO".to_owned())));
+		assert_eq!(args.next(), Some(Argument::KeyWithValue("-D", "=FOO".to_owned())));
+		assert_eq!(args.next(), None);
+	}
+
+	#[test]
+	fn t_argue_leading_commands() {
+		let cli = [
+			OsString::from("-v"),
+			OsString::from("b"),
+			OsString::from("file.txt"),
+			OsString::from("build"),
+			OsString::from("-v"),
+		];
+		let keys = [
+			KeyWord::Command("build"),
+			KeyWord::Alias("b", "build"),
+			KeyWord::Key("-v"),
+		];
+
+		// Commands are normally recognized anywhere.
+		let mut args = Argue::from(cli.iter().cloned()).with_keywords(keys);
+		assert_eq!(args.next(), Some(Argument::Key("-v")));
+		assert_eq!(args.next(), Some(Argument::Command("build")));
+		assert_eq!(args.next(), Some(Argument::Other("file.txt".to_owned())));
+		assert_eq!(args.next(), Some(Argument::Command("build")));
+		assert_eq!(args.next(), Some(Argument::Key("-v")));
+		assert_eq!(args.next(), None);
+
+		// But not here.
+		let mut args = Argue::from(cli.iter().cloned())
+			.with_keywords(keys)
+			.with_leading_commands()
+			.with_warnings();
+		assert_eq!(args.next(), Some(Argument::Key("-v")));
+		assert_eq!(args.next(), Some(Argument::Command("build")));
+		assert_eq!(args.next(), Some(Argument::Other("file.txt".to_owned())));
+		assert_eq!(args.next(), Some(Argument::Other("build".to_owned())));
+		assert_eq!(args.next(), Some(Argument::Key("-v")));
+		assert_eq!(args.next(), None);
+		assert_eq!(args.warnings(), [Warning::LateCommand("build"), Warning::Duplicate("-v")]);
+
+		// A positional up front rules them out entirely; demoted commands
+		// count as positionals for POSIX purposes too.
+		let mut args = Argue::from(cli[2..].iter().cloned())
+			.with_keywords(keys)
+			.with_leading_commands()
+			.with_posix();
+		assert_eq!(args.next(), Some(Argument::Other("file.txt".to_owned())));
+		assert_eq!(args.next(), Some(Argument::Other("build".to_owned())));
+		assert_eq!(args.next(), Some(Argument::Other("-v".to_owned())));
+		assert_eq!(args.next(), None);
+
+		// Each line is a fresh start.
+		let mut repl = Argue::from(Vec::new())
+			.with_keywords(keys)
+			.with_leading_commands();
+		assert_eq!(repl.parse_line("b x"), [Argument::Command("build"), Argument::Other("x".to_owned())]);
+		assert_eq!(repl.parse_line("build"), [Argument::Command("build")]);
+	}
+
+	#[test]
+	fn t_argue_from_pairs() {
+		let pair = |k: &str, v: Option<&str>| (k.to_owned(), v.map(str::to_owned));
+		let mut args = Argue::from_pairs([
+			pair("-D", Some("x=1")),
+			pair("-o", Some("=-v")),
+			pair("-o", None),
+			pair("-v", None),
+			pair("-v", Some("")),
+			pair("--color", Some("auto=yes")),
+			pair("-c", None),
+			pair("--size", Some("640")),
+			pair("build", Some("now")),
+			pair("build", None),
+			pair("--nope", Some("1")),
+		])
+			.with_keywords([
+				KeyWord::Command("build"),
+				KeyWord::Key("-v"),
+				KeyWord::KeyWithValue("-D"),
+				KeyWord::KeyWithValue("-o"),
+				KeyWord::KeyWithValue("--size"),
+				KeyWord::KeyWithOptionalValue("--color"),
+				KeyWord::Alias("-c", "--color"),
+			])
+			.with_verbatim_values(["-D"])
+			.with_strict_values()
+			.nargs("--size", 2);
+
+		assert_eq!(args.next(), Some(Argument::KeyWithValue("-D", "x=1".to_owned())));
+		assert_eq!(args.next(), Some(Argument::KeyWithValue("-o", "=-v".to_owned())));
+		assert_eq!(args.next(), Some(Argument::MissingValue("-o")));
+		assert_eq!(args.next(), Some(Argument::Key("-v")));
+		assert_eq!(args.next(), Some(Argument::UnexpectedValue("-v", String::new())));
+		assert_eq!(args.next(), Some(Argument::KeyWithValue("--color", "auto=yes".to_owned())));
+		assert_eq!(args.next(), Some(Argument::Key("--color")));
+		assert_eq!(args.next(), Some(Argument::MissingValue("--size")));
+		assert_eq!(args.next(), Some(Argument::Other("build=now".to_owned())));
+		assert_eq!(args.next(), Some(Argument::Command("build")));
+		assert_eq!(args.next(), Some(Argument::Other("--nope=1".to_owned())));
+		assert_eq!(args.next(), None);
+
+		// Children parse raw arguments normally.
+		let mut child = args.child([OsString::from("-o"), OsString::from("x")]);
+		assert_eq!(child.next(), Some(Argument::KeyWithValue("-o", "x".to_owned())));
+		assert_eq!(child.next(), None);
+
+		// Keys are never lexed: no separators, no splitting, no pulling.
+		let mut args = Argue::from_pairs([
+			pair("--", None),
+			pair("-v", None),
+			pair("--out=b", None),
+			pair("--out=b", Some("c")),
+			pair("-vo", Some("x")),
+			pair("--help", None),
+			pair("--out", Some("--")),
+			pair("a\0b", Some("c")),
+		])
+			.with_keywords([
+				KeyWord::Key("-v"),
+				KeyWord::KeyWithValue("--out"),
+				KeyWord::KeyWithValue("-o"),
+			])
+			.with_standard_help();
+		assert_eq!(args.next(), Some(Argument::Other("--".to_owned())));
+		assert_eq!(args.next(), Some(Argument::Key("-v")));
+		assert_eq!(args.next(), Some(Argument::Other("--out=b".to_owned())));
+		assert_eq!(args.next(), Some(Argument::Other("--out=b=c".to_owned())));
+		assert_eq!(args.next(), Some(Argument::Other("-vo=x".to_owned())));
+		assert_eq!(args.next(), Some(Argument::Help { topic: None }));
+		assert_eq!(args.next(), Some(Argument::KeyWithValue("--out", "--".to_owned())));
+		assert_eq!(args.next(), Some(Argument::Other("a\0b=c".to_owned())));
+		assert_eq!(args.next(), None);
+
+		// Leftovers come back as key=value.
+		let args = Argue::from_pairs([pair("-v", None), pair("--out", Some("b"))]);
+		assert_eq!(
+			args.into_end(),
+			[OsString::from("-v"), OsString::from("--out=b")],
+		);
+	}
+
+	#[test]
+	fn t_argue_map_arguments() {
+		/// # Lowercase Values.
+		fn lower(arg: Argument) -> Argument {
+			match arg {
+				Argument::KeyWithValue(k, v) => Argument::KeyWithValue(k, v.to_lowercase()),
+				arg => arg,
+			}
+		}
+
+		/// # Values to Keys.
+		fn unvalue(arg: Argument) -> Argument {
+			match arg {
+				Argument::KeyWithValue(k, v) if v == "off" => Argument::Key(k),
+				arg => arg,
+			}
+		}
+
+		let cli = [
+			OsString::from("--color=OFF"),
+			OsString::from("--color=Off"),
+			OsString::from("--color=Auto"),
+			OsString::from("--"),
+			OsString::from("--color=OFF"),
+		];
+
+		// Warnings see the transformed results.
+		let mut args = Argue::from(cli.iter().cloned())
+			.with_keywords([KeyWord::KeyWithOptionalValue("--color")])
+			.map_arguments(lower)
+			.map_arguments(unvalue)
+			.with_warnings();
+		assert_eq!(args.next(), Some(Argument::Key("--color")));
+		assert_eq!(args.next(), Some(Argument::Key("--color")));
+		assert_eq!(args.next(), Some(Argument::KeyWithValue("--color", "auto".to_owned())));
+		let Some(Argument::End(rest)) = args.next() else { panic!("Missing end."); };
+		assert_eq!(args.next(), None);
+		assert_eq!(args.warnings(), [Warning::Duplicate("--color")]);
+
+		// The transformations carry over to children.
+		let mut child = args.child(rest);
+		assert_eq!(child.next(), Some(Argument::Key("--color")));
+
+		// Order matters.
+		let mut args = Argue::from(cli.into_iter())
+			.with_keywords([KeyWord::KeyWithOptionalValue("--color")])
+			.map_arguments(unvalue)
+			.map_arguments(lower);
+		assert_eq!(args.next(), Some(Argument::KeyWithValue("--color", "off".to_owned())));
+	}
+
+	#[test]
+	fn t_argue_numeric_positionals() {
+		for v in ["-0", "-123", "-1.5", "-10.25"] { assert!(is_number(v.as_bytes()), "{v}"); }
+		for v in ["-", "1", "--1", "-1.", "-.5", "-1.2.3", "-1e5", "-1x"] {
+			assert!(! is_number(v.as_bytes()), "{v}");
+		}
+
+		let cli = [
+			OsString::from("-123"),
+			OsString::from("-o"),
+			OsString::from("-5"),
+			OsString::from("-1"),
+			OsString::from("-n"),
+			OsString::from("-2.5"),
+			OsString::from("-3"),
+		];
+		let keys = [
+			KeyWord::Key("-1"),
+			KeyWord::KeyWithValue("-o"),
+			KeyWord::KeyWithValue("-n"),
+		];
 
+		// Normally numbers are keys or key-like.
+		let mut args = Argue::from(cli.iter().cloned())
+			.with_keywords(keys)
+			.with_strict_values()
+			.with_diagnostics();
+		assert_eq!(
+			args.by_ref().collect::<Vec<_>>(),
+			[
+				Argument::UnexpectedValue("-1", "23".to_owned()),
+				Argument::MissingValue("-o"),
+				Argument::Other("-5".to_owned()),
+				Argument::Key("-1"),
+				Argument::MissingValue("-n"),
+				Argument::Other("-2.5".to_owned()),
+				Argument::Other("-3".to_owned()),
+			],
+		);
+		assert_eq!(args.finish().len(), 6);
 
-#[must_use]
-/// # CLI Argument Iterator.
-///
-/// Return an [`Argue`] iterator seeded with [`ArgsOs`], skipping the first
-/// entry — the script path — since that isn't super useful.
-///
-/// (If you disagree on that last point, create your instance using
-/// `Argue::from(std::env::args_os())` instead.)
-pub fn args() -> Argue<Skip<ArgsOs>> {
-	Argue {
-		iter: std::env::args_os().skip(1),
-		keys: BTreeSet::new(),
+		// But not here.
+		let mut args = Argue::from(cli.iter().cloned())
+			.with_keywords(keys)
+			.with_strict_values()
+			.with_diagnostics()
+			.numeric_positionals()
+			.nargs("-n", 2);
+		assert_eq!(
+			args.by_ref().collect::<Vec<_>>(),
+			[
+				Argument::Other("-123".to_owned()),
+				Argument::KeyWithValue("-o", "-5".to_owned()),
+				Argument::Other("-1".to_owned()),
+				Argument::KeyWithValues("-n", vec!["-2.5".to_owned(), "-3".to_owned()]),
+			],
+		);
+		assert!(args.finish().is_empty());
+
+		// They're positional for POSIX purposes too.
+		let mut args = Argue::from(cli.iter().cloned())
+			.with_keywords(keys)
+			.numeric_positionals()
+			.with_posix();
+		assert_eq!(args.nth(1), Some(Argument::Other("-o".to_owned())));
 	}
-}
 
+	#[test]
+	fn t_argue_prefix() {
+		let mut args = Argue::from([
+			OsString::from("-O2"),
+			OsString::from("-O"),
+			OsString::from("3"),
+			OsString::from("-Wall"),
+			OsString::from("-Wl,-rpath=x"),
+			OsString::from("-v"),
+			OsString::from("-o"),
+			OsString::from("-Ofast"),
+		].into_iter())
+			.with_keywords([
+				KeyWord::Prefix("-O"),
+				KeyWord::Prefix("-W"),
+				KeyWord::Key("-v"),
+				KeyWord::KeyWithValue("-o"),
+			])
+			.with_diagnostics();
+		assert_eq!(
+			args.by_ref().collect::<Vec<_>>(),
+			[
+				Argument::KeyWithValue("-O", "2".to_owned()),
+				Argument::Key("-O"),
+				Argument::Other("3".to_owned()),
+				Argument::KeyWithValue("-W", "all".to_owned()),
+				Argument::KeyWithValue("-W", "l,-rpath=x".to_owned()),
+				Argument::Key("-v"),
+				// Prefixed values are still values.
+				Argument::KeyWithValue("-o", "-Ofast".to_owned()),
+			],
+		);
+		assert!(args.finish().is_empty());
 
+		// And they survive the round trip.
+		let keys = [KeyWord::Prefix("-O"), KeyWord::Prefix("--opt")];
+		assert_eq!(
+			crate::roundtrip(
+				&[OsString::from("-O2"), OsString::from("--opt=x"), OsString::from("-O")],
+				&keys,
+			),
+			[OsString::from("-O2"), OsString::from("--opt=x"), OsString::from("-O")],
+		);
+		assert_eq!(crate::selftest(&keys), Ok(8));
 
-#[cfg(test)]
-mod test {
-	use super::*;
-	use std::ffi::OsString;
+		// Pair values are taken as-is, equal signs and all.
+		let pair = |k: &str, v: Option<&str>| (k.to_owned(), v.map(str::to_owned));
+		let args = Argue::from_pairs([
+			pair("-O", Some("2")),
+			pair("-O", Some("=3")),
+			pair("--opt", Some("x")),
+			pair("-O", None),
+			pair("-O2", None),
+		])
+			.with_keywords(keys);
+		assert_eq!(
+			args.collect::<Vec<_>>(),
+			[
+				Argument::KeyWithValue("-O", "2".to_owned()),
+				Argument::KeyWithValue("-O", "=3".to_owned()),
+				Argument::KeyWithValue("--opt", "x".to_owned()),
+				Argument::Key("-O"),
+				// Pair keys are matched exactly.
+				Argument::Other("-O2".to_owned()),
+			],
+		);
+	}
 
 	#[test]
-	fn t_argue() {
-		let mut cli = vec![
-			OsString::from(""),
-			OsString::from("-s"),
-			OsString::from("--long"),
-			OsString::from("-t2"),
-			OsString::from("--m=yar"),
-			OsString::from("--n"),
-			OsString::from("yar"),
-			OsString::from("-u"),
-			OsString::from("2"),
-			OsString::from("/foo/bar"),
+	fn t_argue_command_policies() {
+		/// # Parse With Policy.
+		fn parse(cli: &[&str], policy: fn(Argue<std::vec::IntoIter<OsString>>) -> Argue<std::vec::IntoIter<OsString>>)
+		-> Vec<Argument> {
+			let args = Argue::from(cli.iter().map(OsString::from).collect::<Vec<_>>())
+				.with_keywords([
+					KeyWord::Command("build"),
+					KeyWord::Command("2048"),
+					KeyWord::Key("-v"),
+					KeyWord::KeyWithValue("-o"),
+				])
+				.with_subcommands("build", [KeyWord::Command("all")]);
+			policy(args).collect()
+		}
+
+		let cmd = Argument::Command;
+		let other = |s: &str| Argument::Other(s.to_owned());
+
+		// By default, commands are matched anywhere, numbers included, but
+		// not as values.
+		let cli = ["-v", "build", "file.txt", "2048", "-o", "build", "all"];
+		assert_eq!(
+			parse(&cli, |a| a),
+			[
+				Argument::Key("-v"), cmd("build"), other("file.txt"), cmd("2048"),
+				Argument::KeyWithValue("-o", "build".to_owned()), cmd("all"),
+			],
+		);
+
+		// First position only, per level.
+		assert_eq!(
+			parse(&cli, Argue::with_first_commands),
+			[
+				Argument::Key("-v"), other("build"), other("file.txt"), other("2048"),
+				Argument::KeyWithValue("-o", "build".to_owned()), other("all"),
+			],
+		);
+		assert_eq!(
+			parse(&["build", "all", "2048", "-v"], Argue::with_first_commands),
+			[cmd("build"), cmd("all"), other("2048"), Argument::Key("-v")],
+		);
+		assert_eq!(
+			parse(&["build", "-v", "all"], Argue::with_first_commands),
+			[cmd("build"), Argument::Key("-v"), other("all")],
+		);
+
+		// Never after a key, but positionals are fine.
+		assert_eq!(
+			parse(&cli, Argue::with_commands_before_keys),
+			[
+				Argument::Key("-v"), other("build"), other("file.txt"), other("2048"),
+				Argument::KeyWithValue("-o", "build".to_owned()), other("all"),
+			],
+		);
+		assert_eq!(
+			parse(&["file.txt", "build", "all", "-v", "2048"], Argue::with_commands_before_keys),
+			[other("file.txt"), cmd("build"), cmd("all"), Argument::Key("-v"), other("2048")],
+		);
+
+		// Policies stack.
+		assert_eq!(
+			parse(
+				&["build", "2048", "-v"],
+				|a| a.with_leading_commands().with_commands_before_keys(),
+			),
+			[cmd("build"), other("2048"), Argument::Key("-v")],
+		);
+
+		// Demotions are warned about.
+		let mut args = Argue::from([OsString::from("-v"), OsString::from("build")].into_iter())
+			.with_keywords([KeyWord::Command("build"), KeyWord::Key("-v")])
+			.with_first_commands()
+			.with_warnings();
+		assert_eq!(args.by_ref().count(), 2);
+		assert_eq!(args.warnings(), [Warning::LateCommand("build")]);
+
+		// Children and lines start fresh.
+		let child = args.child([OsString::from("build")]);
+		assert_eq!(child.collect::<Vec<_>>(), [cmd("build")]);
+		assert_eq!(args.parse_line("-v build"), [Argument::Key("-v"), other("build")]);
+		assert_eq!(args.parse_line("build"), [cmd("build")]);
+	}
+
+	#[test]
+	fn t_argue_subcommands() {
+		let cli = [
+			OsString::from("remote"),
+			OsString::from("add"),
+			OsString::from("-f"),
+			OsString::from("origin"),
+			OsString::from("rm"),
+		];
+
+		let mut args = Argue::from(cli.iter().cloned())
+			.with_keywords([KeyWord::Command("remote"), KeyWord::Command("stash")])
+			.with_subcommands("remote", [KeyWord::Command("add"), KeyWord::Command("rm")])
+			.with_subcommands("add", [KeyWord::Key("-f")])
+			.with_subcommands("stash", [KeyWord::Command("origin")])
+			.with_leading_commands();
+		assert!(args.command_path().is_empty());
+		assert_eq!(args.next(), Some(Argument::Command("remote")));
+		assert_eq!(args.command_path(), ["remote"]);
+		assert_eq!(args.next(), Some(Argument::Command("add")));
+		assert_eq!(args.command_path(), ["remote", "add"]);
+		assert_eq!(args.next(), Some(Argument::Key("-f")));
+		assert_eq!(args.next(), Some(Argument::Other("origin".to_owned())));
+		assert_eq!(args.next(), Some(Argument::Other("rm".to_owned())));
+		assert_eq!(args.next(), None);
+		assert_eq!(args.command_path(), ["remote", "add"]);
+
+		// Without the leading restriction, in-scope commands are found
+		// anywhere.
+		let mut args = Argue::from(cli.iter().cloned())
+			.with_keywords([KeyWord::Command("remote")])
+			.with_subcommands("remote", [KeyWord::Command("add"), KeyWord::Command("rm")]);
+		assert_eq!(args.next(), Some(Argument::Command("remote")));
+		assert_eq!(args.next(), Some(Argument::Command("add")));
+		assert_eq!(args.next(), Some(Argument::Other("-f".to_owned())));
+		assert_eq!(args.next(), Some(Argument::Other("origin".to_owned())));
+		assert_eq!(args.next(), Some(Argument::Command("rm")));
+		assert_eq!(args.next(), None);
+		assert_eq!(args.command_path(), ["remote", "add", "rm"]);
+	}
+
+	#[test]
+	fn t_argument_os_str() {
+		let mut raw = vec![OsString::from("a.txt"), OsString::from("-v")];
+		#[cfg(unix)]
+		{
+			use std::os::unix::ffi::OsStrExt;
+			raw.push(OsStr::from_bytes(b"b\xff.txt").to_os_string());
+		}
+		#[cfg(windows)]
+		{
+			use std::os::windows::ffi::OsStringExt;
+			raw.push(OsString::from_wide(&[u16::from(b'b'), 0xD800, u16::from(b'x')]));
+		}
+
+		let out: Vec<OsString> = Argue::from(raw.clone().into_iter())
+			.with_keywords([KeyWord::Key("-v")])
+			.filter_map(Argument::into_os_string)
+			.collect();
+		raw.remove(1);
+		assert_eq!(out, raw);
+	}
+
+	#[test]
+	fn t_argument_paths() {
+		let raw = vec![
+			OsString::from("dir/a.txt"),
+			OsString::from("-v"),
+			OsString::from("b.TXT"),
+			OsString::from("dir/"),
+			OsString::from(".txt"),
+		];
+
+		#[cfg(unix)]
+		let raw = {
+			use std::os::unix::ffi::OsStrExt;
+			let mut raw = raw;
+			raw.push(OsStr::from_bytes(b"dir/c\xff.txt").to_os_string());
+			raw
+		};
+
+		let args: Vec<Argument> = Argue::from(raw.into_iter())
+			.with_keywords([KeyWord::Key("-v")])
+			.collect();
+		let txt: Vec<&OsStr> = args.iter()
+			.filter(|a| a.ends_with(".txt"))
+			.filter_map(Argument::file_name)
+			.collect();
+
+		#[cfg(unix)]
+		{
+			use std::os::unix::ffi::OsStrExt;
+			assert_eq!(
+				txt,
+				[OsStr::new("a.txt"), OsStr::new(".txt"), OsStr::from_bytes(b"c\xff.txt")],
+			);
+		}
+		#[cfg(not(unix))]
+		assert_eq!(txt, [OsStr::new("a.txt"), OsStr::new(".txt")]);
+
+		assert_eq!(args[2].file_name(), Some(OsStr::new("b.TXT")));
+		assert_eq!(args[3].file_name(), Some(OsStr::new("dir")));
+		assert!(args[1].file_name().is_none());
+		assert!(! args[1].ends_with("v"));
+	}
+
+	#[test]
+	fn t_argue_namespace() {
+		let mut args = Argue::from([
+			OsString::from("--x-child-"),
+			OsString::from("--x-child-help"),
+			OsString::from("--x-child-v"),
+			OsString::from("--y-q"),
 			OsString::from("--"),
+			OsString::from("--y-q"),
+		])
+			.with_keywords([KeyWord::Key("--x-child-help")])
+			.with_namespace("--x-child-")
+			.with_namespace("--y-");
+
+		// Too short!
+		assert_eq!(args.next(), Some(Argument::Other("--x-child-".to_owned())));
+
+		// Keywords take priority.
+		assert_eq!(args.next(), Some(Argument::Key("--x-child-help")));
+
+		assert_eq!(args.next(), Some(Argument::Namespaced("--x-child-", "--v".to_owned())));
+		assert_eq!(args.next(), Some(Argument::Namespaced("--y-", "--q".to_owned())));
+		assert_eq!(args.next(), Some(Argument::End(vec![OsString::from("--y-q")])));
+		assert_eq!(args.next(), None);
+	}
+
+	#[test]
+	fn t_argue_optional_value() {
+		let cli = [
+			OsString::from("-c"),
+			OsString::from("--color"),
+			OsString::from("auto"),
+			OsString::from("-calways"),
+			OsString::from("-c=never"),
+			OsString::from("--color=always"),
+			OsString::from("--color="),
 		];
+		let mut args = Argue::from(cli.into_iter())
+			.with_keywords(KeyWord::pair("-c", "--color", false).unwrap())
+			.with_keywords([KeyWord::KeyWithOptionalValue("--color")]);
+		assert_eq!(args.next(), Some(Argument::Key("--color")));
+		assert_eq!(args.next(), Some(Argument::Key("--color")));
+		assert_eq!(args.next(), Some(Argument::Other("auto".to_owned())));
+		assert_eq!(args.next(), Some(Argument::KeyWithValue("--color", "always".to_owned())));
+		assert_eq!(args.next(), Some(Argument::KeyWithValue("--color", "never".to_owned())));
+		assert_eq!(args.next(), Some(Argument::KeyWithValue("--color", "always".to_owned())));
+		assert_eq!(args.next(), Some(Argument::KeyWithValue("--color", String::new())));
+		assert_eq!(args.next(), None);
+	}
 
-		// Without keywords, everything should turn up other.
-		let mut args = Argue::from(cli.iter().cloned());
-		assert_eq!(args.next(), Some(Argument::Other("-s".to_owned())));
-		assert_eq!(args.next(), Some(Argument::Other("--long".to_owned())));
-		assert_eq!(args.next(), Some(Argument::Other("-t2".to_owned())));
-		assert_eq!(args.next(), Some(Argument::Other("--m=yar".to_owned())));
-		assert_eq!(args.next(), Some(Argument::Other("--n".to_owned())));
-		assert_eq!(args.next(), Some(Argument::Other("yar".to_owned())));
-		assert_eq!(args.next(), Some(Argument::Other("-u".to_owned())));
-		assert_eq!(args.next(), Some(Argument::Other("2".to_owned())));
-		assert_eq!(args.next(), Some(Argument::Other("/foo/bar".to_owned())));
-		assert_eq!(args.next(), None); // Without trailing arguments, the end is noned.
+	#[test]
+	fn t_argue_partition_keys() {
+		let cli = [
+			OsString::from("--config=a.toml"),
+			OsString::from("build"),
+			OsString::from("file.txt"),
+			OsString::from("-v"),
+			OsString::from("--release"),
+			OsString::from("--config"),
+			OsString::from("b.toml"),
+			OsString::from("--"),
+			OsString::from("-v"),
+		];
 
-		// Try again with some keywords.
-		args = Argue::from(cli.iter().cloned())
+		let (globals, args) = Argue::from(cli.into_iter())
 			.with_keywords([
-				KeyWord::Key("-s"),
-				KeyWord::Key("--long"),
-				KeyWord::KeyWithValue("-t"),
-				KeyWord::KeyWithValue("--m"),
-				KeyWord::KeyWithValue("--n"),
-				KeyWord::KeyWithValue("-u"),
-			]);
-		assert_eq!(args.next(), Some(Argument::Key("-s")));
-		assert_eq!(args.next(), Some(Argument::Key("--long")));
-		assert_eq!(args.next(), Some(Argument::KeyWithValue("-t", "2".to_owned())));
-		assert_eq!(args.next(), Some(Argument::KeyWithValue("--m", "yar".to_owned())));
-		assert_eq!(args.next(), Some(Argument::KeyWithValue("--n", "yar".to_owned())));
-		assert_eq!(args.next(), Some(Argument::KeyWithValue("-u", "2".to_owned())));
-		assert_eq!(args.next(), Some(Argument::Other("/foo/bar".to_owned())));
-		assert_eq!(args.next(), None); // Without trailing arguments, the end is noned.
+				KeyWord::Command("build"),
+				KeyWord::Key("--release"),
+				KeyWord::Key("-v"),
+				KeyWord::KeyWithValue("--config"),
+			])
+			.partition_keys(["-v", "--config", "build"]);
 
-		// Add some trailing arguments for good measure.
-		cli.push(OsString::from("Björk"));
-		cli.push(OsString::from("is"));
-		cli.push(OsString::from("best"));
+		assert_eq!(
+			globals,
+			[
+				Argument::KeyWithValue("--config", "a.toml".to_owned()),
+				Argument::Key("-v"),
+				Argument::KeyWithValue("--config", "b.toml".to_owned()),
+			],
+		);
+		assert_eq!(
+			args.collect::<Vec<_>>(),
+			[
+				Argument::Command("build"), // Commands aren't keys.
+				Argument::Other("file.txt".to_owned()),
+				Argument::Key("--release"),
+				Argument::End(vec![OsString::from("-v")]),
+			],
+		);
 
-		args = Argue::from(cli.iter().cloned())
-			.with_keywords([
-				KeyWord::Key("-s"),
-				KeyWord::Key("--long"),
-				KeyWord::KeyWithValue("-t"),
-				KeyWord::KeyWithValue("--m"),
-				KeyWord::KeyWithValue("--n"),
-				KeyWord::KeyWithValue("-u"),
-			]);
-		assert_eq!(args.next(), Some(Argument::Key("-s")));
-		assert_eq!(args.next(), Some(Argument::Key("--long")));
-		assert_eq!(args.next(), Some(Argument::KeyWithValue("-t", "2".to_owned())));
-		assert_eq!(args.next(), Some(Argument::KeyWithValue("--m", "yar".to_owned())));
-		assert_eq!(args.next(), Some(Argument::KeyWithValue("--n", "yar".to_owned())));
-		assert_eq!(args.next(), Some(Argument::KeyWithValue("-u", "2".to_owned())));
-		assert_eq!(args.next(), Some(Argument::Other("/foo/bar".to_owned())));
-		assert_eq!(args.next(), Some(Argument::End(vec![
-			OsString::from("Björk"),
-			OsString::from("is"),
-			OsString::from("best"),
-		])));
+		// Malformed globals should be pulled out too.
+		let keys = [KeyWord::Command("build"), KeyWord::KeyWithValue("--config")];
+		let (globals, args) = Argue::from([
+			OsString::from("build"),
+			OsString::from("--config"),
+		])
+			.with_keywords(keys)
+			.with_strict_values()
+			.partition_keys(["--config"]);
+		assert_eq!(globals, [Argument::MissingValue("--config")]);
+		assert_eq!(args.collect::<Vec<_>>(), [Argument::Command("build")]);
+
+		let (globals, args) = Argue::from([
+			OsString::from("--config=abcdef"),
+			OsString::from("build"),
+		])
+			.with_keywords(keys)
+			.with_value_limit(2)
+			.partition_keys(["--config"]);
+		assert_eq!(globals, [Argument::ValueTooLong("--config", 6)]);
+		assert_eq!(args.collect::<Vec<_>>(), [Argument::Command("build")]);
+	}
+
+	#[cfg(unix)]
+	#[test]
+	fn t_argue_partition_keys_invalid() {
+		use std::os::unix::ffi::OsStrExt;
+
+		let bad = |raw: &[u8]| OsStr::from_bytes(raw).to_os_string();
+		let (globals, args) = Argue::from([
+			OsString::from("build"),
+			bad(b"--config=\xff"),
+		])
+			.with_keywords([KeyWord::Command("build"), KeyWord::KeyWithValue("--config")])
+			.partition_keys(["--config"]);
+		assert_eq!(globals, [Argument::KeyWithInvalidValue("--config", bad(b"\xff"))]);
+		assert_eq!(args.collect::<Vec<_>>(), [Argument::Command("build")]);
+	}
+
+	#[test]
+	fn t_argue_posix() {
+		let cli = [
+			OsString::from("-a"),
+			OsString::from("build"),
+			OsString::from("-b"),
+			OsString::from("file.txt"),
+			OsString::from(""),
+			OsString::from("-a"),
+			OsString::from("--"),
+			OsString::from("-b"),
+		];
+		let keys = [
+			KeyWord::Key("-a"),
+			KeyWord::Key("-b"),
+			KeyWord::Command("build"),
+		];
+
+		// Normally keys are matched anywhere.
+		let mut args = Argue::from(cli.iter().cloned()).with_keywords(keys);
+		assert_eq!(args.next(), Some(Argument::Key("-a")));
+		assert_eq!(args.next(), Some(Argument::Command("build")));
+		assert_eq!(args.next(), Some(Argument::Key("-b")));
+		assert_eq!(args.next(), Some(Argument::Other("file.txt".to_owned())));
+		assert_eq!(args.next(), Some(Argument::Key("-a")));
+		assert_eq!(args.next(), Some(Argument::End(vec![OsString::from("-b")])));
 		assert_eq!(args.next(), None);
 
-		// Shorten the test so we can focus on key types.
-		cli.truncate(0);
-		cli.push(OsString::from("-t2"));
-		cli.push(OsString::from("--m=yar"));
+		// In POSIX mode, parsing stops at the first positional.
+		let mut args = Argue::from(cli.iter().cloned()).with_keywords(keys).with_posix();
+		assert_eq!(args.next(), Some(Argument::Key("-a")));
+		assert_eq!(args.next(), Some(Argument::Command("build")));
+		assert_eq!(args.next(), Some(Argument::Key("-b")));
+		assert_eq!(args.next(), Some(Argument::Other("file.txt".to_owned())));
+		assert_eq!(args.next(), Some(Argument::Other("-a".to_owned())));
+		assert_eq!(args.next(), Some(Argument::Other("--".to_owned())));
+		assert_eq!(args.next(), Some(Argument::Other("-b".to_owned())));
+		assert_eq!(args.next(), None);
+	}
 
-		// As before.
-		args = Argue::from(cli.iter().cloned())
+	#[test]
+	fn t_argue_verify_keywords() {
+		let args = Argue::from(Vec::<OsString>::new())
 			.with_keywords([
-				KeyWord::Key("--long"), // Unused.
-				KeyWord::KeyWithValue("-t"),
-				KeyWord::KeyWithValue("--m"),
-			]);
-		assert_eq!(args.next(), Some(Argument::KeyWithValue("-t", "2".to_owned())));
-		assert_eq!(args.next(), Some(Argument::KeyWithValue("--m", "yar".to_owned())));
-		assert_eq!(args.next(), None);
+				KeyWord::Key("--x-help"),
+				KeyWord::KeyWithValue("--out"),
+				KeyWord::Alias("-o", "--out"),
+				KeyWord::Alias("-p", "-o"),
+				KeyWord::Key("-v"),
+			])
+			.with_namespace("--x-")
+			.allow_dash_values(["--out", "-v"]);
 
-		// The values should get dropped for booleans.
-		args = Argue::from(cli.iter().cloned())
+		assert_eq!(
+			args.verify_keywords(),
+			[
+				"Key --x-help shadows namespace --x-.",
+				"Alias -p points to another alias (-o).",
+				"Dash values allowed for -v, but it doesn't take a value.",
+			],
+		);
+	}
+
+	#[test]
+	fn t_shell_words() {
+		for (raw, expected) in [
+			("", &[][..]),
+			("  -q   --threads 4 ", &["-q", "--threads", "4"]),
+			("'a b' \"c d\"", &["a b", "c d"]),
+			("'' \"\"", &["", ""]),
+			("a\\ b", &["a b"]),
+			("\"a\\\"b\\n\"", &["a\"b\\n"]),
+			("'a\\b'", &["a\\b"]),
+			("x'y'\"z\"", &["xyz"]),
+			("'unterminated", &["unterminated"]),
+		] {
+			assert_eq!(shell_words(raw), expected, "Failed to split {raw:?}.");
+		}
+	}
+
+	#[test]
+	fn t_argue_unexpected_value() {
+		let cli = [
+			OsString::from("--verbose=yes"),
+			OsString::from("--verbose="),
+			OsString::from("-v=1"),
+			OsString::from("--verbose"),
+			OsString::from("-v"),
+		];
+		let mut args = Argue::from(cli.into_iter())
 			.with_keywords([
-				KeyWord::Key("-t"),
-				KeyWord::Key("--m"),
+				KeyWord::Key("-v"),
+				KeyWord::Key("--verbose"),
 			]);
-		assert_eq!(args.next(), Some(Argument::Key("-t")));
-		assert_eq!(args.next(), Some(Argument::Key("--m")));
+		assert_eq!(args.next(), Some(Argument::UnexpectedValue("--verbose", "yes".to_owned())));
+		assert_eq!(args.next(), Some(Argument::UnexpectedValue("--verbose", String::new())));
+		assert_eq!(args.next(), Some(Argument::UnexpectedValue("-v", "1".to_owned())));
+		assert_eq!(args.next(), Some(Argument::Key("--verbose")));
+		assert_eq!(args.next(), Some(Argument::Key("-v")));
 		assert_eq!(args.next(), None);
 	}
 
@@ -450,4 +5188,329 @@ mod test {
 		assert!(! matches!(key, KeyWord::Key("-h")));
 		assert!(matches!(key, KeyWord::KeyWithValue("-h")));
 	}
+
+	#[test]
+	fn t_argue_try_keywords() {
+		let args = || Argue::from(Vec::<OsString>::new())
+			.try_with_keywords([
+				KeyWord::Key("-h"),
+				KeyWord::Key("-h"),
+				KeyWord::Alias("-v", "--verbose"),
+				KeyWord::Alias("-v", "--verbose"),
+			])
+			.expect("Exact duplicates are fine.");
+
+		// Variant changes are not.
+		assert!(matches!(
+			args().try_with_keywords([KeyWord::KeyWithValue("-h")]),
+			Err(KeyWord::KeyWithValue("-h")),
+		));
+		assert!(matches!(
+			args().try_with_keywords([KeyWord::Command("-h")]),
+			Err(KeyWord::Command("-h")),
+		));
+		assert!(matches!(
+			args().try_with_keywords([KeyWord::Alias("-v", "--very")]),
+			Err(KeyWord::Alias("-v", "--very")),
+		));
+
+		// Conflicts within the same batch count too.
+		assert!(Argue::from(Vec::<OsString>::new())
+			.try_with_keywords([KeyWord::Key("-x"), KeyWord::KeyWithValue("-x")])
+			.is_err());
+	}
+
+	#[test]
+	fn t_env_source() {
+		/// # Fake Environment.
+		fn fake(key: &str) -> Option<OsString> {
+			match key {
+				"FLAGS" => Some(OsString::from("-v 'a b'")),
+				"POSIXLY_CORRECT" => Some(OsString::new()),
+				_ => None,
+			}
+		}
+
+		let args = Argue::from([OsString::from("c"), OsString::from("-v")].into_iter())
+			.with_keywords([KeyWord::Key("-v")])
+			.with_env_source(fake)
+			.with_env_args("FLAGS")
+			.with_env_args("NOPE")
+			.with_posixly_correct();
+
+		// The child should inherit the source.
+		assert_eq!(
+			args.child(Vec::<OsString>::new()).with_env_args("FLAGS").collect::<Vec<_>>(),
+			[Argument::Key("-v"), Argument::Other("a b".to_owned())],
+		);
+
+		assert_eq!(
+			args.collect::<Vec<_>>(),
+			[
+				Argument::Key("-v"),
+				Argument::Other("a b".to_owned()),
+				Argument::Other("c".to_owned()),
+				Argument::Other("-v".to_owned()), // POSIX!
+			],
+		);
+	}
+
+	#[test]
+	fn t_command_payloads() {
+		let cli = [
+			OsString::from("remote"),
+			OsString::from("add"),
+			OsString::from("-f"),
+			OsString::from("origin"),
+			OsString::from("-v"),
+			OsString::from("show"),
+			OsString::from("remote"),
+			OsString::from("--"),
+			OsString::from("x"),
+		];
+		let keys = [KeyWord::Command("remote"), KeyWord::Command("show"), KeyWord::Key("-v")];
+
+		// Normal.
+		let args = Argue::from(cli.clone().into_iter()).with_keywords(keys);
+		assert_eq!(
+			args.collect::<Vec<_>>(),
+			[
+				Argument::Command("remote"),
+				Argument::Other("add".to_owned()),
+				Argument::Other("-f".to_owned()),
+				Argument::Other("origin".to_owned()),
+				Argument::Key("-v"),
+				Argument::Command("show"),
+				Argument::Command("remote"),
+				Argument::End(vec![OsString::from("x")]),
+			],
+		);
+
+		// Greedy.
+		let args = Argue::from(cli.into_iter())
+			.with_keywords(keys)
+			.with_command_payloads();
+		assert_eq!(
+			args.collect::<Vec<_>>(),
+			[
+				Argument::CommandWith("remote", vec!["add".to_owned(), "-f".to_owned(), "origin".to_owned()]),
+				Argument::Key("-v"),
+				Argument::CommandWith("show", Vec::new()),
+				Argument::CommandWith("remote", Vec::new()),
+				Argument::End(vec![OsString::from("x")]),
+			],
+		);
+	}
+
+	#[test]
+	fn t_extend() {
+		let mut args = Argue::from([OsString::from("-o")].into_iter())
+			.with_keywords([KeyWord::KeyWithValue("-o"), KeyWord::Key("-v")])
+			.with_posix();
+
+		// Values can be satisfied by later batches.
+		args.extend([OsString::from("a")]);
+		assert_eq!(args.size_hint(), (0, Some(2)));
+		assert_eq!(args.next(), Some(Argument::KeyWithValue("-o", "a".to_owned())));
+		assert_eq!(args.next(), None);
+
+		// State carries over too.
+		args.extend([OsString::from("b"), OsString::from("-v")]);
+		assert_eq!(args.next(), Some(Argument::Other("b".to_owned())));
+		assert_eq!(args.next(), Some(Argument::Other("-v".to_owned()))); // POSIX!
+		assert_eq!(args.next(), None);
+
+		// Ends gobble up everything available at the time.
+		let mut args = Argue::from([OsString::from("--")].into_iter());
+		args.extend([OsString::from("a")]);
+		assert_eq!(args.next(), Some(Argument::End(vec![OsString::from("a")])));
+		args.extend([OsString::from("b")]);
+		assert_eq!(args.next(), Some(Argument::Other("b".to_owned())));
+	}
+
+	#[test]
+	fn t_unfused() {
+		/// # Unfused Source.
+		///
+		/// This returns `None` every other call.
+		struct Unfused(usize);
+
+		impl Iterator for Unfused {
+			type Item = OsString;
+
+			fn next(&mut self) -> Option<Self::Item> {
+				self.0 += 1;
+				if self.0 % 2 == 0 { None }
+				else { Some(OsString::from(self.0.to_string())) }
+			}
+		}
+
+		// Once the source returns None, it should be left alone.
+		let mut args = Argue::from(Unfused(0));
+		assert_eq!(args.next(), Some(Argument::Other("1".to_owned())));
+		assert_eq!(args.next(), None);
+		assert_eq!(args.next(), None);
+		assert_eq!(args.size_hint(), (0, Some(0)));
+
+		// Appended arguments should still come through.
+		args.extend([OsString::from("a")]);
+		assert_eq!(args.size_hint(), (0, Some(1)));
+		assert_eq!(args.next(), Some(Argument::Other("a".to_owned())));
+		assert_eq!(args.next(), None);
+		assert_eq!(args.iter.0, 2);
+
+		// Even after a checkpoint restoration.
+		let mut args = Argue::from(Unfused(0));
+		args.checkpoint();
+		assert_eq!(args.next(), Some(Argument::Other("1".to_owned())));
+		assert_eq!(args.next(), None);
+		assert!(args.restore());
+		assert_eq!(args.next(), Some(Argument::Other("1".to_owned())));
+		assert_eq!(args.next(), None);
+		assert_eq!(args.iter.0, 2);
+	}
+
+	#[test]
+	fn t_is_empty() {
+		// Empty strings count, even though they're skipped.
+		let mut args = Argue::from([OsString::new()].into_iter());
+		assert!(! args.is_empty());
+		assert_eq!(args.next(), None);
+		assert!(args.is_empty());
+
+		// Peeking shouldn't affect the byte limit.
+		let mut args = Argue::from([OsString::from("abc")].into_iter())
+			.with_byte_limit(3);
+		assert!(! args.is_empty());
+		assert!(! args.is_empty());
+		assert_eq!(args.try_next(), Ok(Some(Argument::Other("abc".to_owned()))));
+		assert!(args.is_empty());
+
+		// Appended arguments count too.
+		args.extend([OsString::from("d")]);
+		assert!(! args.is_empty());
+		assert_eq!(args.try_next(), Err(LimitExceeded));
+		assert!(args.is_empty());
+	}
+
+	#[test]
+	fn t_into_end() {
+		let cli = [
+			OsString::from("-v"),
+			OsString::from("run"),
+			OsString::from("--color"),
+			OsString::from("-o"),
+			OsString::from("x"),
+		];
+		let keys = [
+			KeyWord::Command("run"),
+			KeyWord::Key("-v"),
+			KeyWord::KeyWithOptionalValue("--color"),
+			KeyWord::KeyWithValue("-o"),
+		];
+
+		// Mid-stream.
+		let mut args = Argue::from(cli.clone().into_iter()).with_keywords(keys);
+		assert_eq!(args.next(), Some(Argument::Key("-v")));
+		assert_eq!(args.into_end(), cli[1..]);
+
+		// Partitioned.
+		let (found, args) = Argue::from(cli.clone().into_iter())
+			.with_keywords(keys)
+			.partition_keys(["-v"]);
+		assert_eq!(found, [Argument::Key("-v")]);
+		assert_eq!(args.into_end(), cli[1..]);
+
+		// Limited.
+		let args = Argue::from(cli.into_iter()).with_byte_limit(5);
+		assert_eq!(args.into_end(), [OsString::from("-v"), OsString::from("run")]);
+	}
+
+	#[test]
+	fn t_parse_line() {
+		let mut repl = Argue::from(Vec::<OsString>::new())
+			.with_keywords([KeyWord::Command("set"), KeyWord::KeyWithValue("-o")])
+			.with_posix();
+
+		assert_eq!(
+			repl.parse_line(r"set -o 'a b' c\ d -o"),
+			[
+				Argument::Command("set"),
+				Argument::KeyWithValue("-o", "a b".to_owned()),
+				Argument::Other("c d".to_owned()),
+				Argument::Other("-o".to_owned()), // POSIX!
+			],
+		);
+
+		// Lines don't bleed into one another.
+		assert_eq!(repl.parse_line("-o"), [Argument::MissingValue("-o")]);
+		assert_eq!(repl.parse_line("x"), [Argument::Other("x".to_owned())]);
+		assert!(repl.parse_line("").is_empty());
+	}
+
+	#[test]
+	fn t_adapters() {
+		let cli = [
+			OsString::from("-o"),
+			OsString::from("a"),
+			OsString::from("-v"),
+			OsString::from("-o"),
+			OsString::from("b"),
+			OsString::from("c"),
+			OsString::from("--"),
+			OsString::from("-v"),
+		];
+		let new = || Argue::from(cli.clone().into_iter())
+			.with_keywords([KeyWord::KeyWithValue("-o"), KeyWord::Key("-v")]);
+
+		let all = vec![
+			Argument::KeyWithValue("-o", "a".to_owned()),
+			Argument::Key("-v"),
+			Argument::KeyWithValue("-o", "b".to_owned()),
+			Argument::Other("c".to_owned()),
+			Argument::End(vec![OsString::from("-v")]),
+		];
+		assert_eq!(new().collect::<Vec<_>>(), all);
+
+		// Skipping and stepping work with whole arguments.
+		assert_eq!(new().skip(1).collect::<Vec<_>>(), all[1..]);
+		assert_eq!(new().skip(2).step_by(2).collect::<Vec<_>>(), [all[2].clone(), all[4].clone()]);
+		assert_eq!(new().nth(2), Some(all[2].clone()));
+		assert_eq!(new().nth(5), None);
+
+		let mut args = new();
+		assert_eq!(args.nth(1), Some(all[1].clone()));
+		assert_eq!(args.next(), Some(all[2].clone()));
+
+		// As do the conditional adapters, modulo the usual caveat that the
+		// first non-match is consumed.
+		let mut args = new();
+		assert_eq!(
+			args.by_ref().take_while(|a| matches!(a, Argument::KeyWithValue(..))).count(),
+			1,
+		);
+		assert_eq!(args.next(), Some(all[2].clone()));
+		assert_eq!(
+			new().skip_while(|a| ! matches!(a, Argument::Other(_))).collect::<Vec<_>>(),
+			all[3..],
+		);
+
+		// Peeking doesn't lose anything either.
+		let mut args = new().peekable();
+		assert_eq!(args.peek(), Some(&all[0]));
+		assert_eq!(args.next_if(|a| matches!(a, Argument::Key(_))), None);
+		assert_eq!(args.collect::<Vec<_>>(), all);
+
+		// Size hints are loose but sane.
+		let mut args = new();
+		assert_eq!(args.size_hint(), (0, Some(cli.len())));
+		args.next();
+		assert_eq!(args.size_hint(), (0, Some(cli.len() - 2)));
+		assert_eq!(args.by_ref().count(), 4);
+		assert_eq!(args.size_hint(), (0, Some(0)));
+
+		// And once done, it stays done.
+		assert_eq!(args.next(), None);
+		assert_eq!(args.next(), None);
+	}
 }
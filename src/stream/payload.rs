@@ -0,0 +1,131 @@
+/*!
+# Argyle: Keyword Payloads.
+*/
+
+use crate::{
+	Argue,
+	KeyWord,
+};
+use std::{
+	any::Any,
+	sync::Arc,
+};
+
+
+
+/// # Payload.
+///
+/// A type-erased value attached to a keyword by [`Argue::with_keyword_map`].
+pub(super) type Payload = Arc<dyn Any + Send + Sync>;
+
+
+
+impl<I> Argue<I> {
+	#[must_use]
+	/// # With Keyword Map.
+	///
+	/// Register keywords — same as [`Argue::with_keywords`] — along with an
+	/// arbitrary payload for each, retrievable afterwards via
+	/// [`Argue::payload_for`].
+	///
+	/// This makes it possible to drive dispatch from a table, mapping keys
+	/// straight to an enum or the like instead of re-matching the strings in
+	/// every loop.
+	///
+	/// Payloads are keyed by [`KeyWord::as_str`], so should be attached to
+	/// canonical keys rather than aliases; the parsed [`Argument`](crate::Argument)s
+	/// always report the former.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use argyle::{Argue, Argument, KeyWord};
+	/// use std::ffi::OsString;
+	///
+	/// #[derive(Debug, Clone, Copy, Eq, PartialEq)]
+	/// enum Mode { Fast, Slow }
+	///
+	/// let mut args = Argue::from([
+	///     OsString::from("--slow"),
+	///     OsString::from("-f"),
+	/// ])
+	///     .with_keyword_map([
+	///         (KeyWord::key("--fast").unwrap(), Mode::Fast),
+	///         (KeyWord::key("--slow").unwrap(), Mode::Slow),
+	///     ])
+	///     .with_keywords([KeyWord::Alias("-f", "--fast")]);
+	///
+	/// let mut modes = Vec::new();
+	/// while let Some(arg) = args.next() {
+	///     if let Argument::Key(k) = arg {
+	///         if let Some(mode) = args.payload_for::<Mode>(k) {
+	///             modes.push(*mode);
+	///         }
+	///     }
+	/// }
+	///
+	/// assert_eq!(modes, [Mode::Slow, Mode::Fast]);
+	/// ```
+	pub fn with_keyword_map<T, M>(mut self, map: M) -> Self
+	where T: Any + Send + Sync, M: IntoIterator<Item=(KeyWord, T)> {
+		for (key, payload) in map {
+			// Note: we're using `replace` instead of `insert` to keep the
+			// variants synced.
+			let _res = self.keys.replace(key);
+			self.payloads.insert(key.as_str(), Arc::new(payload));
+		}
+		self
+	}
+
+	#[must_use]
+	/// # Payload For Key.
+	///
+	/// Return the payload attached to `key` via [`Argue::with_keyword_map`],
+	/// if any, and if it is of type `T`.
+	///
+	/// See [`Argue::with_keyword_map`] for an example.
+	pub fn payload_for<T: Any>(&self, key: &str) -> Option<&T> {
+		self.payloads.get(key)?.downcast_ref()
+	}
+}
+
+
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::Argument;
+	use std::ffi::OsString;
+
+	#[test]
+	fn t_payload_for() {
+		let args = Argue::from([
+			OsString::from("build"),
+			OsString::from("-j"),
+			OsString::from("4"),
+		].into_iter())
+			.with_keyword_map([
+				(KeyWord::Command("build"), "Build it!"),
+				(KeyWord::KeyWithValue("-j"), "Threads."),
+			])
+			.with_keyword_map([(KeyWord::Key("-v"), 3_u8)]);
+
+		assert_eq!(args.payload_for::<&str>("build"), Some(&"Build it!"));
+		assert_eq!(args.payload_for::<&str>("-j"), Some(&"Threads."));
+		assert_eq!(args.payload_for::<u8>("-v"), Some(&3));
+
+		// Wrong type, or wrong key.
+		assert!(args.payload_for::<u8>("build").is_none());
+		assert!(args.payload_for::<&str>("--nope").is_none());
+
+		// Payloads carry over to children.
+		let child = args.child([OsString::from("-v")]);
+		assert_eq!(child.payload_for::<u8>("-v"), Some(&3));
+
+		// And the keywords work like any others.
+		assert_eq!(
+			args.collect::<Vec<_>>(),
+			[Argument::Command("build"), Argument::KeyWithValue("-j", "4".to_owned())],
+		);
+	}
+}
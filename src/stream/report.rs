@@ -0,0 +1,388 @@
+/*!
+# Argyle: Parse Report.
+*/
+
+use crate::{
+	Argue,
+	Argument,
+};
+use std::{
+	collections::{
+		BTreeMap,
+		BTreeSet,
+	},
+	ffi::OsString,
+};
+
+
+
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+/// # Parse Report.
+///
+/// This is a summary of _everything_ parsed by an [`Argue`] instance, as
+/// returned by [`Argue::exhaust`]. (It can also be collected from any other
+/// iterator of [`Argument`]s.)
+///
+/// For quick scripts, it can be a handy alternative to the usual `match`
+/// loop.
+pub struct ParseReport {
+	/// # Commands and Boolean Keys.
+	///
+	/// All [`Argument::Command`], [`Argument::CommandWith`], and
+	/// [`Argument::Key`] matches.
+	pub keys_seen: BTreeSet<&'static str>,
+
+	/// # Keys and Values.
+	///
+	/// All [`Argument::KeyWithValue`] and [`Argument::KeyWithValues`]
+	/// matches — and non-empty [`Argument::CommandWith`] payloads — with the
+	/// values for each key listed in the order they were found.
+	pub values: BTreeMap<&'static str, Vec<String>>,
+
+	/// # Keys and Raw Values.
+	///
+	/// All [`Argument::KeyWithOsValue`] matches, with the values for each
+	/// key listed in the order they were found.
+	pub os_values: BTreeMap<&'static str, Vec<OsString>>,
+
+	/// # Namespaced Arguments.
+	///
+	/// All [`Argument::Namespaced`] values, grouped by prefix, in order.
+	pub namespaced: BTreeMap<&'static str, Vec<String>>,
+
+	/// # Other Arguments.
+	///
+	/// All [`Argument::Other`] values, in order.
+	pub others: Vec<String>,
+
+	/// # Help.
+	///
+	/// True if any [`Argument::Help`] were found.
+	pub help: bool,
+
+	/// # Help Topic.
+	///
+	/// The last [`Argument::Help`] topic, if any.
+	pub help_topic: Option<String>,
+
+	/// # STDIN.
+	///
+	/// True if any [`Argument::Stdin`] were found.
+	pub stdin: bool,
+
+	/// # Trailing Arguments.
+	///
+	/// The [`Argument::End`] values, if any.
+	pub end: Vec<OsString>,
+
+	/// # Errors.
+	///
+	/// Any arguments that _probably_ indicate a user error, such as
+	/// [`Argument::InvalidUtf8`], [`Argument::KeyWithInvalidValue`],
+	/// [`Argument::MissingValue`], [`Argument::Placeholder`],
+	/// [`Argument::UnexpectedValue`], and [`Argument::ValueTooLong`].
+	pub errors: Vec<Argument>,
+}
+
+impl ParseReport {
+	#[must_use]
+	/// # First Value.
+	///
+	/// Return the first value found for `key`, if any, for first-wins
+	/// semantics.
+	///
+	/// Values are always collected in the order they appeared on the command
+	/// line — with those from an [`Argument::KeyWithValues`] kept together
+	/// — so this is the value from the earliest occurrence of the key.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use argyle::{Argue, KeyWord};
+	/// use std::ffi::OsString;
+	///
+	/// let report = Argue::from([
+	///     OsString::from("--out"),
+	///     OsString::from("a"),
+	///     OsString::from("--out=b"),
+	/// ])
+	///     .with_keywords([KeyWord::key_with_value("--out").unwrap()])
+	///     .exhaust();
+	///
+	/// assert_eq!(report.values["--out"], ["a", "b"]);
+	/// assert_eq!(report.first_value("--out"), Some("a"));
+	/// assert_eq!(report.last_value("--out"), Some("b"));
+	/// assert_eq!(report.first_value("--in"), None);
+	/// ```
+	pub fn first_value(&self, key: &str) -> Option<&str> {
+		self.values.get(key)?.first().map(String::as_str)
+	}
+
+	#[must_use]
+	/// # Last Value.
+	///
+	/// Return the last value found for `key`, if any, for last-wins
+	/// semantics.
+	///
+	/// See [`ParseReport::first_value`] for an example.
+	pub fn last_value(&self, key: &str) -> Option<&str> {
+		self.values.get(key)?.last().map(String::as_str)
+	}
+}
+
+impl FromIterator<Argument> for ParseReport {
+	fn from_iter<T: IntoIterator<Item=Argument>>(iter: T) -> Self {
+		let mut out = Self::default();
+		for arg in iter {
+			match arg {
+				Argument::Command(k) | Argument::Key(k) => { out.keys_seen.insert(k); },
+				Argument::CommandWith(k, v) => {
+					out.keys_seen.insert(k);
+					if ! v.is_empty() { out.values.entry(k).or_default().extend(v); }
+				},
+				Argument::KeyWithValue(k, v) => { out.values.entry(k).or_default().push(v); },
+				Argument::KeyWithValues(k, v) => { out.values.entry(k).or_default().extend(v); },
+				Argument::KeyWithOsValue(k, v) => { out.os_values.entry(k).or_default().push(v); },
+				Argument::Namespaced(k, v) => { out.namespaced.entry(k).or_default().push(v); },
+				Argument::Other(v) => { out.others.push(v); },
+				Argument::Help { topic } => {
+					out.help = true;
+					if topic.is_some() { out.help_topic = topic; }
+				},
+				Argument::Stdin => { out.stdin = true; },
+				Argument::End(v) => { out.end.extend(v); },
+				Argument::InvalidUtf8(_) |
+				Argument::KeyWithInvalidValue(_, _) |
+				Argument::MissingValue(_) |
+				Argument::Placeholder { .. } |
+				Argument::UnexpectedValue(_, _) |
+				Argument::ValueTooLong(_, _) => { out.errors.push(arg); },
+			}
+		}
+		out
+	}
+}
+
+impl<I: Iterator<Item=OsString>> Argue<I> {
+	#[must_use]
+	/// # Exhaust.
+	///
+	/// Drive the iterator to completion, collecting the results into a
+	/// single [`ParseReport`].
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use argyle::{Argue, KeyWord};
+	/// use std::ffi::OsString;
+	///
+	/// let report = Argue::from([
+	///     OsString::from("-v"),
+	///     OsString::from("--threads=4"),
+	///     OsString::from("file.txt"),
+	/// ])
+	///     .with_keywords([
+	///         KeyWord::key("-v").unwrap(),
+	///         KeyWord::key_with_value("--threads").unwrap(),
+	///     ])
+	///     .exhaust();
+	///
+	/// assert!(report.keys_seen.contains("-v"));
+	/// assert_eq!(report.values["--threads"], ["4"]);
+	/// assert_eq!(report.others, ["file.txt"]);
+	/// assert!(report.errors.is_empty());
+	/// ```
+	pub fn exhaust(self) -> ParseReport { self.collect() }
+
+	#[must_use]
+	/// # Into Key Map.
+	///
+	/// Drive the iterator to completion, collecting all keyword matches into
+	/// a lookup table, and all positional arguments into a list.
+	///
+	/// [`Argument::Command`] and [`Argument::Key`] matches are included with
+	/// an empty value list; [`Argument::KeyWithValue`],
+	/// [`Argument::KeyWithValues`], and [`Argument::CommandWith`] values are
+	/// listed in the order they were found.
+	///
+	/// The positionals include both [`Argument::Other`] and [`Argument::End`]
+	/// values, the latter converted lossily.
+	///
+	/// Everything else is discarded. If you need errors, help requests, etc.,
+	/// use [`Argue::exhaust`] instead.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use argyle::{Argue, KeyWord};
+	/// use std::ffi::OsString;
+	///
+	/// let (keys, paths) = Argue::from([
+	///     OsString::from("-v"),
+	///     OsString::from("--exclude=a"),
+	///     OsString::from("file.txt"),
+	///     OsString::from("--exclude=b"),
+	/// ])
+	///     .with_keywords([
+	///         KeyWord::key("-v").unwrap(),
+	///         KeyWord::key_with_value("--exclude").unwrap(),
+	///     ])
+	///     .into_key_map();
+	///
+	/// assert!(keys.contains_key("-v"));
+	/// assert_eq!(keys["--exclude"], ["a", "b"]);
+	/// assert_eq!(paths, ["file.txt"]);
+	/// ```
+	pub fn into_key_map(self) -> (BTreeMap<&'static str, Vec<String>>, Vec<String>) {
+		let mut keys = BTreeMap::<&'static str, Vec<String>>::new();
+		let mut others = Vec::new();
+		for arg in self {
+			match arg {
+				Argument::Command(k) | Argument::Key(k) => { keys.entry(k).or_default(); },
+				Argument::KeyWithValue(k, v) => { keys.entry(k).or_default().push(v); },
+				Argument::KeyWithOsValue(k, v) => {
+					keys.entry(k).or_default().push(lossy(v));
+				},
+				Argument::CommandWith(k, v) |
+				Argument::KeyWithValues(k, v) => { keys.entry(k).or_default().extend(v); },
+				Argument::Other(v) => { others.push(v); },
+				Argument::End(v) => { others.extend(v.into_iter().map(lossy)); },
+				_ => {},
+			}
+		}
+		(keys, others)
+	}
+}
+
+
+
+/// # Lossy String.
+///
+/// Convert an `OsString` to a `String`, replacing any invalid sequences.
+fn lossy(src: OsString) -> String {
+	match src.into_string() {
+		Ok(s) => s,
+		Err(s) => s.to_string_lossy().into_owned(),
+	}
+}
+
+
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::KeyWord;
+
+	#[test]
+	fn t_exhaust() {
+		let report = Argue::from([
+			OsString::from("build"),
+			OsString::from("-v"),
+			OsString::from("-v=1"),
+			OsString::from("-o"),
+			OsString::from("a.txt"),
+			OsString::from("-ob.txt"),
+			OsString::from("file.txt"),
+			OsString::from("-"),
+			OsString::from("--"),
+			OsString::from("-v"),
+		])
+			.with_keywords([
+				KeyWord::Command("build"),
+				KeyWord::Key("-v"),
+				KeyWord::KeyWithValue("-o"),
+			])
+			.with_stdin()
+			.exhaust();
+
+		assert!(report.stdin);
+		assert_eq!(report.keys_seen, BTreeSet::from(["build", "-v"]));
+		assert_eq!(report.values.len(), 1);
+		assert_eq!(report.values["-o"], ["a.txt", "b.txt"]);
+		assert_eq!(report.others, ["file.txt"]);
+		assert_eq!(report.end, [OsString::from("-v")]);
+		assert_eq!(report.errors, [Argument::UnexpectedValue("-v", "1".to_owned())]);
+	}
+
+	#[test]
+	fn t_duplicate_values() {
+		let cli = [
+			OsString::from("--out"),
+			OsString::from("a"),
+			OsString::from("file.txt"),
+			OsString::from("--out=b"),
+			OsString::from("-o"),
+			OsString::from("c"),
+			OsString::from("-s"),
+			OsString::from("1"),
+			OsString::from("2"),
+			OsString::from("-s3"),
+			OsString::from("4"),
+		];
+		let args = || Argue::from(cli.iter().cloned())
+			.with_keywords([
+				KeyWord::KeyWithValue("--out"),
+				KeyWord::Alias("-o", "--out"),
+				KeyWord::KeyWithValue("-s"),
+			])
+			.nargs("-s", 2);
+
+		// Each occurrence is returned separately, in order.
+		assert_eq!(
+			args().collect::<Vec<_>>(),
+			[
+				Argument::KeyWithValue("--out", "a".to_owned()),
+				Argument::Other("file.txt".to_owned()),
+				Argument::KeyWithValue("--out", "b".to_owned()),
+				Argument::KeyWithValue("--out", "c".to_owned()),
+				Argument::KeyWithValues("-s", vec!["1".to_owned(), "2".to_owned()]),
+				Argument::KeyWithValues("-s", vec!["3".to_owned(), "4".to_owned()]),
+			],
+		);
+
+		// And collected in that same order.
+		let report = args().exhaust();
+		assert_eq!(report.values["--out"], ["a", "b", "c"]);
+		assert_eq!(report.values["-s"], ["1", "2", "3", "4"]);
+		assert_eq!(report.first_value("--out"), Some("a"));
+		assert_eq!(report.last_value("--out"), Some("c"));
+		assert_eq!(report.first_value("-s"), Some("1"));
+		assert_eq!(report.last_value("-s"), Some("4"));
+		assert_eq!(report.first_value("-o"), None);
+
+		let (keys, _) = args().into_key_map();
+		assert_eq!(keys["--out"], ["a", "b", "c"]);
+	}
+
+	#[test]
+	fn t_into_key_map() {
+		let (keys, others) = Argue::from([
+			OsString::from("build"),
+			OsString::from("-v"),
+			OsString::from("-v=1"),
+			OsString::from("-o"),
+			OsString::from("a.txt"),
+			OsString::from("file.txt"),
+			OsString::from("-ob.txt"),
+			OsString::from("--"),
+			OsString::from("-v"),
+		])
+			.with_keywords([
+				KeyWord::Command("build"),
+				KeyWord::Key("-v"),
+				KeyWord::KeyWithValue("-o"),
+				KeyWord::KeyWithValue("-x"),
+			])
+			.into_key_map();
+
+		assert_eq!(
+			keys,
+			BTreeMap::from([
+				("build", Vec::new()),
+				("-v", Vec::new()),
+				("-o", vec!["a.txt".to_owned(), "b.txt".to_owned()]),
+			]),
+		);
+		assert_eq!(others, ["file.txt", "-v"]);
+	}
+}
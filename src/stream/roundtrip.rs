@@ -0,0 +1,224 @@
+/*!
+# Argyle: Round Trip.
+*/
+
+use crate::{
+	Argue,
+	Argument,
+	KeyWord,
+};
+use std::ffi::OsString;
+
+
+
+/// # Round Trip.
+///
+/// Parse `args` with an [`Argue`] configured with `keywords` — and nothing
+/// else — then re-emit an equivalent command line from the results.
+///
+/// The output is normalized: aliases are replaced by their canonical keys,
+/// empty arguments are dropped, values are split off into separate
+/// arguments (except for [`KeyWord::KeyWithOptionalValue`] and
+/// [`KeyWord::Prefix`], which must be attached), etc. The guarantee is
+/// that parsing the output yields exactly the same [`Argument`]s as
+/// parsing the input.
+///
+/// This can be used to re-exec a program (or hand off to another) with
+/// arguments that will be understood the same way.
+///
+/// ## Examples
+///
+/// ```
+/// use argyle::KeyWord;
+/// use std::ffi::OsString;
+///
+/// let keys = [
+///     KeyWord::Key("--verbose"),
+///     KeyWord::Alias("-v", "--verbose"),
+///     KeyWord::KeyWithValue("-o"),
+/// ];
+///
+/// let out = argyle::roundtrip(
+///     &[
+///         OsString::from("-v"),
+///         OsString::from("-ofile.txt"),
+///         OsString::new(),
+///         OsString::from("--"),
+///         OsString::from("-v"),
+///     ],
+///     &keys,
+/// );
+///
+/// assert_eq!(
+///     out,
+///     [
+///         OsString::from("--verbose"),
+///         OsString::from("-o"),
+///         OsString::from("file.txt"),
+///         OsString::from("--"),
+///         OsString::from("-v"),
+///     ],
+/// );
+/// ```
+#[must_use]
+pub fn roundtrip(args: &[OsString], keywords: &[KeyWord]) -> Vec<OsString> {
+	let argue = Argue::from(args.iter().cloned()).with_keywords(keywords);
+	let mut out = Vec::with_capacity(args.len());
+	for arg in argue { emit(arg, keywords, &mut out); }
+	out
+}
+
+
+
+/// # Emit Argument.
+///
+/// Convert a parsed [`Argument`] back into raw form, appending the result
+/// to `out`. See [`roundtrip`] for the details.
+pub(super) fn emit<'a, K>(arg: Argument, keywords: K, out: &mut Vec<OsString>)
+where K: IntoIterator<Item=&'a KeyWord> + Copy {
+	match arg {
+		// Overlong values are discarded, so only the key remains.
+		Argument::Command(k) | Argument::Key(k) | Argument::MissingValue(k) |
+		Argument::ValueTooLong(k, _) => {
+			out.push(OsString::from(k));
+		},
+		Argument::KeyWithValue(k, v) |
+		Argument::Placeholder { key: Some(k), value: v } =>
+			// Optional and prefix values have to be attached.
+			if let Some(glue) = attachment(k, keywords) {
+				out.push(OsString::from(format!("{k}{glue}{v}")));
+			}
+			else {
+				out.push(OsString::from(k));
+				out.push(OsString::from(v));
+			},
+		Argument::CommandWith(k, v) |
+		Argument::KeyWithValues(k, v) => {
+			out.push(OsString::from(k));
+			out.extend(v.into_iter().map(OsString::from));
+		},
+		Argument::UnexpectedValue(k, v) => {
+			out.push(OsString::from(format!("{k}={v}")));
+		},
+		Argument::KeyWithOsValue(k, v) => {
+			out.push(OsString::from(k));
+			out.push(v);
+		},
+		Argument::KeyWithInvalidValue(k, v) =>
+			// Optional and prefix values have to be attached.
+			if let Some(glue) = attachment(k, keywords) {
+				let mut tmp = OsString::from(k);
+				tmp.push(glue);
+				tmp.push(v);
+				out.push(tmp);
+			}
+			else {
+				out.push(OsString::from(k));
+				out.push(v);
+			},
+		Argument::Namespaced(prefix, v) => {
+			out.push(OsString::from(format!("{prefix}{}", v.trim_start_matches('-'))));
+		},
+		Argument::Help { topic } => {
+			out.push(OsString::from("--help"));
+			if let Some(topic) = topic { out.push(OsString::from(topic)); }
+		},
+		Argument::Stdin => { out.push(OsString::from("-")); },
+		Argument::Other(v) | Argument::Placeholder { key: None, value: v } => {
+			out.push(OsString::from(v));
+		},
+		Argument::InvalidUtf8(v) => { out.push(v); },
+		Argument::End(v) => {
+			out.push(OsString::from("--"));
+			out.extend(v);
+		},
+	}
+}
+
+
+/// # Attachment.
+///
+/// Returns the glue — `=` or nothing — needed to attach a value to `k` if
+/// it is a [`KeyWord::KeyWithOptionalValue`] or [`KeyWord::Prefix`], or
+/// `None` if it can be passed separately.
+fn attachment<'a, K>(k: &str, keywords: K) -> Option<&'static str>
+where K: IntoIterator<Item=&'a KeyWord> {
+	keywords.into_iter().find_map(|kw| match kw {
+		KeyWord::KeyWithOptionalValue(k2) if *k2 == k => Some("="),
+		KeyWord::Prefix(k2) if *k2 == k => Some(""),
+		_ => None,
+	})
+}
+
+
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	/// # Parse.
+	fn parse(args: &[OsString], keys: &[KeyWord]) -> Vec<Argument> {
+		Argue::from(args.iter().cloned()).with_keywords(keys).collect()
+	}
+
+	#[test]
+	fn t_roundtrip() {
+		let keys = [
+			KeyWord::Command("build"),
+			KeyWord::Key("-v"),
+			KeyWord::Key("--verbose"),
+			KeyWord::Alias("-V", "--verbose"),
+			KeyWord::KeyWithValue("-o"),
+			KeyWord::KeyWithValue("--out"),
+			KeyWord::Alias("-O", "--out"),
+			KeyWord::KeyWithOptionalValue("--color"),
+			KeyWord::KeyWithOptionalValue("-c"),
+			KeyWord::Prefix("-W"),
+		];
+
+		let pool: Vec<OsString> = [
+			"", "-", "--", "=", "x", "-x", "--x", "build", "help", "-v", "-v=1",
+			"-vx", "--verbose", "--verbose=", "--verbose==", "-V", "-o", "-o=",
+			"-o==", "-oval", "--out", "--out=", "--out=a=b", "-O", "-Oval",
+			"--color", "--color=", "--color=never", "-c", "-calways", "-c=x",
+			"-W", "-Wall", "-W=x", "-W=",
+		].into_iter().map(OsString::from).collect();
+
+		#[cfg(unix)]
+		let pool = {
+			use std::os::unix::ffi::OsStrExt;
+			let mut pool = pool;
+			for raw in [&b"\xff"[..], b"--out=\xff", b"-v=\xff", b"-W\xff"] {
+				pool.push(std::ffi::OsStr::from_bytes(raw).to_os_string());
+			}
+			pool
+		};
+
+		// A simple xorshift for reproducible "random" command lines.
+		let mut seed = 0x2545_f491_4f6c_dd1d_u64;
+		let mut rand = move || {
+			seed ^= seed << 13;
+			seed ^= seed >> 7;
+			seed ^= seed << 17;
+			usize::try_from(seed % 1024).unwrap()
+		};
+
+		for _ in 0..2000 {
+			let len = rand() % 8;
+			let args: Vec<OsString> = (0..len)
+				.map(|_| pool[rand() % pool.len()].clone())
+				.collect();
+
+			let parsed = parse(&args, &keys);
+			let emitted = roundtrip(&args, &keys);
+			assert_eq!(
+				parse(&emitted, &keys),
+				parsed,
+				"Round trip failed for {args:?} (via {emitted:?}).",
+			);
+
+			// Emitting again shouldn't change anything.
+			assert_eq!(roundtrip(&emitted, &keys), emitted);
+		}
+	}
+}
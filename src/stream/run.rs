@@ -0,0 +1,322 @@
+/*!
+# Argyle: Runner.
+*/
+
+use super::Diagnostic;
+use crate::{
+	Argument,
+	KeyWord,
+	Version,
+};
+use std::{
+	fmt,
+	process::{
+		exit,
+		ExitCode,
+		Termination,
+	},
+	vec::IntoIter,
+};
+
+
+
+/// # Exit Code: Usage Error.
+const EXIT_USAGE: u8 = 2;
+
+
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+/// # Main Result.
+///
+/// This is a [`Termination`]-friendly alternative to [`run`] for apps that
+/// would rather return from `main` than have the process exited out from
+/// under them. Each variant holds the message — if any — to print on the
+/// way out, and maps to the conventional exit code:
+///
+/// | Variant | Output | Code |
+/// | ------- | ------ | ---- |
+/// | `Success` | | `0` |
+/// | `Print` | STDOUT | `0` |
+/// | `Failure` | STDERR | `1` |
+/// | `Usage` | STDERR | `2` |
+///
+/// See [`RunConfig::parse`] for help/version/usage handling, and note that
+/// any `Result<(), E: Display>` can be converted via `From`.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use argyle::{Argument, KeyWord, MainResult, RunConfig};
+///
+/// fn main() -> MainResult {
+///     let config = RunConfig::new(argyle::version!(), "Usage: app [-q] <FILE>")
+///         .with_keywords([KeyWord::key("-q").unwrap()]);
+///
+///     let args = match config.parse() {
+///         Ok(args) => args,
+///         Err(e) => return e, // Help, version, or usage error.
+///     };
+///
+///     real_main(args).into()
+/// }
+///
+/// fn real_main(args: Vec<Argument>) -> Result<(), String> {
+///     // Do stuff…
+///     Ok(())
+/// }
+/// ```
+pub enum MainResult {
+	/// # Success.
+	Success,
+
+	/// # Success (With Output).
+	///
+	/// E.g. a help screen or version.
+	Print(String),
+
+	/// # Failure.
+	Failure(String),
+
+	/// # Usage Error.
+	Usage(String),
+}
+
+impl<E: fmt::Display> From<Result<(), E>> for MainResult {
+	fn from(src: Result<(), E>) -> Self {
+		match src {
+			Ok(()) => Self::Success,
+			Err(e) => Self::Failure(format!("Error: {e}")),
+		}
+	}
+}
+
+impl Termination for MainResult {
+	fn report(self) -> ExitCode {
+		self.print();
+		ExitCode::from(self.exit_code())
+	}
+}
+
+impl MainResult {
+	#[must_use]
+	/// # Exit Code.
+	///
+	/// Return the corresponding exit code.
+	pub const fn exit_code(&self) -> u8 {
+		match self {
+			Self::Success | Self::Print(_) => 0,
+			Self::Failure(_) => 1,
+			Self::Usage(_) => EXIT_USAGE,
+		}
+	}
+
+	/// # Print.
+	///
+	/// Print the message, if any, to the appropriate stream.
+	fn print(&self) {
+		match self {
+			Self::Success => {},
+			Self::Print(msg) => { println!("{msg}"); },
+			Self::Failure(msg) | Self::Usage(msg) => { eprintln!("{msg}"); },
+		}
+	}
+}
+
+
+
+#[derive(Debug, Clone)]
+/// # Run Configuration.
+///
+/// This holds the settings for [`run`]: the app's [`Version`], help text,
+/// and any [`KeyWord`]s it expects.
+///
+/// The standard `-h`/`--help` and `-V`/`--version` keys are registered
+/// automatically.
+///
+/// ## Examples
+///
+/// ```
+/// use argyle::{KeyWord, RunConfig};
+///
+/// let config = RunConfig::new(argyle::version!(), "Usage: app [FLAGS] <FILE>")
+///     .with_keywords([KeyWord::key("-q").unwrap()]);
+/// ```
+pub struct RunConfig {
+	/// # Version.
+	version: Version,
+
+	/// # Help Text.
+	help: &'static str,
+
+	/// # Keywords.
+	keys: Vec<KeyWord>,
+}
+
+impl RunConfig {
+	#[must_use]
+	/// # New.
+	///
+	/// Create a new configuration from a [`Version`] and help screen.
+	pub fn new(version: Version, help: &'static str) -> Self {
+		Self {
+			version,
+			help,
+			keys: vec![
+				KeyWord::Key("-h"),
+				KeyWord::Key("--help"),
+				KeyWord::Key("-V"),
+				KeyWord::Key("--version"),
+			],
+		}
+	}
+
+	#[must_use]
+	/// # With Keywords.
+	///
+	/// Add the app-specific keywords to look for.
+	pub fn with_keywords<I>(mut self, keys: I) -> Self
+	where I: IntoIterator, I::Item: Into<KeyWord> {
+		self.keys.extend(keys.into_iter().map(Into::into));
+		self
+	}
+}
+
+impl RunConfig {
+	/// # Preflight.
+	///
+	/// Check the parsed arguments for help/version requests and usage errors,
+	/// returning the message to print — `Ok` for stdout, `Err` for stderr —
+	/// if the program should exit without invoking the handler.
+	///
+	/// Help and version requests take priority over any errors.
+	fn preflight(&self, args: &[Argument]) -> Option<Result<String, String>> {
+		for arg in args {
+			match arg {
+				Argument::Key("-h" | "--help") | Argument::Help { .. } => return Some(Ok(self.help.trim_end().to_owned())),
+				Argument::Key("-V" | "--version") => return Some(Ok(self.version.to_string())),
+				_ => {},
+			}
+		}
+
+		args.iter()
+			.find_map(Diagnostic::from_arg)
+			.map(|e| Err(format!("Error: {e}\nRun with --help for usage.")))
+	}
+
+	/// # Parse.
+	///
+	/// Parse the environment arguments — see [`args`](crate::args) — and
+	/// check them for help/version requests and usage errors, the same way
+	/// [`run`] does, but without exiting.
+	///
+	/// ## Errors
+	///
+	/// If the program should stop here, the appropriate [`MainResult`] is
+	/// returned as an error: [`MainResult::Print`] for help and version
+	/// requests, [`MainResult::Usage`] for usage errors.
+	pub fn parse(&self) -> Result<Vec<Argument>, MainResult> {
+		let args: Vec<Argument> = crate::args()
+			.with_keywords(self.keys.iter().copied())
+			.collect();
+
+		match self.preflight(&args) {
+			Some(Ok(msg)) => Err(MainResult::Print(msg)),
+			Some(Err(msg)) => Err(MainResult::Usage(msg)),
+			None => Ok(args),
+		}
+	}
+}
+
+
+
+/// # Run!
+///
+/// Parse the environment arguments — see [`args`](crate::args) — and
+/// handle the usual CLI boilerplate, exiting the process when done:
+///
+/// * `-h`/`--help` prints the help to STDOUT and exits with `0`;
+/// * `-V`/`--version` prints the version to STDOUT and exits with `0`;
+/// * Usage errors — [`Argument::InvalidUtf8`], [`Argument::KeyWithInvalidValue`], [`Argument::MissingValue`], [`Argument::Placeholder`], [`Argument::UnexpectedValue`], and [`Argument::ValueTooLong`] — are printed to STDERR, exiting with `2`;
+///
+/// Otherwise the `handler` is called with the (already-parsed) arguments.
+/// If it returns an error, that is printed to STDERR and the program exits
+/// with `1`; if not, it exits with `0`.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use argyle::{Argument, KeyWord, RunConfig};
+///
+/// let config = RunConfig::new(argyle::version!(), "Usage: app [-q] <FILE>")
+///     .with_keywords([KeyWord::key("-q").unwrap()]);
+///
+/// argyle::run(&config, |args| {
+///     for arg in args {
+///         if let Argument::Other(path) = arg {
+///             if path.is_empty() { return Err("Bad path."); }
+///         }
+///     }
+///     Ok(())
+/// });
+/// ```
+pub fn run<F, E>(config: &RunConfig, handler: F) -> !
+where F: FnOnce(IntoIter<Argument>) -> Result<(), E>, E: fmt::Display {
+	let res = match config.parse() {
+		Ok(args) => MainResult::from(handler(args.into_iter())),
+		Err(e) => e,
+	};
+
+	res.print();
+	exit(i32::from(res.exit_code()));
+}
+
+
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	#[test]
+	fn t_preflight() {
+		let config = RunConfig::new(Version::new("Foo", "1.0.0"), "Help!\n");
+
+		// Nothing to see.
+		assert_eq!(config.preflight(&[Argument::Other("a".to_owned())]), None);
+
+		// Help and version.
+		assert_eq!(
+			config.preflight(&[Argument::Other("a".to_owned()), Argument::Key("--help")]),
+			Some(Ok("Help!".to_owned())),
+		);
+		assert_eq!(
+			config.preflight(&[Argument::Key("-V"), Argument::Key("-h")]),
+			Some(Ok("Foo v1.0.0".to_owned())),
+		);
+
+		// Errors.
+		assert_eq!(
+			config.preflight(&[Argument::MissingValue("-o")]),
+			Some(Err("Error: -o requires a value.\nRun with --help for usage.".to_owned())),
+		);
+
+		// Help beats errors.
+		assert_eq!(
+			config.preflight(&[Argument::MissingValue("-o"), Argument::Key("-h")]),
+			Some(Ok("Help!".to_owned())),
+		);
+	}
+
+	#[test]
+	fn t_main_result() {
+		assert_eq!(MainResult::from(Ok::<(), &str>(())), MainResult::Success);
+		assert_eq!(
+			MainResult::from(Err::<(), &str>("Oops.")),
+			MainResult::Failure("Error: Oops.".to_owned()),
+		);
+
+		assert_eq!(MainResult::Success.exit_code(), 0);
+		assert_eq!(MainResult::Print(String::new()).exit_code(), 0);
+		assert_eq!(MainResult::Failure(String::new()).exit_code(), 1);
+		assert_eq!(MainResult::Usage(String::new()).exit_code(), 2);
+	}
+}
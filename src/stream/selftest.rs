@@ -0,0 +1,231 @@
+/*!
+# Argyle: Self-Test.
+*/
+
+use crate::{
+	Argue,
+	Argument,
+	KeyWord,
+};
+use std::ffi::OsString;
+
+
+
+/// # Dummy Value.
+const VALUE: &str = "1";
+
+/// # Dummy Positional.
+const OTHER: &str = "file.txt";
+
+/// # Test Case.
+///
+/// A raw invocation and its expected result.
+type Case = (Vec<OsString>, Vec<Argument>);
+
+
+
+/// # Self-Test.
+///
+/// Generate a bunch of synthetic invocations from `keywords` — each key on
+/// its own, with dummy values both attached and separate, every switch
+/// followed by a positional, everything at once, etc. — and make sure each
+/// one parses the way it ought to, without any diagnostics.
+///
+/// This can catch configuration mistakes like aliases pointing nowhere, or
+/// keys shadowing other keys' glued values (`-o` vs `-o1`), that would
+/// otherwise only show up when a user trips over them.
+///
+/// It is intended for CI smoke checks, e.g. wired up to a hidden
+/// `--self-test-args` flag in the binary itself.
+///
+/// ## Examples
+///
+/// ```
+/// use argyle::KeyWord;
+///
+/// const KEYWORDS: [KeyWord; 4] = [
+///     KeyWord::Command("build"),
+///     KeyWord::Key("--verbose"),
+///     KeyWord::Alias("-v", "--verbose"),
+///     KeyWord::KeyWithValue("-o"),
+/// ];
+///
+/// // In main, something like:
+/// // if std::env::args().any(|a| a == "--self-test-args") { … }
+/// assert!(argyle::selftest(&KEYWORDS).is_ok());
+///
+/// // Whoops!
+/// assert!(argyle::selftest(&[
+///     KeyWord::KeyWithValue("-o"),
+///     KeyWord::Key("-o1"),
+/// ]).is_err());
+/// ```
+///
+/// ## Errors
+///
+/// If an invocation doesn't parse as expected, a human-readable description
+/// of the problem is returned as the error. Otherwise the number of
+/// invocations tested is returned.
+pub fn selftest(keywords: &[KeyWord]) -> Result<usize, String> {
+	let mut all_raw = Vec::new();
+	let mut all_expected = Vec::new();
+	let mut switches = Vec::new();
+	let mut total = 0;
+
+	for kw in keywords {
+		for (idx, (raw, expected)) in cases(*kw, keywords)?.into_iter().enumerate() {
+			check(&raw, &expected, keywords)?;
+			total += 1;
+
+			// Hold onto the first form of each for the combos.
+			if idx == 0 {
+				if let [Argument::Key(k)] = expected.as_slice() {
+					if ! switches.contains(k) { switches.push(*k); }
+				}
+				all_raw.extend(raw);
+				all_expected.extend(expected);
+			}
+		}
+	}
+
+	// Each switch followed by a positional.
+	for k in &switches {
+		check(
+			&[OsString::from(*k), OsString::from(OTHER)],
+			&[Argument::Key(k), Argument::Other(OTHER.to_owned())],
+			keywords,
+		)?;
+		total += 1;
+	}
+
+	// All the switches in reverse.
+	if 1 < switches.len() {
+		let raw: Vec<_> = switches.iter().rev().map(OsString::from).collect();
+		let expected: Vec<_> = switches.iter().rev().map(|k| Argument::Key(k)).collect();
+		check(&raw, &expected, keywords)?;
+		total += 1;
+	}
+
+	// And everything all at once.
+	if 1 < all_expected.len() {
+		check(&all_raw, &all_expected, keywords)?;
+		total += 1;
+	}
+
+	Ok(total)
+}
+
+
+
+/// # Test Cases.
+///
+/// Return the raw invocation(s) and expected result(s) for a single keyword.
+fn cases(kw: KeyWord, keywords: &[KeyWord]) -> Result<Vec<Case>, String> {
+	// Aliases resolve to their targets.
+	let (raw, target) = match kw {
+		KeyWord::Alias(a, t) => {
+			let target = keywords.iter()
+				.find(|kw2| ! matches!(kw2, KeyWord::Alias(_, _)) && kw2.as_str() == t)
+				.ok_or_else(|| format!("Alias {a} points to unknown key {t}."))?;
+			(a, *target)
+		},
+		kw => (kw.as_str(), kw),
+	};
+
+	let out = match target {
+		KeyWord::Command(k) => vec![
+			(vec![OsString::from(raw)], vec![Argument::Command(k)]),
+		],
+		KeyWord::Key(k) => vec![
+			(vec![OsString::from(raw)], vec![Argument::Key(k)]),
+		],
+		KeyWord::KeyWithValue(k) => {
+			// Short keys glue values directly; long ones need an equal sign.
+			let glued =
+				if raw.starts_with("--") { format!("{raw}={VALUE}") }
+				else { format!("{raw}{VALUE}") };
+			vec![
+				(
+					vec![OsString::from(raw), OsString::from(VALUE)],
+					vec![Argument::KeyWithValue(k, VALUE.to_owned())],
+				),
+				(vec![OsString::from(glued)], vec![Argument::KeyWithValue(k, VALUE.to_owned())]),
+			]
+		},
+		KeyWord::KeyWithOptionalValue(k) => vec![
+			(vec![OsString::from(raw)], vec![Argument::Key(k)]),
+			(
+				vec![OsString::from(format!("{raw}={VALUE}"))],
+				vec![Argument::KeyWithValue(k, VALUE.to_owned())],
+			),
+		],
+		KeyWord::Prefix(k) => vec![
+			(vec![OsString::from(raw)], vec![Argument::Key(k)]),
+			(
+				vec![OsString::from(format!("{raw}{VALUE}"))],
+				vec![Argument::KeyWithValue(k, VALUE.to_owned())],
+			),
+		],
+		KeyWord::Alias(_, _) => return Err(format!("Alias {raw} points to another alias.")),
+	};
+
+	Ok(out)
+}
+
+/// # Check Invocation.
+///
+/// Parse `raw` and make sure it comes out as `expected`, without any
+/// diagnostics.
+fn check(raw: &[OsString], expected: &[Argument], keywords: &[KeyWord])
+-> Result<(), String> {
+	let mut args = Argue::from(raw.iter().cloned())
+		.with_keywords(keywords)
+		.with_diagnostics();
+	let parsed: Vec<Argument> = args.by_ref().collect();
+
+	if parsed != expected {
+		return Err(format!("{raw:?} parsed as {parsed:?} instead of {expected:?}."));
+	}
+
+	if let Some(d) = args.finish().first() {
+		return Err(format!("{raw:?}: {d}"));
+	}
+
+	Ok(())
+}
+
+
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	#[test]
+	fn t_selftest() {
+		// Nothing is fine.
+		assert_eq!(selftest(&[]), Ok(0));
+
+		let keys = [
+			KeyWord::Command("build"),
+			KeyWord::Key("-v"),
+			KeyWord::Key("--verbose"),
+			KeyWord::Alias("-V", "--verbose"),
+			KeyWord::KeyWithValue("-o"),
+			KeyWord::KeyWithValue("--out"),
+			KeyWord::KeyWithOptionalValue("--color"),
+		];
+		// 1 + 1 + 1 + 1 + 2 + 2 + 2 singles, 3 switch + positional, 1
+		// reversed switches, 1 combo.
+		assert_eq!(selftest(&keys), Ok(15));
+
+		// Broken aliases.
+		assert_eq!(
+			selftest(&[KeyWord::Alias("-V", "--verbose")]),
+			Err("Alias -V points to unknown key --verbose.".to_owned()),
+		);
+
+		// Shadowed values.
+		assert!(selftest(&[KeyWord::KeyWithValue("-o"), KeyWord::Key("-o1")]).is_err());
+		assert!(selftest(&[KeyWord::KeyWithValue("--out"), KeyWord::Command("1")]).is_ok());
+	}
+}
@@ -0,0 +1,145 @@
+/*!
+# Argyle: Parse Stats.
+*/
+
+use crate::Argue;
+
+
+
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+/// # Parse Stats.
+///
+/// This holds some basic running totals for an [`Argue`] instance, as
+/// returned by [`Argue::stats`]. It is only available when the `stats`
+/// crate feature is enabled.
+///
+/// The allocation counts only cover the (new) heap allocations made by
+/// `Argue` itself. Most [`Argument`](crate::Argument)s — including
+/// values split off from keys, like `--key=val` — reuse or borrow existing
+/// memory, so never contribute.
+pub struct ArgueStats {
+	/// # Raw Arguments.
+	///
+	/// The total number of arguments pulled from the source, including any
+	/// values and end-of-command arguments.
+	pub args: usize,
+
+	/// # Keyword Hits.
+	///
+	/// The number of arguments that matched a [`KeyWord`](crate::KeyWord).
+	pub hits: usize,
+
+	/// # Keyword Misses.
+	///
+	/// The number of arguments checked against the keywords that _didn't_
+	/// match anything.
+	pub misses: usize,
+
+	/// # Invalid UTF-8 Allocations.
+	///
+	/// Raw values split off of `--key=value` arguments for
+	/// [`Argument::KeyWithInvalidValue`](crate::Argument::KeyWithInvalidValue).
+	pub alloc_invalid: usize,
+
+	/// # End Allocations.
+	///
+	/// Collections created for [`Argument::End`](crate::Argument::End).
+	pub alloc_end: usize,
+}
+
+impl ArgueStats {
+	#[must_use]
+	/// # Total Allocations.
+	///
+	/// Return the sum of all the allocation counts.
+	pub const fn allocations(&self) -> usize {
+		self.alloc_invalid + self.alloc_end
+	}
+}
+
+impl<I> Argue<I> {
+	#[must_use]
+	#[cfg_attr(docsrs, doc(cfg(feature = "stats")))]
+	/// # Stats.
+	///
+	/// Return the running totals for everything parsed so far. This is
+	/// mainly intended for profiling, so is only available when the `stats`
+	/// crate feature is enabled.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use argyle::{Argue, KeyWord};
+	/// use std::ffi::OsString;
+	///
+	/// let mut args = Argue::from([
+	///     OsString::from("-v"),
+	///     OsString::from("--threads=4"),
+	///     OsString::from("file.txt"),
+	/// ])
+	///     .with_keywords([
+	///         KeyWord::key("-v").unwrap(),
+	///         KeyWord::key_with_value("--threads").unwrap(),
+	///     ]);
+	///
+	/// for _ in args.by_ref() {}
+	///
+	/// let stats = args.stats();
+	/// assert_eq!(stats.args, 3);
+	/// assert_eq!(stats.hits, 2);
+	/// assert_eq!(stats.misses, 1);
+	/// assert_eq!(stats.allocations(), 0);
+	/// ```
+	pub const fn stats(&self) -> ArgueStats { self.stats }
+}
+
+
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::KeyWord;
+	use std::ffi::OsString;
+
+	#[test]
+	fn t_stats() {
+		#[cfg(unix)]
+		let bad = {
+			use std::{
+				ffi::OsStr,
+				os::unix::ffi::OsStrExt,
+			};
+			OsStr::from_bytes(b"-o=\xff").to_os_string()
+		};
+		#[cfg(not(unix))]
+		let bad = OsString::from("-o=fine");
+
+		let mut args = Argue::from([
+			OsString::from("build"),
+			OsString::from("-o"),
+			OsString::from("a.txt"),
+			OsString::from("-ob.txt"),
+			OsString::from("-v=1"),
+			OsString::from("file.txt"),
+			bad,
+			OsString::from("--"),
+			OsString::from("-v"),
+			OsString::from("-v"),
+		])
+			.with_keywords([
+				KeyWord::Command("build"),
+				KeyWord::Key("-v"),
+				KeyWord::KeyWithValue("-o"),
+			]);
+
+		assert_eq!(args.stats(), ArgueStats::default());
+		for _ in args.by_ref() {}
+
+		let stats = args.stats();
+		assert_eq!(stats.args, 10);
+		assert_eq!(stats.hits, if cfg!(unix) { 4 } else { 5 });
+		assert_eq!(stats.misses, 1);
+		assert_eq!(stats.alloc_invalid, usize::from(cfg!(unix)));
+		assert_eq!(stats.alloc_end, 1);
+	}
+}
@@ -0,0 +1,298 @@
+/*!
+# Argyle: Lexical Tokens.
+*/
+
+use std::ffi::OsString;
+
+
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+/// # Lexical Token.
+///
+/// This is the return type for the [`Tokens`] iterator.
+pub enum Token {
+	/// # Key.
+	///
+	/// An argument that looks like a key — see [`starts_like_key`](crate::starts_like_key)
+	/// — minus any value attached to it.
+	///
+	/// Keys are split the same way [`Argue`](crate::Argue) splits them:
+	/// long keys at the first `=`, and short keys — which are always two
+	/// bytes — after the second byte, so `-kval` is `-k` and `val`.
+	Key(String),
+
+	/// # Value.
+	///
+	/// The value attached to a key, like the `value` in `--key=value`,
+	/// `-k=value`, or `-kvalue`. This always immediately follows the
+	/// [`Token::Key`] it was split from.
+	///
+	/// Note: since there's no telling which keys are special, `-k=value` is
+	/// always split as `value`, never `=value` as it would be for
+	/// [`Argue::with_verbatim_values`](crate::Argue::with_verbatim_values).
+	Value(String),
+
+	/// # Positional.
+	///
+	/// Anything else, including everything after a [`Token::Separator`].
+	Positional(String),
+
+	/// # Separator.
+	///
+	/// The first `--` (unless [`Tokens::with_literal_separator`] is in
+	/// effect).
+	Separator,
+
+	/// # Invalid UTF-8.
+	///
+	/// An argument that couldn't be stringified.
+	InvalidUtf8(OsString),
+}
+
+
+
+/// # Lexical Token Iterator.
+///
+/// This is a lower-level alternative to [`Argue`](crate::Argue) that splits
+/// raw arguments into [`Token`]s based purely on their shape, leaving any
+/// keyword matching and key/value association to you.
+///
+/// It is mainly intended for apps with unusual association rules that
+/// `Argue` doesn't support.
+///
+/// Arguments are lexed exactly as `Argue` lexes them — the two share the
+/// same rules — and as with `Argue`, empty arguments are skipped (unless
+/// they follow a separator).
+///
+/// ## Examples
+///
+/// ```
+/// use argyle::{Token, Tokens};
+/// use std::ffi::OsString;
+///
+/// let mut tokens = Tokens::from([
+///     OsString::from("--threads=4"),
+///     OsString::from("-v"),
+///     OsString::from("file.txt"),
+///     OsString::from("--"),
+///     OsString::from("-v"),
+/// ]);
+///
+/// assert_eq!(tokens.next(), Some(Token::Key("--threads".to_owned())));
+/// assert_eq!(tokens.next(), Some(Token::Value("4".to_owned())));
+/// assert_eq!(tokens.next(), Some(Token::Key("-v".to_owned())));
+/// assert_eq!(tokens.next(), Some(Token::Positional("file.txt".to_owned())));
+/// assert_eq!(tokens.next(), Some(Token::Separator));
+/// assert_eq!(tokens.next(), Some(Token::Positional("-v".to_owned())));
+/// assert_eq!(tokens.next(), None);
+/// ```
+pub struct Tokens<I> {
+	/// # Raw Iterator.
+	iter: I,
+
+	/// # Pending Value.
+	value: Option<String>,
+
+	/// # Separator Found?
+	sep: bool,
+
+	/// # Literal Separator?
+	literal: bool,
+}
+
+impl<I: IntoIterator<Item=OsString>> From<I> for Tokens<I::IntoIter> {
+	#[inline]
+	fn from(src: I) -> Self {
+		Self {
+			iter: src.into_iter(),
+			value: None,
+			sep: false,
+			literal: false,
+		}
+	}
+}
+
+impl<I> Tokens<I> {
+	#[must_use]
+	/// # With Literal Separator.
+	///
+	/// Treat `--` as an ordinary [`Token::Positional`], same as
+	/// [`Argue::with_literal_separator`](crate::Argue::with_literal_separator).
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use argyle::{Token, Tokens};
+	/// use std::ffi::OsString;
+	///
+	/// let mut tokens = Tokens::from([OsString::from("--"), OsString::from("-v")])
+	///     .with_literal_separator();
+	///
+	/// assert_eq!(tokens.next(), Some(Token::Positional("--".to_owned())));
+	/// assert_eq!(tokens.next(), Some(Token::Key("-v".to_owned())));
+	/// assert_eq!(tokens.next(), None);
+	/// ```
+	pub const fn with_literal_separator(mut self) -> Self {
+		self.literal = true;
+		self
+	}
+}
+
+impl<I: Iterator<Item=OsString>> Iterator for Tokens<I> {
+	type Item = Token;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		// Finish the last key/value split.
+		if let Some(v) = self.value.take() { return Some(Token::Value(v)); }
+
+		loop {
+			let mut next = match self.iter.next()?.into_string() {
+				Ok(next) => next,
+				Err(e) => return Some(Token::InvalidUtf8(e)),
+			};
+
+			// Everything after a separator is positional.
+			if self.sep { return Some(Token::Positional(next)); }
+
+			return match lex(&next, self.literal) {
+				Lexeme::Empty => continue,
+				Lexeme::Separator => {
+					self.sep = true;
+					Some(Token::Separator)
+				},
+				// Keys, maybe with values.
+				Lexeme::Key(len, value) => {
+					if let Some(start) = value {
+						self.value = Some(next.split_off(start));
+						next.truncate(len);
+					}
+					Some(Token::Key(next))
+				},
+				Lexeme::Positional => Some(Token::Positional(next)),
+			};
+		}
+	}
+}
+
+
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+/// # Lexeme.
+///
+/// The shape of a single raw argument, as determined by [`lex`].
+pub(super) enum Lexeme {
+	/// # Empty.
+	Empty,
+
+	/// # End-of-Options Separator.
+	Separator,
+
+	/// # Key-Like.
+	///
+	/// The length of the key part, and the starting position of the value,
+	/// if any. (See [`split_keylike`](crate::key::split_keylike).)
+	Key(usize, Option<usize>),
+
+	/// # Anything Else.
+	Positional,
+}
+
+/// # Lex.
+///
+/// Classify a raw argument by its shape alone. This is the one and only
+/// lexer, shared by [`Tokens`] and [`Argue`](crate::Argue).
+pub(super) fn lex(raw: &str, literal_sep: bool) -> Lexeme {
+	if raw.is_empty() { Lexeme::Empty }
+	else if raw == "--" && ! literal_sep { Lexeme::Separator }
+	else if let Some((key, value)) = crate::key::split_keylike(raw.as_bytes()) {
+		Lexeme::Key(key.len(), value.map(|v| raw.len() - v.len()))
+	}
+	else { Lexeme::Positional }
+}
+
+
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	#[test]
+	fn t_tokens() {
+		let tokens: Vec<Token> = Tokens::from([
+			OsString::new(),
+			OsString::from("-"),
+			OsString::from("-kval"),
+			OsString::from("-k=val"),
+			OsString::from("-kv=al"),
+			OsString::from("-k="),
+			OsString::from("--key"),
+			OsString::from("--key="),
+			OsString::from("--key=a=b"),
+			OsString::from("-=x"),
+			OsString::from("--"),
+			OsString::new(),
+			OsString::from("--"),
+		]).collect();
+
+		assert_eq!(
+			tokens,
+			[
+				Token::Positional("-".to_owned()),
+				Token::Key("-k".to_owned()),
+				Token::Value("val".to_owned()),
+				Token::Key("-k".to_owned()),
+				Token::Value("val".to_owned()),
+				Token::Key("-k".to_owned()),
+				Token::Value("v=al".to_owned()),
+				Token::Key("-k".to_owned()),
+				Token::Value(String::new()),
+				Token::Key("--key".to_owned()),
+				Token::Key("--key".to_owned()),
+				Token::Value(String::new()),
+				Token::Key("--key".to_owned()),
+				Token::Value("a=b".to_owned()),
+				Token::Positional("-=x".to_owned()),
+				Token::Separator,
+				Token::Positional(String::new()),
+				Token::Positional("--".to_owned()),
+			],
+		);
+	}
+
+	#[test]
+	fn t_tokens_argue() {
+		use crate::{
+			Argue,
+			Argument,
+			KeyWord,
+		};
+
+		// Tokens and Argue should agree on where keys end and values begin.
+		for raw in ["-k", "-kval", "-k=val", "-kv=al", "-k=", "--key", "--key=", "--key=a=b"] {
+			let tokens: Vec<Token> = Tokens::from([OsString::from(raw)]).collect();
+			let Some(Token::Key(k)) = tokens.first() else { panic!("Not a key: {raw}"); };
+			let k: &'static str = k.clone().leak();
+			let expected = match tokens.get(1) {
+				Some(Token::Value(v)) => Argument::KeyWithValue(k, v.clone()),
+				_ => Argument::MissingValue(k),
+			};
+
+			let mut args = Argue::from([OsString::from(raw)])
+				.with_keywords([KeyWord::KeyWithValue(k)]);
+			assert_eq!(args.next(), Some(expected), "{raw}");
+		}
+
+		// Separators too.
+		let raw = [OsString::from("--"), OsString::from("-k")];
+		assert_eq!(Tokens::from(raw.clone()).next(), Some(Token::Separator));
+		assert_eq!(
+			Tokens::from(raw.clone()).with_literal_separator().next(),
+			Some(Token::Positional("--".to_owned())),
+		);
+		assert!(matches!(Argue::from(raw.clone()).next(), Some(Argument::End(_))));
+		assert_eq!(
+			Argue::from(raw).with_literal_separator().next(),
+			Some(Argument::Other("--".to_owned())),
+		);
+	}
+}
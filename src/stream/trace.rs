@@ -0,0 +1,161 @@
+/*!
+# Argyle: Tracing.
+*/
+
+use crate::{
+	Argue,
+	KeyWord,
+};
+use std::fmt;
+
+
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+/// # Trace Event.
+///
+/// This describes a single classification decision made by [`Argue`], as
+/// reported to the callback registered with [`Argue::trace`].
+///
+/// The `Display` implementation produces a short, human-readable summary,
+/// suitable for printing to STDERR.
+pub enum Trace<'a> {
+	/// # Keyword Match.
+	///
+	/// The raw argument matched a keyword (after resolving any aliases). If
+	/// a value was attached, `split` is the byte index at which it begins.
+	Match {
+		/// # Raw Argument.
+		raw: &'a str,
+
+		/// # Matched Keyword.
+		keyword: KeyWord,
+
+		/// # Value Position.
+		split: Option<usize>,
+	},
+
+	/// # No Match.
+	///
+	/// The raw argument did not match any keyword.
+	NoMatch {
+		/// # Raw Argument.
+		raw: &'a str,
+	},
+
+	/// # Value.
+	///
+	/// The raw argument was consumed as the value for the preceding key,
+	/// without any further classification.
+	Value {
+		/// # Key.
+		key: &'static str,
+
+		/// # Raw Argument.
+		raw: &'a str,
+	},
+}
+
+impl fmt::Display for Trace<'_> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Self::Match { raw, keyword, split: Some(split) } =>
+				write!(f, "{raw:?}: matched {keyword:?}, value at byte {split}."),
+			Self::Match { raw, keyword, split: None } =>
+				write!(f, "{raw:?}: matched {keyword:?}."),
+			Self::NoMatch { raw } => write!(f, "{raw:?}: no match."),
+			Self::Value { key, raw } => write!(f, "{raw:?}: value for {key}."),
+		}
+	}
+}
+
+
+
+impl<I> Argue<I> {
+	#[must_use]
+	/// # Trace.
+	///
+	/// Register a callback to be notified of each classification decision —
+	/// keyword matches, misses, and consumed values — as it happens.
+	///
+	/// This is purely a debugging aid, useful for figuring out why a
+	/// particular argument was (or wasn't) treated a certain way given the
+	/// subtleties of glued and `=` value splitting.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use argyle::{Argue, KeyWord};
+	/// use std::ffi::OsString;
+	///
+	/// let args = Argue::from([
+	///     OsString::from("-o"),
+	///     OsString::from("-t2"),
+	///     OsString::from("-t2"),
+	/// ])
+	///     .with_keywords([
+	///         KeyWord::key_with_value("-o").unwrap(),
+	///         KeyWord::key_with_value("-t").unwrap(),
+	///     ])
+	///     .trace(|t| eprintln!("{t}"));
+	///
+	/// // Prints:
+	/// // "-o": matched KeyWithValue("-o").
+	/// // "-t2": value for -o.
+	/// // "-t2": matched KeyWithValue("-t"), value at byte 2.
+	/// for _ in args {}
+	/// ```
+	pub fn trace(mut self, cb: fn(&Trace<'_>)) -> Self {
+		self.tracer = Some(cb);
+		self
+	}
+
+	/// # Emit Trace.
+	///
+	/// Pass the event to the callback, if any.
+	pub(super) fn emit_trace(&self, event: &Trace<'_>) {
+		if let Some(cb) = self.tracer { cb(event); }
+	}
+}
+
+
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use std::{
+		ffi::OsString,
+		sync::Mutex,
+	};
+
+	#[test]
+	fn t_trace() {
+		static EVENTS: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+		let args = Argue::from([
+			OsString::from("-o"),
+			OsString::from("-t2"),
+			OsString::from("-t2"),
+			OsString::from("--v=1"),
+			OsString::from("file.txt"),
+		].into_iter())
+			.with_keywords([
+				KeyWord::KeyWithValue("-o"),
+				KeyWord::KeyWithValue("-t"),
+				KeyWord::Key("--verbose"),
+				KeyWord::Alias("--v", "--verbose"),
+			])
+			.trace(|t| { EVENTS.lock().unwrap().push(t.to_string()); });
+
+		assert_eq!(args.count(), 4);
+		assert_eq!(
+			*EVENTS.lock().unwrap(),
+			[
+				r#""-o": matched KeyWithValue("-o")."#,
+				r#""-t2": value for -o."#,
+				r#""-t2": matched KeyWithValue("-t"), value at byte 2."#,
+				r#""--v=1": matched Key("--verbose"), value at byte 4."#,
+				r#""file.txt": no match."#,
+			],
+		);
+	}
+}
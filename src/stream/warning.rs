@@ -0,0 +1,247 @@
+/*!
+# Argyle: Warnings.
+*/
+
+use crate::{
+	Argue,
+	Argument,
+};
+use std::fmt;
+use super::{
+	FLAG_PLACEHOLDERS,
+	FLAG_WARNINGS,
+};
+
+
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+/// # Warning.
+///
+/// This is a non-fatal issue collected by [`Argue`] when
+/// [`Argue::with_warnings`] is in effect. Unlike [`Diagnostic`](crate::Diagnostic)s,
+/// these don't necessarily mean anything is _wrong_, but may be worth
+/// mentioning to the user.
+///
+/// The `Display` implementation produces short, human-readable messages
+/// suitable for printing as-are.
+pub enum Warning {
+	/// # Deprecated Key.
+	///
+	/// A key registered via [`Argue::with_deprecated_keys`] was used.
+	Deprecated(&'static str),
+
+	/// # Duplicate Switch.
+	///
+	/// An [`Argument::Key`] was passed more than once.
+	Duplicate(&'static str),
+
+	/// # Empty Value.
+	///
+	/// A key was given an empty value, e.g. `--out=`.
+	EmptyValue(&'static str),
+
+	/// # Late Command.
+	///
+	/// A command appeared too late and was demoted per
+	/// [`Argue::with_leading_commands`], [`Argue::with_first_commands`], or
+	/// [`Argue::with_commands_before_keys`].
+	LateCommand(&'static str),
+
+	/// # Suspicious Placeholder.
+	///
+	/// A value or positional looks like an unexpanded template placeholder.
+	/// (This is only checked when [`Argue::with_placeholder_checks`] is _not_
+	/// in effect; otherwise such arguments are errors.)
+	Placeholder(Option<&'static str>, String),
+}
+
+impl fmt::Display for Warning {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Self::Deprecated(k) => write!(f, "{k} is deprecated."),
+			Self::Duplicate(k) => write!(f, "{k} was passed more than once."),
+			Self::EmptyValue(k) => write!(f, "{k} has an empty value."),
+			Self::LateCommand(k) => write!(f, "{k} was treated as a positional; commands must come first."),
+			Self::Placeholder(Some(k), v) => write!(f, "Possible unexpanded placeholder for {k}: {v}"),
+			Self::Placeholder(None, v) => write!(f, "Possible unexpanded placeholder: {v}"),
+		}
+	}
+}
+
+
+
+impl<I> Argue<I> {
+	#[must_use]
+	/// # With Warnings.
+	///
+	/// Keep track of non-fatal issues — deprecated or duplicate keys, empty
+	/// values, suspicious placeholders — as they are encountered. Use
+	/// [`Argue::warnings`] to see what's been collected so far.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use argyle::{Argue, Argument, KeyWord, Warning};
+	/// use std::ffi::OsString;
+	///
+	/// let mut args = Argue::from([
+	///     OsString::from("-v"),
+	///     OsString::from("--old"),
+	///     OsString::from("-v"),
+	///     OsString::from("--out="),
+	/// ])
+	///     .with_keywords([
+	///         KeyWord::key("-v").unwrap(),
+	///         KeyWord::key("--old").unwrap(),
+	///         KeyWord::key_with_value("--out").unwrap(),
+	///     ])
+	///     .with_deprecated_keys(["--old"])
+	///     .with_warnings();
+	///
+	/// // Use by_ref so the warnings are still around afterwards.
+	/// for arg in args.by_ref() {
+	///     // Do stuff…
+	/// }
+	///
+	/// assert_eq!(
+	///     args.warnings(),
+	///     [
+	///         Warning::Deprecated("--old"),
+	///         Warning::Duplicate("-v"),
+	///         Warning::EmptyValue("--out"),
+	///     ],
+	/// );
+	/// ```
+	pub const fn with_warnings(mut self) -> Self {
+		self.flags |= FLAG_WARNINGS;
+		self
+	}
+
+	#[must_use]
+	/// # With Deprecated Keys.
+	///
+	/// Register keys — or commands — that still work, but should be reported
+	/// via [`Warning::Deprecated`] when used. This requires
+	/// [`Argue::with_warnings`].
+	///
+	/// Aliases are resolved _before_ the check, so this applies to
+	/// canonical keys only.
+	pub fn with_deprecated_keys<K: IntoIterator<Item=&'static str>>(mut self, keys: K) -> Self {
+		self.deprecated.extend(keys);
+		self
+	}
+
+	#[must_use]
+	/// # Warnings.
+	///
+	/// Return the [`Warning`]s collected so far, in order.
+	pub fn warnings(&self) -> &[Warning] { &self.warnings }
+
+	/// # Check Warnings.
+	///
+	/// Record any warnings for the argument, if [`Argue::with_warnings`] is
+	/// in effect.
+	pub(super) fn check_warnings(&mut self, arg: &Argument) {
+		if 0 == self.flags & FLAG_WARNINGS { return; }
+
+		// Deprecation.
+		if let
+			Argument::Command(k) | Argument::CommandWith(k, _) | Argument::Key(k) |
+			Argument::KeyWithValue(k, _) | Argument::KeyWithOsValue(k, _) |
+			Argument::KeyWithValues(k, _) | Argument::UnexpectedValue(k, _) |
+			Argument::MissingValue(k) = arg
+		{
+			if self.deprecated.contains(k) { self.warnings.push(Warning::Deprecated(k)); }
+		}
+
+		// Duplicates.
+		if let Argument::Key(k) = arg {
+			if ! self.seen.insert(k) { self.warnings.push(Warning::Duplicate(k)); }
+		}
+
+		// Empty values and placeholders.
+		let placeholders = 0 == self.flags & FLAG_PLACEHOLDERS;
+		match arg {
+			Argument::KeyWithValue(k, v) if v.is_empty() => {
+				self.warnings.push(Warning::EmptyValue(k));
+			},
+			Argument::KeyWithValue(k, v) if placeholders && super::has_placeholder(v) => {
+				self.warnings.push(Warning::Placeholder(Some(k), v.clone()));
+			},
+			Argument::KeyWithOsValue(k, v) if v.is_empty() => {
+				self.warnings.push(Warning::EmptyValue(k));
+			},
+			Argument::KeyWithValues(k, v) if v.iter().any(String::is_empty) => {
+				self.warnings.push(Warning::EmptyValue(k));
+			},
+			Argument::Other(v) if placeholders && super::has_placeholder(v) => {
+				self.warnings.push(Warning::Placeholder(None, v.clone()));
+			},
+			_ => {},
+		}
+	}
+}
+
+
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::KeyWord;
+	use std::ffi::OsString;
+
+	#[test]
+	fn t_warnings() {
+		let cli = [
+			OsString::from("build"),
+			OsString::from("-v"),
+			OsString::from("-o"),
+			OsString::from("{}.txt"),
+			OsString::from("-v"),
+			OsString::from("-v"),
+			OsString::from("-o="),
+			OsString::from("$@"),
+		];
+		let keys = [KeyWord::Command("build"), KeyWord::Key("-v"), KeyWord::KeyWithValue("-o")];
+
+		// Nothing without the flag.
+		let mut args = Argue::from(cli.clone().into_iter())
+			.with_keywords(keys)
+			.with_deprecated_keys(["build"]);
+		assert_eq!(args.by_ref().count(), 7);
+		assert!(args.warnings().is_empty());
+
+		// Now with.
+		let mut args = Argue::from(cli.clone().into_iter())
+			.with_keywords(keys)
+			.with_deprecated_keys(["build"])
+			.with_warnings();
+		assert_eq!(args.by_ref().count(), 7);
+		assert_eq!(
+			args.warnings(),
+			[
+				Warning::Deprecated("build"),
+				Warning::Placeholder(Some("-o"), "{}.txt".to_owned()),
+				Warning::Duplicate("-v"),
+				Warning::Duplicate("-v"),
+				Warning::EmptyValue("-o"),
+				Warning::Placeholder(None, "$@".to_owned()),
+			],
+		);
+
+		// Placeholders are errors, not warnings, with checks enabled.
+		let mut args = Argue::from(cli.into_iter())
+			.with_keywords(keys)
+			.with_placeholder_checks()
+			.with_warnings();
+		assert_eq!(args.by_ref().count(), 7);
+		assert_eq!(
+			args.warnings(),
+			[
+				Warning::Duplicate("-v"),
+				Warning::Duplicate("-v"),
+				Warning::EmptyValue("-o"),
+			],
+		);
+	}
+}
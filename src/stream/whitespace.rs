@@ -0,0 +1,176 @@
+/*!
+# Argyle: Whitespace Handling.
+*/
+
+use crate::Argue;
+use super::{
+	FLAG_WS_SKIP,
+	FLAG_WS_TRIM,
+};
+
+
+
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+/// # Whitespace Policy.
+///
+/// This determines how [`Argue`] treats arguments with leading or trailing
+/// whitespace — or nothing _but_ whitespace — as set by
+/// [`Argue::with_whitespace`].
+pub enum WhitespacePolicy {
+	#[default]
+	/// # Keep.
+	///
+	/// Leave everything as-is. Whitespace-only arguments are returned as
+	/// [`Argument::Other`](crate::Argument::Other).
+	Keep,
+
+	/// # Trim.
+	///
+	/// Trim leading and trailing whitespace from each argument before
+	/// classifying it, skipping any left empty.
+	Trim,
+
+	/// # Skip.
+	///
+	/// Skip whitespace-only arguments, but leave the rest as-is.
+	Skip,
+}
+
+
+
+impl<I> Argue<I> {
+	#[must_use]
+	/// # With Whitespace Policy.
+	///
+	/// Set the [`WhitespacePolicy`] to use for standalone arguments, so
+	/// that mis-quoted shell invocations don't produce phantom positionals
+	/// like `" "`.
+	///
+	/// The policy is applied uniformly to everything pulled from the source
+	/// _except_ values consumed by a preceding key — `-o " "` — and
+	/// [`Argument::End`](crate::Argument::End) arguments, which are always
+	/// kept byte-for-byte.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use argyle::{Argue, Argument, KeyWord, WhitespacePolicy};
+	/// use std::ffi::OsString;
+	///
+	/// let raw = [
+	///     OsString::from(" "),
+	///     OsString::from(" -v "),
+	///     OsString::from("file.txt"),
+	/// ];
+	/// let keys = [KeyWord::key("-v").unwrap()];
+	///
+	/// // Keep (default).
+	/// let args = Argue::from(raw.clone()).with_keywords(keys);
+	/// assert_eq!(
+	///     args.collect::<Vec<_>>(),
+	///     [
+	///         Argument::Other(" ".to_owned()),
+	///         Argument::Other(" -v ".to_owned()),
+	///         Argument::Other("file.txt".to_owned()),
+	///     ],
+	/// );
+	///
+	/// // Skip.
+	/// let args = Argue::from(raw.clone())
+	///     .with_keywords(keys)
+	///     .with_whitespace(WhitespacePolicy::Skip);
+	/// assert_eq!(
+	///     args.collect::<Vec<_>>(),
+	///     [
+	///         Argument::Other(" -v ".to_owned()),
+	///         Argument::Other("file.txt".to_owned()),
+	///     ],
+	/// );
+	///
+	/// // Trim.
+	/// let args = Argue::from(raw)
+	///     .with_keywords(keys)
+	///     .with_whitespace(WhitespacePolicy::Trim);
+	/// assert_eq!(
+	///     args.collect::<Vec<_>>(),
+	///     [Argument::Key("-v"), Argument::Other("file.txt".to_owned())],
+	/// );
+	/// ```
+	pub const fn with_whitespace(mut self, policy: WhitespacePolicy) -> Self {
+		self.flags &= ! (FLAG_WS_SKIP | FLAG_WS_TRIM);
+		match policy {
+			WhitespacePolicy::Keep => {},
+			WhitespacePolicy::Trim => { self.flags |= FLAG_WS_TRIM; },
+			WhitespacePolicy::Skip => { self.flags |= FLAG_WS_SKIP; },
+		}
+		self
+	}
+
+	/// # Apply Whitespace Policy.
+	///
+	/// Return `raw` trimmed or emptied as the policy dictates. (Empty
+	/// arguments are skipped by the caller.)
+	pub(super) fn apply_whitespace(&self, raw: String) -> String {
+		if FLAG_WS_TRIM == self.flags & FLAG_WS_TRIM {
+			let trimmed = raw.trim();
+			if trimmed.len() != raw.len() { return trimmed.to_owned(); }
+		}
+		else if FLAG_WS_SKIP == self.flags & FLAG_WS_SKIP && raw.trim().is_empty() {
+			return String::new();
+		}
+		raw
+	}
+}
+
+
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::{
+		Argument,
+		KeyWord,
+	};
+	use std::ffi::OsString;
+
+	#[test]
+	fn t_whitespace() {
+		let raw = [
+			OsString::from("\t"),
+			OsString::from("-o"),
+			OsString::from(" "),
+			OsString::from(" file.txt\n"),
+			OsString::from("\u{a0}"),
+			OsString::from("--"),
+			OsString::from(" "),
+		];
+		let parse = |policy| Argue::from(raw.iter().cloned())
+			.with_keywords([KeyWord::KeyWithValue("-o")])
+			.with_whitespace(WhitespacePolicy::Trim)
+			.with_whitespace(policy)
+			.collect::<Vec<_>>();
+
+		// Values and trailing args are never touched.
+		let value = Argument::KeyWithValue("-o", " ".to_owned());
+		let end = Argument::End(vec![OsString::from(" ")]);
+
+		assert_eq!(
+			parse(WhitespacePolicy::Keep),
+			[
+				Argument::Other("\t".to_owned()),
+				value.clone(),
+				Argument::Other(" file.txt\n".to_owned()),
+				Argument::Other("\u{a0}".to_owned()),
+				end.clone(),
+			],
+		);
+		assert_eq!(
+			parse(WhitespacePolicy::Skip),
+			[value.clone(), Argument::Other(" file.txt\n".to_owned()), end.clone()],
+		);
+		assert_eq!(
+			parse(WhitespacePolicy::Trim),
+			[value, Argument::Other("file.txt".to_owned()), end],
+		);
+	}
+}
@@ -0,0 +1,184 @@
+/*!
+# Argyle: Version.
+*/
+
+use core::fmt;
+
+
+
+#[macro_export]
+/// # Version Helper.
+///
+/// This macro returns a [`Version`] populated with the calling crate's
+/// `CARGO_PKG_NAME` and `CARGO_PKG_VERSION`, along with the optional
+/// `ARGYLE_VERSION_COMMIT` and `ARGYLE_VERSION_DATE` compile-time
+/// environment variables, if present.
+///
+/// The latter two aren't set by Cargo, but can be set by a build script
+/// like so:
+///
+/// ```ignore
+/// println!("cargo:rustc-env=ARGYLE_VERSION_COMMIT=abc1234");
+/// println!("cargo:rustc-env=ARGYLE_VERSION_DATE=2024-10-17");
+/// ```
+///
+/// ## Examples
+///
+/// ```
+/// use argyle::Argument;
+///
+/// for arg in argyle::args() {
+///     match arg {
+///         Argument::Key("-V" | "--version") => {
+///             println!("{}", argyle::version!());
+///             return;
+///         },
+///         _ => {},
+///     }
+/// }
+/// ```
+macro_rules! version {
+	() => (
+		$crate::Version::new(env!("CARGO_PKG_NAME"), env!("CARGO_PKG_VERSION"))
+			.with_commit(option_env!("ARGYLE_VERSION_COMMIT"))
+			.with_date(option_env!("ARGYLE_VERSION_DATE"))
+	);
+}
+
+
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+/// # Version Block.
+///
+/// This struct composes a consistent, single-line version string from an
+/// app's name, version, and optional commit and build date, suitable for
+/// printing in response to `--version`.
+///
+/// In most cases you'll want to create instances using the [`version!`]
+/// macro, but you can also build them manually.
+///
+/// ## Examples
+///
+/// ```
+/// use argyle::Version;
+///
+/// let v = Version::new("Argyle", "0.10.0");
+/// assert_eq!(v.to_string(), "Argyle v0.10.0");
+///
+/// let v = v.with_commit(Some("abc1234")).with_date(Some("2024-10-17"));
+/// assert_eq!(v.to_string(), "Argyle v0.10.0 (abc1234, 2024-10-17)");
+/// ```
+pub struct Version {
+	/// # App Name.
+	name: &'static str,
+
+	/// # Version Number.
+	number: &'static str,
+
+	/// # Commit.
+	commit: Option<&'static str>,
+
+	/// # Build Date.
+	date: Option<&'static str>,
+}
+
+impl fmt::Display for Version {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "{} v{}", self.name, self.number)?;
+		match (self.commit, self.date) {
+			(Some(c), Some(d)) => write!(f, " ({c}, {d})"),
+			(Some(v), None) | (None, Some(v)) => write!(f, " ({v})"),
+			(None, None) => Ok(()),
+		}
+	}
+}
+
+impl Version {
+	#[must_use]
+	/// # New.
+	///
+	/// Create a new instance from a name and version. Leading `v`s, if any,
+	/// are stripped from the version.
+	pub const fn new(name: &'static str, version: &'static str) -> Self {
+		let number = match version.as_bytes() {
+			[b'v' | b'V', rest @ ..] => match core::str::from_utf8(rest) {
+				Ok(v) => v,
+				Err(_) => version,
+			},
+			_ => version,
+		};
+
+		Self { name, number, commit: None, date: None }
+	}
+
+	#[must_use]
+	/// # With Commit.
+	///
+	/// Set (or unset) the commit hash. Empty values are ignored.
+	pub const fn with_commit(mut self, commit: Option<&'static str>) -> Self {
+		self.commit = non_empty(commit);
+		self
+	}
+
+	#[must_use]
+	/// # With Build Date.
+	///
+	/// Set (or unset) the build date. Empty values are ignored.
+	pub const fn with_date(mut self, date: Option<&'static str>) -> Self {
+		self.date = non_empty(date);
+		self
+	}
+}
+
+impl Version {
+	#[must_use]
+	/// # Name.
+	pub const fn name(&self) -> &'static str { self.name }
+
+	#[must_use]
+	/// # Version.
+	pub const fn version(&self) -> &'static str { self.number }
+
+	#[must_use]
+	/// # Commit.
+	pub const fn commit(&self) -> Option<&'static str> { self.commit }
+
+	#[must_use]
+	/// # Build Date.
+	pub const fn date(&self) -> Option<&'static str> { self.date }
+}
+
+
+
+/// # Non-Empty.
+///
+/// Return `None` if the value is `None` or empty.
+const fn non_empty(src: Option<&'static str>) -> Option<&'static str> {
+	match src {
+		Some(s) if ! s.is_empty() => Some(s),
+		_ => None,
+	}
+}
+
+
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use alloc::string::ToString;
+
+	#[test]
+	fn t_version() {
+		let v = Version::new("Foo", "v1.2.3");
+		assert_eq!(v.version(), "1.2.3");
+		assert_eq!(v.to_string(), "Foo v1.2.3");
+		assert_eq!(v.with_commit(Some("")).to_string(), "Foo v1.2.3");
+		assert_eq!(v.with_commit(Some("abc")).to_string(), "Foo v1.2.3 (abc)");
+		assert_eq!(v.with_date(Some("2024-01-01")).to_string(), "Foo v1.2.3 (2024-01-01)");
+
+		// The macro should pick up our own details.
+		let v = crate::version!();
+		assert_eq!(v.name(), "argyle");
+		assert_eq!(v.version(), env!("CARGO_PKG_VERSION"));
+	}
+}